@@ -0,0 +1,326 @@
+//! Serde adapters that read human time strings, e.g. in config files.
+//!
+//! Each module has a `deserialize` function for
+//! `#[serde(deserialize_with = "...")]` that reads a natural-language phrase
+//! or an ISO 8601 string:
+//!
+//! - [`local`] and [`utc`] read times (`"in 30 days"`, `"tomorrow at 9am"`,
+//!   `"2024-12-25T15:30:00Z"`) into `DateTime<Local>` and `DateTime<Utc>`
+//! - with the `chrono-tz` feature, `tz` reads times into a
+//!   `DateTime<chrono_tz::Tz>` in the provider's named time zone
+//! - [`time_delta`] reads durations (`"2 hours"`, `"1.5 days"`, `"PT2H"`)
+//!   into a [`TimeDelta`](chrono::TimeDelta)
+//!
+//! Phrases are read in English and resolved by a default [`ChronoProvider`]
+//! relative to the system clock, unless [`with_settings`] picks other
+//! [`Settings`] while deserializing.
+//!
+//! # Examples
+//!
+//! ```
+//! use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+//! use serde::Deserialize;
+//! use temps_chrono::de::{Settings, with_settings};
+//! use temps_core::Language;
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(deserialize_with = "temps_chrono::de::utc::deserialize")]
+//!     expires_at: DateTime<Utc>,
+//!     #[serde(deserialize_with = "temps_chrono::de::time_delta::deserialize")]
+//!     timeout: TimeDelta,
+//! }
+//!
+//! let json = r#"{"expires_at": "in 30 Tagen", "timeout": "2 Stunden"}"#;
+//! let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
+//! let settings = Settings::new(Language::German).with_reference(reference);
+//! let config: Config = with_settings(settings, || serde_json::from_str(json)).unwrap();
+//!
+//! assert_eq!(config.expires_at, Utc.with_ymd_and_hms(2024, 4, 14, 10, 30, 0).unwrap());
+//! assert_eq!(config.timeout, TimeDelta::hours(2));
+//! ```
+
+use std::cell::RefCell;
+
+use chrono::{DateTime, TimeZone, Utc};
+use temps_core::{Clock, FixedClock, Language, ParseOptions, Result, TimeParser};
+
+use crate::ChronoProvider;
+
+thread_local! {
+    static SETTINGS: RefCell<Option<Settings>> = const { RefCell::new(None) };
+}
+
+/// How the adapters read phrases: the language and the provider that
+/// resolves them, with its reference clock and policies.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use temps_chrono::{ChronoProvider, de::Settings};
+/// use temps_core::{DateOrder, Disambiguation, Language, ParseOptions};
+///
+/// let settings = Settings::new(ParseOptions::new(Language::English).with_date_order(DateOrder::Mdy))
+///     .with_reference(Utc.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap());
+///
+/// let settings = Settings::new(Language::English)
+///     .with_provider(ChronoProvider::new(Utc).with_disambiguation(Disambiguation::Reject));
+/// ```
+#[derive(Clone)]
+pub struct Settings {
+    options: ParseOptions,
+    /// The clock and policies; each adapter resolves in its own time zone
+    provider: ChronoProvider<Utc>,
+    /// The time zone of the `tz` adapter
+    #[cfg(feature = "chrono-tz")]
+    timezone: chrono_tz::Tz,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::new(Language::English)
+    }
+}
+
+impl Settings {
+    /// Read phrases with the given language or parse options, relative to
+    /// the system clock.
+    #[must_use]
+    pub fn new(options: impl Into<ParseOptions>) -> Self {
+        Self {
+            options: options.into(),
+            provider: ChronoProvider::new(Utc),
+            #[cfg(feature = "chrono-tz")]
+            timezone: chrono_tz::UTC,
+        }
+    }
+
+    /// Resolve phrases with the clock, disambiguation, year policy, period
+    /// end and week start of the given provider.
+    ///
+    /// [`local`] and [`utc`] still resolve phrases in their own time zone.
+    /// With the `chrono-tz` feature, `tz` resolves them in the provider's
+    /// time zone if it is a `chrono_tz::Tz`, and in UTC otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use temps_chrono::{ChronoProvider, de::Settings};
+    /// use temps_core::{Language, WeekStart, YearPolicy};
+    ///
+    /// let provider = ChronoProvider::new(Utc)
+    ///     .with_week_start(WeekStart::Sunday)
+    ///     .with_year_policy(YearPolicy::Previous);
+    /// let settings = Settings::new(Language::English).with_provider(provider);
+    /// ```
+    #[must_use]
+    pub fn with_provider<Tz>(mut self, provider: ChronoProvider<Tz>) -> Self
+    where
+        Tz: TimeZone + Send + Sync + 'static,
+    {
+        #[cfg(feature = "chrono-tz")]
+        {
+            let timezone: &dyn std::any::Any = provider.timezone();
+            self.timezone = timezone
+                .downcast_ref::<chrono_tz::Tz>()
+                .copied()
+                .unwrap_or(chrono_tz::UTC);
+        }
+        self.provider = provider.in_timezone(Utc);
+        self
+    }
+
+    /// Resolve phrases against the given clock instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl Clock<DateTime<Utc>> + 'static) -> Self {
+        self.provider = self.provider.with_clock(clock);
+        self
+    }
+
+    /// Resolve phrases against a fixed reference time.
+    #[must_use]
+    pub fn with_reference<Tz: TimeZone>(self, reference: DateTime<Tz>) -> Self {
+        self.with_clock(FixedClock(reference.to_utc()))
+    }
+
+    /// The parse options phrases are read with.
+    #[must_use]
+    pub fn options(&self) -> ParseOptions {
+        self.options
+    }
+}
+
+/// Run `f` with the adapters reading phrases according to `settings`.
+///
+/// The settings apply to deserialization on the current thread until `f`
+/// returns, after which the previous settings are restored.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use serde::Deserialize;
+/// use temps_chrono::de::{Settings, with_settings};
+/// use temps_core::Language;
+///
+/// #[derive(Deserialize)]
+/// struct Job {
+///     #[serde(deserialize_with = "temps_chrono::de::utc::deserialize")]
+///     start: DateTime<Utc>,
+/// }
+///
+/// let settings = Settings::new(Language::German);
+/// let job: Job = with_settings(settings, || serde_json::from_str(r#"{"start": "morgen um 9:00"}"#))
+///     .unwrap();
+/// ```
+pub fn with_settings<R>(settings: Settings, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous settings, also when `f` panics
+    struct Restore(Option<Settings>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SETTINGS.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(SETTINGS.replace(Some(settings)));
+    f()
+}
+
+/// The settings of the innermost [`with_settings`], or the default
+fn current() -> Settings {
+    SETTINGS.with_borrow(|settings| settings.clone().unwrap_or_default())
+}
+
+/// Resolve a phrase in `timezone` with the current settings
+fn resolve<Tz>(input: &str, timezone: Tz) -> Result<DateTime<Tz>>
+where
+    Tz: TimeZone + Send + Sync + 'static,
+{
+    let settings = current();
+    let expr = temps_core::parse(input, settings.options)?;
+    settings
+        .provider
+        .in_timezone(timezone)
+        .parse_expression(expr)
+}
+
+/// Read a time as `DateTime<Local>`.
+///
+/// Phrases such as "tomorrow at 9am" are resolved in the system's time zone.
+pub mod local {
+    use chrono::{DateTime, Local};
+    use serde::{Deserialize, Deserializer, de::Error};
+
+    /// Deserialize a phrase or ISO 8601 string into a `DateTime<Local>`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the [`TempsError`](temps_core::TempsError) message if the
+    /// string cannot be parsed or resolved.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Local>, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        super::resolve(&input, Local).map_err(D::Error::custom)
+    }
+}
+
+/// Read a time as `DateTime<Utc>`.
+///
+/// Phrases such as "tomorrow at 9am" are resolved in UTC.
+pub mod utc {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Deserializer, de::Error};
+
+    /// Deserialize a phrase or ISO 8601 string into a `DateTime<Utc>`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the [`TempsError`](temps_core::TempsError) message if the
+    /// string cannot be parsed or resolved.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        super::resolve(&input, Utc).map_err(D::Error::custom)
+    }
+}
+
+/// Read a time as a `DateTime<chrono_tz::Tz>`.
+///
+/// Phrases such as "tomorrow at 9am" are resolved in the time zone of the
+/// provider given to [`Settings::with_provider`], or in UTC.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{DateTime, TimeZone, Timelike};
+/// use chrono_tz::{Europe::Berlin, Tz};
+/// use serde::Deserialize;
+/// use temps_chrono::{
+///     ChronoProvider,
+///     de::{Settings, with_settings},
+/// };
+/// use temps_core::Language;
+///
+/// #[derive(Deserialize)]
+/// struct Job {
+///     #[serde(deserialize_with = "temps_chrono::de::tz::deserialize")]
+///     start: DateTime<Tz>,
+/// }
+///
+/// let reference = Berlin.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
+/// let provider = ChronoProvider::new(Berlin).with_reference(reference);
+/// let settings = Settings::new(Language::English).with_provider(provider);
+/// let job: Job = with_settings(settings, || {
+///     serde_json::from_str(r#"{"start": "tomorrow at 9am"}"#)
+/// })
+/// .unwrap();
+/// assert_eq!(job.start.timezone(), Berlin);
+/// assert_eq!(job.start.hour(), 9);
+/// ```
+#[cfg(feature = "chrono-tz")]
+pub mod tz {
+    use chrono::DateTime;
+    use chrono_tz::Tz;
+    use serde::{Deserialize, Deserializer, de::Error};
+
+    /// Deserialize a phrase or ISO 8601 string into a `DateTime<Tz>`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the [`TempsError`](temps_core::TempsError) message if the
+    /// string cannot be parsed or resolved.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Tz>, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        let timezone = super::current().timezone;
+        super::resolve(&input, timezone).map_err(D::Error::custom)
+    }
+}
+
+/// Read a duration as a [`TimeDelta`](chrono::TimeDelta).
+///
+/// Durations are read like [`parse_to_time_delta`](crate::parse_to_time_delta),
+/// in the language of the current settings.
+pub mod time_delta {
+    use chrono::TimeDelta;
+    use serde::{Deserialize, Deserializer, de::Error};
+
+    /// Deserialize a duration such as "2 hours" or "PT2H" into a `TimeDelta`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the [`TempsError`](temps_core::TempsError) message if the
+    /// string is not a duration or has months or years, which have no fixed
+    /// length.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TimeDelta, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        let language = super::current().options.language();
+        crate::parse_to_time_delta(&input, language).map_err(D::Error::custom)
+    }
+}
@@ -4,7 +4,8 @@
 //!
 //! This crate provides a `ChronoProvider` that implements the `TimeParser` trait
 //! using the chrono datetime library. It enables parsing natural language time
-//! expressions into chrono's `DateTime<Local>` type.
+//! expressions into chrono's `DateTime<Tz>` type, where `Tz` is any chrono time
+//! zone (`Local` by default).
 //!
 //! ## Features
 //!
@@ -12,6 +13,7 @@
 //! - Support for all time expression types
 //! - Proper handling of month/year arithmetic
 //! - Timezone support (UTC and fixed offsets)
+//! - Configurable target time zone (`Local`, `Utc`, `FixedOffset`, `chrono_tz::Tz`)
 //! - DST-aware local time handling
 //!
 //! ## Example
@@ -25,11 +27,67 @@
 //! println!("In 5 minutes: {}", datetime);
 //!
 //! // Or use the provider directly
-//! let provider = ChronoProvider;
+//! let provider = ChronoProvider::default();
 //! let expr = temps_core::parse("tomorrow at 3:30 pm", Language::English).unwrap();
 //! let datetime = provider.parse_expression(expr).unwrap();
 //! ```
 //!
+//! ## Reference Time
+//!
+//! By default all expressions are resolved against `Local::now()`. A fixed
+//! reference time or a custom [`Clock`] makes resolution deterministic:
+//!
+//! ```
+//! use chrono::{Local, TimeZone};
+//! use temps_chrono::{ChronoProvider, parse_to_datetime_at};
+//! use temps_core::{Language, TimeParser};
+//!
+//! let reference = Local.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
+//!
+//! let provider = ChronoProvider::default().with_reference(reference);
+//! let expr = temps_core::parse("tomorrow", Language::English).unwrap();
+//! let tomorrow = provider.parse_expression(expr).unwrap();
+//!
+//! let later = parse_to_datetime_at("in 2 hours", Language::English, reference).unwrap();
+//! ```
+//!
+//! ## Time Zones
+//!
+//! Expressions are resolved in the provider's time zone, so "tomorrow at 9am"
+//! means 9am in that zone regardless of the host's time zone. With the
+//! `chrono-tz` feature, IANA time zones from [`chrono_tz`] can be used:
+//!
+//! ```
+//! # #[cfg(feature = "chrono-tz")] {
+//! use temps_chrono::{ChronoProvider, chrono_tz::Europe::Berlin};
+//! use temps_core::{Language, TimeParser};
+//!
+//! let provider = ChronoProvider::new(Berlin);
+//! let expr = temps_core::parse("tomorrow at 9:00 am", Language::English).unwrap();
+//! let datetime = provider.parse_expression(expr).unwrap();
+//! # }
+//! ```
+//!
+//! ## Daylight Saving Time
+//!
+//! Local times that fall into a DST gap (skipped) or fold (repeated) are
+//! resolved according to the provider's [`Disambiguation`] policy. The
+//! default, [`Disambiguation::Compatible`], moves skipped times forward and
+//! picks the earlier of two repeated times. Use
+//! [`ChronoProvider::parse_expression_with_fold`] to learn which choice was made:
+//!
+//! ```
+//! # #[cfg(feature = "chrono-tz")] {
+//! use temps_chrono::{ChronoProvider, chrono_tz::Europe::Berlin};
+//! use temps_core::{Disambiguation, Fold, FoldChoice, Language};
+//!
+//! let provider = ChronoProvider::new(Berlin).with_disambiguation(Disambiguation::Later);
+//! let expr = temps_core::parse("2024-10-27T02:30:00", Language::English).unwrap();
+//! let (datetime, fold) = provider.parse_expression_with_fold(expr).unwrap();
+//! assert_eq!(fold, Fold::Overlap(FoldChoice::Later));
+//! # }
+//! ```
+//!
 //! ## Month and Year Arithmetic
 //!
 //! This implementation uses chrono's `checked_add_months` and `checked_sub_months`
@@ -40,322 +98,1308 @@
 //!
 //! ## Error Handling
 //!
-//! All parsing operations return `Result<DateTime<Local>, TempsError>`. Common errors include:
+//! All parsing operations return `Result<DateTime<Tz>, TempsError>`. Common errors include:
 //!
 //! - `ParseError`: Invalid input that cannot be parsed
 //! - `DateCalculationError`: Date arithmetic that results in invalid dates
-//! - `AmbiguousTime`: Local times that are ambiguous due to DST transitions
+//! - `AmbiguousTime`: Local times skipped or repeated by DST transitions when
+//!   the provider uses [`Disambiguation::Reject`]
 //! - `InvalidDate`/`InvalidTime`: Components that are out of valid ranges
 
-use chrono::{DateTime, Datelike, Duration, Local, Months};
+use std::sync::Arc;
+
+#[cfg(feature = "chrono-tz")]
+pub use chrono_tz;
+
+#[cfg(feature = "serde")]
+pub mod de;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Local, LocalResult, Months, NaiveDate,
+    NaiveDateTime, NaiveTime, Offset, TimeDelta, TimeZone, Timelike, Utc,
+};
 use temps_core::{
-    DayReference, Direction, Language, Result, TempsError, TimeExpression, TimeParser, TimeUnit,
-    Weekday,
-    constants::MONTHS_PER_YEAR,
+    AbsoluteTime, Boundary, Clock, CronSchedule, DayReference, DayTime, Direction, Disambiguation,
+    Edge, Endpoint, FixedClock, Fold, FoldChoice, Frequency, HumanizeOptions, Interval, Language,
+    MonthDay, ParseOptions, Period, PeriodEnd, PeriodUnit, RRule, RRuleEnd, Recurrence,
+    RecurrenceEnd, RelativeDuration, RelativeTime, Result, Rounding, StandardDate, TempsError,
+    Time, TimeExpression, TimeParser, TimeUnit, Timezone, WeekStart, Weekday, YearPolicy,
+    constants::{DAYS_PER_WEEK, MONTHS_PER_YEAR},
+    cron::cron_times,
     errors::*,
+    format_relative,
+    rrule::rrule_occurrences,
     time_utils::{
-        calculate_timezone_offset_seconds, calculate_weekday_offset, convert_12_to_24_hour,
-        is_valid_time, is_valid_timezone_offset,
+        calculate_timezone_offset_seconds, calculate_week_weekday_offset, calculate_weekday_offset,
+        convert_12_to_24_hour, days_from_week_start, is_valid_time, is_valid_timezone_offset,
+        nominal_seconds, period_month_bounds, recurrence_dates, resolve_month_day,
     },
 };
 
 /// Chrono-based implementation of the TimeParser trait.
 ///
-/// This provider uses chrono's `DateTime<Local>` as its datetime type,
-/// providing full support for timezones, DST, and proper date arithmetic.
+/// This provider resolves expressions in a configurable chrono time zone and
+/// yields `DateTime<Tz>`, providing full support for timezones, DST, and proper
+/// date arithmetic. The default provider uses the system's local time zone.
+///
+/// Relative expressions are resolved against the system clock unless a
+/// reference time or custom [`Clock`] is configured.
 ///
 /// ## Example
 ///
 /// ```
+/// use chrono::Utc;
 /// use temps_chrono::ChronoProvider;
 /// use temps_core::{TimeParser, parse, Language};
 ///
-/// let provider = ChronoProvider;
+/// let provider = ChronoProvider::default();
 /// let expr = parse("next Monday", Language::English).unwrap();
 /// let datetime = provider.parse_expression(expr).unwrap();
+///
+/// // Resolve "tomorrow at 9am" in UTC instead of the host's time zone
+/// let provider = ChronoProvider::new(Utc);
+/// let expr = parse("tomorrow at 9:00 am", Language::English).unwrap();
+/// let datetime = provider.parse_expression(expr).unwrap();
 /// ```
-pub struct ChronoProvider;
+#[derive(Clone)]
+pub struct ChronoProvider<Tz: TimeZone = Local> {
+    timezone: Tz,
+    clock: Option<Arc<dyn Clock<DateTime<Tz>>>>,
+    disambiguation: Disambiguation,
+    year_policy: YearPolicy,
+    period_end: PeriodEnd,
+    week_start: WeekStart,
+}
 
-impl TimeParser for ChronoProvider {
-    type DateTime = DateTime<Local>;
+impl Default for ChronoProvider<Local> {
+    fn default() -> Self {
+        Self::new(Local)
+    }
+}
 
-    fn now(&self) -> Self::DateTime {
-        Local::now()
+impl<Tz: TimeZone> ChronoProvider<Tz> {
+    /// Create a provider that resolves expressions in the given time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    /// use temps_chrono::ChronoProvider;
+    ///
+    /// let provider = ChronoProvider::new(FixedOffset::east_opt(2 * 3600).unwrap());
+    /// ```
+    #[must_use]
+    pub fn new(timezone: Tz) -> Self {
+        Self {
+            timezone,
+            clock: None,
+            disambiguation: Disambiguation::default(),
+            year_policy: YearPolicy::default(),
+            period_end: PeriodEnd::default(),
+            week_start: WeekStart::default(),
+        }
     }
 
-    fn parse_expression(&self, expr: TimeExpression) -> Result<Self::DateTime> {
+    /// The time zone expressions are resolved in.
+    #[must_use]
+    pub fn timezone(&self) -> &Tz {
+        &self.timezone
+    }
+
+    /// Resolve expressions against the given clock instead of the system clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Local, TimeZone};
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::TimeParser;
+    ///
+    /// let provider = ChronoProvider::default()
+    ///     .with_clock(|| Local.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap());
+    /// assert_eq!(provider.now(), Local.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap());
+    /// ```
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl Clock<DateTime<Tz>> + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Resolve expressions against a fixed reference time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Local, TimeZone};
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::TimeParser;
+    ///
+    /// let reference = Local.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
+    /// let provider = ChronoProvider::default().with_reference(reference);
+    /// assert_eq!(provider.now(), reference);
+    /// ```
+    #[must_use]
+    pub fn with_reference(self, reference: DateTime<Tz>) -> Self
+    where
+        DateTime<Tz>: Send + Sync + 'static,
+    {
+        self.with_clock(FixedClock(reference))
+    }
+
+    /// The same provider, resolving expressions in another time zone
+    #[cfg(feature = "serde")]
+    pub(crate) fn in_timezone<Other>(self, timezone: Other) -> ChronoProvider<Other>
+    where
+        Tz: 'static,
+        Other: TimeZone + Send + Sync + 'static,
+    {
+        let clock = self.clock.map(|clock| {
+            let timezone = timezone.clone();
+            Arc::new(move || clock.now().with_timezone(&timezone))
+                as Arc<dyn Clock<DateTime<Other>>>
+        });
+        ChronoProvider {
+            timezone,
+            clock,
+            disambiguation: self.disambiguation,
+            year_policy: self.year_policy,
+            period_end: self.period_end,
+            week_start: self.week_start,
+        }
+    }
+
+    /// Use the given policy for local times skipped or repeated by DST transitions.
+    ///
+    /// The default is [`Disambiguation::Compatible`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::Disambiguation;
+    ///
+    /// let provider = ChronoProvider::default().with_disambiguation(Disambiguation::Reject);
+    /// ```
+    #[must_use]
+    pub fn with_disambiguation(mut self, disambiguation: Disambiguation) -> Self {
+        self.disambiguation = disambiguation;
+        self
+    }
+
+    /// Use the given policy to pick the year of dates written without one.
+    ///
+    /// The default is [`YearPolicy::Next`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::YearPolicy;
+    ///
+    /// // "March 5" in a list of past events
+    /// let provider = ChronoProvider::default().with_year_policy(YearPolicy::Previous);
+    /// ```
+    #[must_use]
+    pub fn with_year_policy(mut self, year_policy: YearPolicy) -> Self {
+        self.year_policy = year_policy;
+        self
+    }
+
+    /// Use the given instant for the end of a period ("end of month", "EOD").
+    ///
+    /// The default is [`PeriodEnd::LastNanosecond`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::{Language, PeriodEnd, TimeParser, parse};
+    ///
+    /// let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0).unwrap();
+    /// let provider = ChronoProvider::new(Utc)
+    ///     .with_reference(reference)
+    ///     .with_period_end(PeriodEnd::NextStart);
+    ///
+    /// let expr = parse("end of month", Language::English).unwrap();
+    /// let end = provider.parse_expression(expr).unwrap();
+    /// assert_eq!(end, Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap());
+    /// ```
+    #[must_use]
+    pub fn with_period_end(mut self, period_end: PeriodEnd) -> Self {
+        self.period_end = period_end;
+        self
+    }
+
+    /// Use the given first day of the week for "this week", "next week Monday"
+    /// and the like.
+    ///
+    /// The default is [`WeekStart::Monday`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::WeekStart;
+    ///
+    /// // "this week" runs from Sunday to Saturday
+    /// let provider = ChronoProvider::default().with_week_start(WeekStart::Sunday);
+    /// ```
+    #[must_use]
+    pub fn with_week_start(mut self, week_start: WeekStart) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Parse a time expression and report how its local time was disambiguated.
+    ///
+    /// Works like [`TimeParser::parse_expression`], but also returns the [`Fold`]
+    /// that was chosen when the expression named a local time that is skipped or
+    /// repeated by a DST transition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::{Fold, Language, parse};
+    ///
+    /// let provider = ChronoProvider::new(FixedOffset::east_opt(0).unwrap());
+    /// let expr = parse("today at 2:30 am", Language::English).unwrap();
+    /// let (_, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    /// assert_eq!(fold, Fold::Unambiguous);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`TimeParser::parse_expression`], including
+    /// `TempsError::AmbiguousTime` when the policy is [`Disambiguation::Reject`]
+    /// and the local time is skipped or repeated.
+    pub fn parse_expression_with_fold(&self, expr: TimeExpression) -> Result<(DateTime<Tz>, Fold)> {
+        match expr {
+            TimeExpression::Now => Ok((self.now(), Fold::Unambiguous)),
+            TimeExpression::Relative(rel) => Ok((self.resolve_relative(rel)?, Fold::Unambiguous)),
+            TimeExpression::RelativeDuration(rel) => {
+                Ok((self.resolve_relative_duration(rel)?, Fold::Unambiguous))
+            }
+            TimeExpression::Absolute(abs) => self.resolve_absolute(abs),
+            TimeExpression::Day(day_ref) => self.start_of_day(self.resolve_day(day_ref)?),
+            TimeExpression::Time(time) => {
+                let today = self.now().date_naive();
+                self.localize(&Self::at_time(today, time)?)
+            }
+            TimeExpression::DayTime(day_time) => {
+                let date = self.resolve_day(day_time.day)?;
+                self.localize(&Self::at_time(date, day_time.time)?)
+            }
+            TimeExpression::Date(date) => self.start_of_day(to_naive_date(date)?),
+            TimeExpression::MonthDay(month_day) => {
+                let today = self.now().date_naive();
+                self.start_of_day(Self::resolve_month_day(today, month_day, self.year_policy)?)
+            }
+            // Intervals and periods on their own resolve to their start
+            TimeExpression::Interval(interval) => {
+                self.parse_expression_with_fold(interval.start.into())
+            }
+            TimeExpression::Period(period) => self.start_of_day(self.resolve_period(period)?.0),
+            TimeExpression::Boundary(boundary) => self.resolve_boundary(boundary),
+            // Recurrences resolve to their next occurrence
+            TimeExpression::Recurrence(recurrence) => self
+                .resolve_occurrences(recurrence)?
+                .next()
+                .unwrap_or_else(|| Err(TempsError::date_calculation(ERR_NO_OCCURRENCES))),
+        }
+    }
+
+    /// Resolve an interval or period expression to its start and end.
+    ///
+    /// Intervals are half-open: an end that names a whole day includes that
+    /// day, so "from Monday to Friday" ends at midnight after Friday. A bare
+    /// time, an unmodified weekday or a date without a year at the end is
+    /// resolved from the start, so "from 10pm to 2am" ends the next day.
+    /// Periods ("last week", "this month") cover the whole calendar period,
+    /// from midnight on its first day to midnight on the first day after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::{Language, parse};
+    ///
+    /// let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0).unwrap();
+    /// let provider = ChronoProvider::new(Utc).with_reference(reference);
+    ///
+    /// let expr = parse("from tomorrow at 3pm to 5pm", Language::English).unwrap();
+    /// let (start, end) = provider.parse_interval(expr).unwrap();
+    /// assert_eq!(start, Utc.with_ymd_and_hms(2024, 3, 16, 15, 0, 0).unwrap());
+    /// assert_eq!(end, Utc.with_ymd_and_hms(2024, 3, 16, 17, 0, 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::UnsupportedOperation` if the expression is neither
+    /// an interval nor a period, `TempsError::DateCalculationError` if it ends before it
+    /// starts, and the errors of [`TimeParser::parse_expression`] otherwise.
+    pub fn parse_interval(&self, expr: TimeExpression) -> Result<(DateTime<Tz>, DateTime<Tz>)> {
         match expr {
-            TimeExpression::Now => Ok(self.now()),
-            TimeExpression::Relative(rel) => {
-                if rel.amount < 0 {
-                    return Err(TempsError::date_calculation(
-                        ERR_RELATIVE_AMOUNT_NON_NEGATIVE,
-                    ));
+            TimeExpression::Interval(interval) => self.resolve_interval(interval),
+            TimeExpression::Period(period) => {
+                let (start, end) = self.resolve_period(period)?;
+                Ok((self.start_of_day(start)?.0, self.start_of_day(end)?.0))
+            }
+            _ => Err(TempsError::unsupported_operation(ERR_NOT_AN_INTERVAL)),
+        }
+    }
+
+    /// Resolve the occurrences of a recurrence at or after the reference time.
+    ///
+    /// Daily and longer recurrences fall on midnight unless they name a time
+    /// of day. Minutely and hourly recurrences step in absolute time from
+    /// midnight on the reference date, so "every 15 minutes" falls on the
+    /// quarter hours. The iterator ends with the count or until date of the
+    /// recurrence; take as many occurrences as needed otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::{Language, Result, parse};
+    ///
+    /// // Friday
+    /// let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0).unwrap();
+    /// let provider = ChronoProvider::new(Utc).with_reference(reference);
+    ///
+    /// let expr = parse("every Monday at 9am", Language::English).unwrap();
+    /// let next = provider.occurrences(expr).unwrap().take(2).collect::<Result<Vec<_>>>();
+    /// assert_eq!(
+    ///     next.unwrap(),
+    ///     [
+    ///         Utc.with_ymd_and_hms(2024, 3, 18, 9, 0, 0).unwrap(),
+    ///         Utc.with_ymd_and_hms(2024, 3, 25, 9, 0, 0).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::UnsupportedOperation` if the expression is not a
+    /// recurrence. An occurrence that cannot be resolved, such as a local time
+    /// skipped by DST under [`Disambiguation::Reject`], is yielded as an error
+    /// and ends the iteration.
+    pub fn occurrences(
+        &self,
+        expr: TimeExpression,
+    ) -> Result<impl Iterator<Item = Result<DateTime<Tz>>> + '_> {
+        let TimeExpression::Recurrence(recurrence) = expr else {
+            return Err(TempsError::unsupported_operation(ERR_NOT_A_RECURRENCE));
+        };
+        Ok(self
+            .resolve_occurrences(recurrence)?
+            .map(|occurrence| occurrence.map(|(datetime, _)| datetime)))
+    }
+
+    /// Resolve the occurrences of an iCalendar recurrence rule at or after
+    /// the reference time.
+    ///
+    /// The rule is expanded in the provider's time zone from its `DTSTART`,
+    /// or from the reference time if it has none, so occurrences keep their
+    /// local time of day across DST changes. UTC start and until times are
+    /// converted to the provider's time zone; local ones, including those
+    /// with a `TZID`, are read in it. `COUNT` counts occurrences from the
+    /// start, including those before the reference time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use chrono_tz::Europe::Berlin;
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::{Result, parse_rrule};
+    ///
+    /// let reference = Berlin.with_ymd_and_hms(2024, 3, 29, 12, 0, 0).unwrap();
+    /// let provider = ChronoProvider::new(Berlin).with_reference(reference);
+    ///
+    /// // Berlin switches to summer time on March 31
+    /// let rule = parse_rrule("DTSTART:20240301T090000\nRRULE:FREQ=DAILY;COUNT=31").unwrap();
+    /// let next = provider.rrule_occurrences(&rule).unwrap().collect::<Result<Vec<_>>>();
+    /// assert_eq!(
+    ///     next.unwrap(),
+    ///     [
+    ///         Berlin.with_ymd_and_hms(2024, 3, 30, 9, 0, 0).unwrap(),
+    ///         Berlin.with_ymd_and_hms(2024, 3, 31, 9, 0, 0).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the start or until time cannot be resolved. A
+    /// local time skipped by DST moves according to the provider's
+    /// [`Disambiguation`], and is left out if that lands on the previous
+    /// occurrence; under [`Disambiguation::Reject`] it is yielded as an error
+    /// and ends the iteration.
+    pub fn rrule_occurrences<'a>(
+        &'a self,
+        rule: &'a RRule,
+    ) -> Result<impl Iterator<Item = Result<DateTime<Tz>>> + 'a> {
+        let now = self.now();
+        let start = match rule.start {
+            // Local start times keep their time of day even if DST skips it
+            Some(start) if start.timezone.is_none() => {
+                let date = StandardDate {
+                    day: start.day,
+                    month: start.month,
+                    year: start.year,
+                };
+                let time = Time {
+                    hour: start.hour.unwrap_or(0),
+                    minute: start.minute.unwrap_or(0),
+                    second: start.second.unwrap_or(0),
+                    meridiem: None,
+                };
+                (date, time)
+            }
+            start => {
+                let start = match start {
+                    Some(start) => self.resolve_absolute(start)?.0,
+                    None => now.clone(),
+                };
+                let time = Time {
+                    hour: start.hour() as u8,
+                    minute: start.minute() as u8,
+                    second: start.second() as u8,
+                    meridiem: None,
+                };
+                (from_naive_date(start.date_naive())?, time)
+            }
+        };
+        let until = match rule.end {
+            // Until a date includes the whole day
+            Some(RRuleEnd::Until(until)) if until.hour.is_none() => {
+                let date = self.resolve_absolute(until)?.0.date_naive();
+                let next_day = self.start_of_next_day(date)?;
+                Some(
+                    next_day
+                        .checked_sub_signed(Duration::nanoseconds(1))
+                        .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID))?,
+                )
+            }
+            Some(RRuleEnd::Until(until)) => Some(self.resolve_absolute(until)?.0),
+            _ => None,
+        };
+        let count = match rule.end {
+            Some(RRuleEnd::Count(count)) => count as usize,
+            _ => usize::MAX,
+        };
+
+        let mut previous: Option<DateTime<Tz>> = None;
+        let occurrences = rrule_occurrences(rule, start.0, start.1)
+            .map(move |(date, time)| self.localize(&Self::at_time(to_naive_date(date)?, time)?))
+            // A local time skipped by DST can land on the previous occurrence
+            .filter(move |occurrence| match occurrence {
+                Ok((datetime, _)) => {
+                    let later = previous.as_ref().is_none_or(|previous| datetime > previous);
+                    if later {
+                        previous = Some(datetime.clone());
+                    }
+                    later
                 }
+                Err(_) => true,
+            })
+            .take_while(move |occurrence| match (occurrence, &until) {
+                (Ok((datetime, _)), Some(until)) => datetime <= until,
+                _ => true,
+            })
+            .take(count)
+            .filter(move |occurrence| !matches!(occurrence, Ok((datetime, _)) if *datetime < now))
+            .map(|occurrence| occurrence.map(|(datetime, _)| datetime));
+        Ok(end_at_error(occurrences))
+    }
+
+    /// Resolve the fire times of a cron schedule strictly after `after`, in
+    /// ascending order.
+    ///
+    /// The schedule is matched against local wall time in the provider's time
+    /// zone, so `0 9 * * *` fires at 9:00 local time on both sides of a DST
+    /// change. A local time repeated by DST fires once, at the instant chosen
+    /// by the provider's [`Disambiguation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use chrono_tz::Europe::Berlin;
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::parse_cron;
+    ///
+    /// let provider = ChronoProvider::new(Berlin);
+    /// let schedule = parse_cron("*/15 9-17 * * MON-FRI").unwrap();
+    ///
+    /// // Friday evening
+    /// let after = Berlin.with_ymd_and_hms(2024, 3, 15, 17, 50, 0).unwrap();
+    /// let mut next = provider.next_after(schedule, &after).unwrap();
+    /// assert_eq!(next.next().unwrap().unwrap(), Berlin.with_ymd_and_hms(2024, 3, 18, 9, 0, 0).unwrap());
+    /// assert_eq!(next.next().unwrap().unwrap(), Berlin.with_ymd_and_hms(2024, 3, 18, 9, 15, 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `after` is outside the supported date range. A
+    /// local time skipped by DST moves according to the provider's
+    /// [`Disambiguation`], and is left out if that lands on a time already
+    /// yielded; under [`Disambiguation::Reject`] it is yielded as an error and
+    /// ends the iteration.
+    pub fn next_after<Tz2: TimeZone>(
+        &self,
+        schedule: CronSchedule,
+        after: &DateTime<Tz2>,
+    ) -> Result<impl Iterator<Item = Result<DateTime<Tz>>> + '_> {
+        self.resolve_cron(schedule, after, Direction::Future)
+    }
+
+    /// Resolve the fire times of a cron schedule strictly before `before`, in
+    /// descending order.
+    ///
+    /// This is the reverse of [`next_after`](Self::next_after), with the same
+    /// handling of time zones and DST.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::parse_cron;
+    ///
+    /// let provider = ChronoProvider::new(Utc);
+    /// let schedule = parse_cron("@daily").unwrap();
+    ///
+    /// let before = Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap();
+    /// let mut previous = provider.prev_before(schedule, &before).unwrap();
+    /// assert_eq!(previous.next().unwrap().unwrap(), Utc.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap());
+    /// assert_eq!(previous.next().unwrap().unwrap(), Utc.with_ymd_and_hms(2024, 3, 13, 0, 0, 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// As for [`next_after`](Self::next_after).
+    pub fn prev_before<Tz2: TimeZone>(
+        &self,
+        schedule: CronSchedule,
+        before: &DateTime<Tz2>,
+    ) -> Result<impl Iterator<Item = Result<DateTime<Tz>>> + '_> {
+        self.resolve_cron(schedule, before, Direction::Past)
+    }
 
-                let now = self.now();
-
-                // Handle months and years separately for proper date arithmetic
-                match rel.unit {
-                    TimeUnit::Month => {
-                        let months = Months::new(
-                            rel.amount
-                                .try_into()
-                                .map_err(|_| TempsError::date_calculation(ERR_MONTH_POSITIVE))?,
-                        );
-
-                        match rel.direction {
-                            Direction::Past => now
-                                .checked_sub_months(months)
-                                .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID)),
-                            Direction::Future => now
-                                .checked_add_months(months)
-                                .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID)),
-                        }
+    /// Describe a point in time relative to the reference time, such as
+    /// "in 5 minutes" or "vor 2 Tagen".
+    ///
+    /// The text uses the largest units first and is rounded as set in the
+    /// [`HumanizeOptions`]; a time closer than the smallest unit is "now".
+    /// [`parse`](temps_core::parse) reads the text back, and this provider
+    /// resolves it to the rounded time: months and years are calendar months
+    /// and years, as when parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    /// use temps_chrono::ChronoProvider;
+    /// use temps_core::{HumanizeOptions, Language, Rounding, TimeParser, TimeUnit, parse};
+    ///
+    /// let reference = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+    /// let provider = ChronoProvider::new(Utc).with_reference(reference);
+    ///
+    /// let later = reference + TimeDelta::minutes(150);
+    /// assert_eq!(provider.humanize(&later, Language::English).unwrap(), "in 3 hours");
+    ///
+    /// let options = HumanizeOptions::new(Language::German)
+    ///     .with_max_units(2)
+    ///     .with_smallest_unit(TimeUnit::Minute)
+    ///     .with_rounding(Rounding::Down);
+    /// let text = provider.humanize(&later, options).unwrap();
+    /// assert_eq!(text, "in 2 Stunden 30 Minuten");
+    ///
+    /// // The text resolves back to the same time
+    /// let expr = parse(&text, Language::German).unwrap();
+    /// assert_eq!(provider.parse_expression(expr).unwrap(), later);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rounded time is outside the supported date
+    /// range.
+    pub fn humanize<Tz2: TimeZone>(
+        &self,
+        datetime: &DateTime<Tz2>,
+        options: impl Into<HumanizeOptions>,
+    ) -> Result<String> {
+        let options = options.into();
+        let relative = self.relative_to_now(&datetime.with_timezone(&self.timezone), &options)?;
+        format_relative(&relative, options.language())
+    }
+
+    fn resolve_interval(&self, interval: Interval) -> Result<(DateTime<Tz>, DateTime<Tz>)> {
+        let (start, _) = self.parse_expression_with_fold(interval.start.into())?;
+        let start_date = start.date_naive();
+        let invalid = || TempsError::date_calculation(ERR_DATE_CALC_INVALID);
+
+        let end =
+            match interval.end {
+                // "from tomorrow at 3pm to 5pm", "from 10pm to 2am"
+                Endpoint::Time(time) => {
+                    let (end, _) = self.localize(&Self::at_time(start_date, time)?)?;
+                    if end > start {
+                        end
+                    } else {
+                        let next_day = start_date.succ_opt().ok_or_else(invalid)?;
+                        self.localize(&Self::at_time(next_day, time)?)?.0
                     }
-                    TimeUnit::Year => {
-                        // Convert years to months for proper arithmetic
-                        let months_count = rel
-                            .amount
-                            .checked_mul(MONTHS_PER_YEAR as i64)
-                            .ok_or_else(|| TempsError::arithmetic_overflow(ERR_YEAR_OVERFLOW))?;
-                        let months = Months::new(
-                            months_count
-                                .try_into()
-                                .map_err(|_| TempsError::date_calculation(ERR_YEAR_POSITIVE))?,
-                        );
-
-                        match rel.direction {
-                            Direction::Past => now
-                                .checked_sub_months(months)
-                                .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID)),
-                            Direction::Future => now
-                                .checked_add_months(months)
-                                .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID)),
-                        }
+                }
+                // "from Friday at 9am to Monday at 5pm"
+                Endpoint::DayTime(DayTime {
+                    day:
+                        DayReference::Weekday {
+                            day,
+                            modifier: None,
+                        },
+                    time,
+                }) => {
+                    let date = Self::weekday_from(start_date, day)?;
+                    self.localize(&Self::at_time(date, time)?)?.0
+                }
+                // Whole days include the last day
+                Endpoint::Day(DayReference::Weekday {
+                    day,
+                    modifier: None,
+                }) => self.start_of_next_day(Self::weekday_from(start_date, day)?)?,
+                Endpoint::Day(day_ref) => self.start_of_next_day(self.resolve_day(day_ref)?)?,
+                Endpoint::Date(date) => self.start_of_next_day(to_naive_date(date)?)?,
+                Endpoint::MonthDay(month_day) => self.start_of_next_day(
+                    Self::resolve_month_day(start_date, month_day, YearPolicy::Next)?,
+                )?,
+                end => self.parse_expression_with_fold(end.into())?.0,
+            };
+
+        if end < start {
+            return Err(TempsError::date_calculation(ERR_INTERVAL_END_BEFORE_START));
+        }
+        Ok((start, end))
+    }
+
+    fn resolve_occurrences(
+        &self,
+        recurrence: Recurrence,
+    ) -> Result<impl Iterator<Item = Result<(DateTime<Tz>, Fold)>> + '_> {
+        let now = self.now();
+        let today = now.date_naive();
+        let time = recurrence.time.unwrap_or(Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            meridiem: None,
+        });
+        let (until, count) = match recurrence.end {
+            Some(RecurrenceEnd::Until(date)) => (Some(to_naive_date(date)?), usize::MAX),
+            Some(RecurrenceEnd::UntilMonthDay(month_day)) => (
+                Some(Self::resolve_month_day(today, month_day, YearPolicy::Next)?),
+                usize::MAX,
+            ),
+            Some(RecurrenceEnd::Count(count)) => (None, count as usize),
+            None => (None, usize::MAX),
+        };
+
+        let occurrences: Box<dyn Iterator<Item = Result<(DateTime<Tz>, Fold)>> + '_> =
+            match recurrence.frequency {
+                Frequency::Minutely | Frequency::Hourly => {
+                    let interval = i64::from(recurrence.interval.max(1));
+                    let step = match recurrence.frequency {
+                        Frequency::Minutely => TimeDelta::try_minutes(interval),
+                        _ => TimeDelta::try_hours(interval),
                     }
-                    _ => {
-                        // Use Duration for time units that have fixed lengths
-                        let duration = match rel.unit {
-                            TimeUnit::Second => Duration::seconds(rel.amount),
-                            TimeUnit::Minute => Duration::minutes(rel.amount),
-                            TimeUnit::Hour => Duration::hours(rel.amount),
-                            TimeUnit::Day => Duration::days(rel.amount),
-                            TimeUnit::Week => Duration::weeks(rel.amount),
-                            _ => unreachable!(), // Month and Year handled above
-                        };
-
-                        match rel.direction {
-                            Direction::Past => Ok(now - duration),
-                            Direction::Future => Ok(now + duration),
-                        }
+                    .ok_or_else(|| TempsError::arithmetic_overflow(ERR_DURATION_OVERFLOW))?;
+                    let weekdays = recurrence.weekdays;
+                    let first = self.localize(&Self::at_time(today, time)?)?;
+                    Box::new(
+                        std::iter::successors(Some(first), move |(datetime, _)| {
+                            Some((
+                                datetime.clone().checked_add_signed(step)?,
+                                Fold::Unambiguous,
+                            ))
+                        })
+                        .take_while(move |(datetime, _)| {
+                            until.is_none_or(|until| datetime.date_naive() <= until)
+                        })
+                        .filter(move |(datetime, _)| {
+                            weekdays.is_empty()
+                                || weekdays
+                                    .iter()
+                                    .any(|day| to_chrono_weekday(day) == datetime.weekday())
+                        })
+                        .map(Ok),
+                    )
+                }
+                _ => {
+                    let dates =
+                        recurrence_dates(from_naive_date(today)?, recurrence, self.week_start);
+                    Box::new(dates.map(move |date| {
+                        self.localize(&Self::at_time(to_naive_date(date)?, time)?)
+                    }))
+                }
+            };
+
+        Ok(end_at_error(
+            occurrences
+                .filter(
+                    move |occurrence| !matches!(occurrence, Ok((datetime, _)) if *datetime < now),
+                )
+                .take(count),
+        ))
+    }
+
+    fn resolve_cron<Tz2: TimeZone>(
+        &self,
+        schedule: CronSchedule,
+        from: &DateTime<Tz2>,
+        direction: Direction,
+    ) -> Result<impl Iterator<Item = Result<DateTime<Tz>>> + '_> {
+        let from = from.with_timezone(&self.timezone);
+        let time = Time {
+            hour: from.hour() as u8,
+            minute: from.minute() as u8,
+            second: from.second() as u8,
+            meridiem: None,
+        };
+        let date = from_naive_date(from.date_naive())?;
+
+        // Each time must pass the last, which also drops the local time at
+        // `from` and skipped local times moved onto a time already yielded
+        let mut bound = from;
+        let times = cron_times(schedule, date, time, direction)
+            .map(move |(date, time)| self.localize(&Self::at_time(to_naive_date(date)?, time)?))
+            .filter(move |time| match time {
+                Ok((datetime, _)) => {
+                    let passes = match direction {
+                        Direction::Future => *datetime > bound,
+                        Direction::Past => *datetime < bound,
+                    };
+                    if passes {
+                        bound = datetime.clone();
                     }
+                    passes
                 }
+                Err(_) => true,
+            })
+            .map(|time| time.map(|(datetime, _)| datetime));
+        Ok(end_at_error(times))
+    }
+
+    /// Split the time from now to `target` into the units of `options`,
+    /// rounding the last one
+    fn relative_to_now(
+        &self,
+        target: &DateTime<Tz>,
+        options: &HumanizeOptions,
+    ) -> Result<RelativeDuration> {
+        let now = self.now();
+        let direction = if *target < now {
+            Direction::Past
+        } else {
+            Direction::Future
+        };
+        let (duration, last, reached) = Self::split_units(&now, target, direction, options)?;
+        let round_up = match options.rounding() {
+            Rounding::Down => false,
+            Rounding::Nearest => {
+                let next = Self::shift(reached.clone(), 1, last, direction)?;
+                (target.clone() - reached.clone()).abs() * 2 >= (next - reached.clone()).abs()
             }
-            TimeExpression::Absolute(abs) => {
-                use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
-
-                let date =
-                    NaiveDate::from_ymd_opt(abs.year as i32, abs.month as u32, abs.day as u32)
-                        .ok_or_else(|| TempsError::invalid_date(abs.year, abs.month, abs.day))?;
-
-                let datetime = if let (Some(hour), Some(minute)) = (abs.hour, abs.minute) {
-                    let time = NaiveTime::from_hms_nano_opt(
-                        hour as u32,
-                        minute as u32,
-                        abs.second.unwrap_or(0) as u32,
-                        abs.nanosecond.unwrap_or(0),
-                    )
-                    .ok_or_else(|| {
-                        TempsError::invalid_time(hour, minute, abs.second.unwrap_or(0))
-                    })?;
-
-                    let naive_dt = NaiveDateTime::new(date, time);
-
-                    match &abs.timezone {
-                        Some(temps_core::Timezone::Utc) => {
-                            Utc.from_utc_datetime(&naive_dt).with_timezone(&Local)
-                        }
-                        Some(temps_core::Timezone::Offset { hours, minutes }) => {
-                            if !is_valid_timezone_offset(temps_core::Timezone::Offset {
-                                hours: *hours,
-                                minutes: *minutes,
-                            }) {
-                                return Err(TempsError::invalid_timezone_offset(*hours, *minutes));
-                            }
-
-                            let offset_seconds =
-                                calculate_timezone_offset_seconds(*hours, *minutes);
-                            let offset =
-                                FixedOffset::east_opt(offset_seconds).ok_or_else(|| {
-                                    TempsError::invalid_timezone_offset(*hours, *minutes)
-                                })?;
-                            offset
-                                .from_local_datetime(&naive_dt)
-                                .single()
-                                .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))?
-                                .with_timezone(&Local)
-                        }
-                        None => {
-                            // No timezone specified, treat as local time
-                            Local
-                                .from_local_datetime(&naive_dt)
-                                .single()
-                                .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))?
-                        }
+            Rounding::Up => reached != *target,
+        };
+
+        // Split again so that rounding up carries into larger units
+        let duration = if round_up {
+            let rounded = Self::shift(reached.clone(), 1, last, direction)?;
+            Self::split_units(&now, &rounded, direction, options)?.0
+        } else {
+            duration
+        };
+        Ok(RelativeDuration {
+            duration,
+            direction,
+        })
+    }
+
+    /// Take the largest amount of each unit of `options` that does not pass
+    /// `to`, returning the amounts, the last unit considered and the time
+    /// reached
+    fn split_units(
+        from: &DateTime<Tz>,
+        to: &DateTime<Tz>,
+        direction: Direction,
+        options: &HumanizeOptions,
+    ) -> Result<(temps_core::Duration, TimeUnit, DateTime<Tz>)> {
+        let mut duration = temps_core::Duration::default();
+        let mut reached = from.clone();
+        let mut last = options.smallest_unit();
+        let mut written = 0;
+        for unit in options.units() {
+            last = unit;
+            let fits = |amount| {
+                Self::shift(reached.clone(), amount, unit, direction).is_ok_and(|shifted| {
+                    match direction {
+                        Direction::Past => shifted >= *to,
+                        Direction::Future => shifted <= *to,
                     }
-                } else {
-                    // Date only, set time to midnight
-                    let midnight = date
-                        .and_hms_opt(0, 0, 0)
-                        .ok_or_else(|| TempsError::date_calculation(ERR_MIDNIGHT_FAILED))?;
-                    Local
-                        .from_local_datetime(&midnight)
-                        .single()
-                        .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))?
+                })
+            };
+            // Estimate from the unit's average length, then correct
+            let mut amount =
+                (to.clone() - reached.clone()).num_seconds().abs() / nominal_seconds(unit);
+            while amount > 0 && !fits(amount) {
+                amount -= 1;
+            }
+            while fits(amount + 1) {
+                amount += 1;
+            }
+
+            if amount > 0 {
+                reached = Self::shift(reached.clone(), amount, unit, direction)?;
+                duration = duration.with(unit, amount);
+                written += 1;
+                if written == options.max_units() {
+                    break;
+                }
+            }
+        }
+        Ok((duration, last, reached))
+    }
+
+    fn resolve_boundary(&self, boundary: Boundary) -> Result<(DateTime<Tz>, Fold)> {
+        let (start, end) = self.resolve_period(boundary.period)?;
+        match (boundary.edge, self.period_end) {
+            (Edge::Start, _) => self.start_of_day(start),
+            (Edge::End, PeriodEnd::NextStart) => self.start_of_day(end),
+            (Edge::End, PeriodEnd::LastNanosecond) => {
+                let (next_start, fold) = self.start_of_day(end)?;
+                let last = next_start
+                    .checked_sub_signed(Duration::nanoseconds(1))
+                    .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID))?;
+                Ok((last, fold))
+            }
+        }
+    }
+
+    /// Calculate the first day of a calendar period and the first day after it.
+    fn resolve_period(&self, period: Period) -> Result<(NaiveDate, NaiveDate)> {
+        let today = self.now().date_naive();
+        let invalid = || TempsError::date_calculation(ERR_DATE_CALC_INVALID);
+
+        match period.unit {
+            PeriodUnit::Day => {
+                let start = Duration::try_days(period.offset)
+                    .and_then(|days| today.checked_add_signed(days))
+                    .ok_or_else(invalid)?;
+                Ok((start, start.succ_opt().ok_or_else(invalid)?))
+            }
+            PeriodUnit::Week => {
+                let days = calculate_week_weekday_offset(
+                    days_from_week_start(
+                        today.weekday().num_days_from_monday() as i64,
+                        self.week_start,
+                    ),
+                    0,
+                    period.offset,
+                );
+                let start = Duration::try_days(days)
+                    .and_then(|days| today.checked_add_signed(days))
+                    .ok_or_else(invalid)?;
+                let end = start
+                    .checked_add_signed(Duration::days(i64::from(DAYS_PER_WEEK)))
+                    .ok_or_else(invalid)?;
+                Ok((start, end))
+            }
+            unit => {
+                let (start, end) =
+                    period_month_bounds(today.year(), today.month() as u8, unit, period.offset)
+                        .ok_or_else(invalid)?;
+                let first_day = |(year, month): (i32, u8)| {
+                    NaiveDate::from_ymd_opt(year, month as u32, 1).ok_or_else(invalid)
                 };
+                Ok((first_day(start)?, first_day(end)?))
+            }
+        }
+    }
+
+    fn start_of_day(&self, date: NaiveDate) -> Result<(DateTime<Tz>, Fold)> {
+        let midnight = date
+            .and_hms_opt(0, 0, 0)
+            .ok_or_else(|| TempsError::date_calculation(ERR_MIDNIGHT_FAILED))?;
+        self.localize(&midnight)
+    }
+
+    fn start_of_next_day(&self, date: NaiveDate) -> Result<DateTime<Tz>> {
+        let next_day = date
+            .succ_opt()
+            .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID))?;
+        Ok(self.start_of_day(next_day)?.0)
+    }
+
+    /// The first date on or after `date` that falls on `day`.
+    fn weekday_from(date: NaiveDate, day: Weekday) -> Result<NaiveDate> {
+        let days = calculate_weekday_offset(
+            date.weekday().num_days_from_monday() as i64,
+            to_chrono_weekday(day).num_days_from_monday() as i64,
+            None,
+        );
+        date.checked_add_signed(Duration::days(days))
+            .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID))
+    }
+
+    fn resolve_month_day(
+        today: NaiveDate,
+        month_day: MonthDay,
+        policy: YearPolicy,
+    ) -> Result<NaiveDate> {
+        let date = resolve_month_day(from_naive_date(today)?, month_day, policy)
+            .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID))?;
+        to_naive_date(date)
+    }
 
-                Ok(datetime)
+    fn resolve_relative(&self, rel: RelativeTime) -> Result<DateTime<Tz>> {
+        Self::shift(self.now(), rel.amount, rel.unit, rel.direction)
+    }
+
+    fn resolve_relative_duration(&self, rel: RelativeDuration) -> Result<DateTime<Tz>> {
+        // Apply the largest units first: "in 1 month 2 days" from January 30
+        // is March 2 (leap year), not March 1
+        let datetime = rel
+            .duration
+            .components()
+            .try_fold(self.now(), |datetime, (unit, amount)| {
+                Self::shift(datetime, amount, unit, rel.direction)
+            })?;
+
+        let nanoseconds = Duration::nanoseconds(rel.duration.nanoseconds);
+        match rel.direction {
+            Direction::Past => datetime.checked_sub_signed(nanoseconds),
+            Direction::Future => datetime.checked_add_signed(nanoseconds),
+        }
+        .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID))
+    }
+
+    /// Move a point in time by an amount of a single unit.
+    fn shift(
+        from: DateTime<Tz>,
+        amount: i64,
+        unit: TimeUnit,
+        direction: Direction,
+    ) -> Result<DateTime<Tz>> {
+        if amount < 0 {
+            return Err(TempsError::date_calculation(
+                ERR_RELATIVE_AMOUNT_NON_NEGATIVE,
+            ));
+        }
+
+        // Handle months and years separately for proper date arithmetic
+        match unit {
+            TimeUnit::Month => {
+                let months = Months::new(
+                    amount
+                        .try_into()
+                        .map_err(|_| TempsError::date_calculation(ERR_MONTH_POSITIVE))?,
+                );
+
+                match direction {
+                    Direction::Past => from
+                        .checked_sub_months(months)
+                        .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID)),
+                    Direction::Future => from
+                        .checked_add_months(months)
+                        .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID)),
+                }
             }
-            TimeExpression::Day(day_ref) => {
-                let now = self.now();
-                match day_ref {
-                    DayReference::Today => {
-                        let midnight = now
-                            .date_naive()
-                            .and_hms_opt(0, 0, 0)
-                            .ok_or_else(|| TempsError::date_calculation(ERR_MIDNIGHT_FAILED))?;
-                        midnight
-                            .and_local_timezone(Local)
-                            .single()
-                            .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))
-                    }
-                    DayReference::Yesterday => {
-                        let yesterday = now - Duration::days(1);
-                        let midnight = yesterday
-                            .date_naive()
-                            .and_hms_opt(0, 0, 0)
-                            .ok_or_else(|| TempsError::date_calculation(ERR_MIDNIGHT_FAILED))?;
-                        midnight
-                            .and_local_timezone(Local)
-                            .single()
-                            .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))
-                    }
-                    DayReference::Tomorrow => {
-                        let tomorrow = now + Duration::days(1);
-                        let midnight = tomorrow
-                            .date_naive()
-                            .and_hms_opt(0, 0, 0)
-                            .ok_or_else(|| TempsError::date_calculation(ERR_MIDNIGHT_FAILED))?;
-                        midnight
-                            .and_local_timezone(Local)
-                            .single()
-                            .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))
-                    }
-                    DayReference::Weekday { day, modifier } => {
-                        let target_weekday = match day {
-                            Weekday::Monday => chrono::Weekday::Mon,
-                            Weekday::Tuesday => chrono::Weekday::Tue,
-                            Weekday::Wednesday => chrono::Weekday::Wed,
-                            Weekday::Thursday => chrono::Weekday::Thu,
-                            Weekday::Friday => chrono::Weekday::Fri,
-                            Weekday::Saturday => chrono::Weekday::Sat,
-                            Weekday::Sunday => chrono::Weekday::Sun,
-                        };
-
-                        let current_weekday = now.weekday();
-                        let current_offset = current_weekday.num_days_from_monday() as i64;
-                        let target_offset = target_weekday.num_days_from_monday() as i64;
-
-                        let days_to_add =
-                            calculate_weekday_offset(current_offset, target_offset, modifier);
-                        let target_date = now + Duration::days(days_to_add);
-
-                        let midnight = target_date
-                            .date_naive()
-                            .and_hms_opt(0, 0, 0)
-                            .ok_or_else(|| TempsError::date_calculation(ERR_MIDNIGHT_FAILED))?;
-                        midnight
-                            .and_local_timezone(Local)
-                            .single()
-                            .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))
-                    }
+            TimeUnit::Year => {
+                // Convert years to months for proper arithmetic
+                let months_count = amount
+                    .checked_mul(MONTHS_PER_YEAR as i64)
+                    .ok_or_else(|| TempsError::arithmetic_overflow(ERR_YEAR_OVERFLOW))?;
+                let months = Months::new(
+                    months_count
+                        .try_into()
+                        .map_err(|_| TempsError::date_calculation(ERR_YEAR_POSITIVE))?,
+                );
+
+                match direction {
+                    Direction::Past => from
+                        .checked_sub_months(months)
+                        .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID)),
+                    Direction::Future => from
+                        .checked_add_months(months)
+                        .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID)),
                 }
             }
-            TimeExpression::Time(time) => {
-                let now = self.now();
-                if !is_valid_time(time.hour, time.minute, time.second, time.meridiem) {
-                    return Err(TempsError::invalid_time(
-                        time.hour,
-                        time.minute,
-                        time.second,
-                    ));
+            _ => {
+                // Use Duration for time units that have fixed lengths
+                let duration = match unit {
+                    TimeUnit::Second => Duration::try_seconds(amount),
+                    TimeUnit::Minute => Duration::try_minutes(amount),
+                    TimeUnit::Hour => Duration::try_hours(amount),
+                    TimeUnit::Day => Duration::try_days(amount),
+                    TimeUnit::Week => Duration::try_weeks(amount),
+                    _ => unreachable!(), // Month and Year handled above
+                }
+                .ok_or_else(|| TempsError::arithmetic_overflow(ERR_DURATION_OVERFLOW))?;
+
+                match direction {
+                    Direction::Past => from.checked_sub_signed(duration),
+                    Direction::Future => from.checked_add_signed(duration),
                 }
+                .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID))
+            }
+        }
+    }
+
+    fn resolve_absolute(&self, abs: AbsoluteTime) -> Result<(DateTime<Tz>, Fold)> {
+        let date = NaiveDate::from_ymd_opt(abs.year as i32, abs.month as u32, abs.day as u32)
+            .ok_or_else(|| TempsError::invalid_date(abs.year, abs.month, abs.day))?;
 
-                let hour = convert_12_to_24_hour(time.hour, time.meridiem.as_ref()) as u32;
+        let (Some(hour), Some(minute)) = (abs.hour, abs.minute) else {
+            // Date only, set time to midnight
+            let midnight = date
+                .and_hms_opt(0, 0, 0)
+                .ok_or_else(|| TempsError::date_calculation(ERR_MIDNIGHT_FAILED))?;
+            return self.localize(&midnight);
+        };
+
+        let time = NaiveTime::from_hms_nano_opt(
+            hour as u32,
+            minute as u32,
+            abs.second.unwrap_or(0) as u32,
+            abs.nanosecond.unwrap_or(0),
+        )
+        .ok_or_else(|| TempsError::invalid_time(hour, minute, abs.second.unwrap_or(0)))?;
+
+        let naive_dt = NaiveDateTime::new(date, time);
+
+        match abs.timezone {
+            Some(Timezone::Utc) => Ok((
+                Utc.from_utc_datetime(&naive_dt)
+                    .with_timezone(&self.timezone),
+                Fold::Unambiguous,
+            )),
+            Some(Timezone::Offset { hours, minutes }) => {
+                if !is_valid_timezone_offset(Timezone::Offset { hours, minutes }) {
+                    return Err(TempsError::invalid_timezone_offset(hours, minutes));
+                }
 
-                Ok(now
-                    .date_naive()
-                    .and_hms_opt(hour, time.minute as u32, time.second as u32)
-                    .ok_or_else(|| TempsError::invalid_time(time.hour, time.minute, time.second))?
-                    .and_local_timezone(Local)
+                let offset_seconds = calculate_timezone_offset_seconds(hours, minutes);
+                let offset = FixedOffset::east_opt(offset_seconds)
+                    .ok_or_else(|| TempsError::invalid_timezone_offset(hours, minutes))?;
+                let datetime = offset
+                    .from_local_datetime(&naive_dt)
                     .single()
-                    .ok_or_else(|| TempsError::ambiguous_time("Ambiguous local time"))?)
+                    .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))?;
+                Ok((datetime.with_timezone(&self.timezone), Fold::Unambiguous))
             }
-            TimeExpression::DayTime(day_time) => {
-                // First get the day
-                let day_result = self.parse_expression(TimeExpression::Day(day_time.day))?;
-                let date = day_result.date_naive();
-
-                if !is_valid_time(
-                    day_time.time.hour,
-                    day_time.time.minute,
-                    day_time.time.second,
-                    day_time.time.meridiem,
-                ) {
-                    return Err(TempsError::invalid_time(
-                        day_time.time.hour,
-                        day_time.time.minute,
-                        day_time.time.second,
-                    ));
+            // No timezone specified, treat as local time in the provider's zone
+            None => self.localize(&naive_dt),
+        }
+    }
+
+    /// Calculate the calendar date a day reference points to.
+    fn resolve_day(&self, day_ref: DayReference) -> Result<NaiveDate> {
+        let today = self.now().date_naive();
+        let week_day = |monday_offset| days_from_week_start(monday_offset, self.week_start);
+        let current_offset = today.weekday().num_days_from_monday() as i64;
+
+        let days = match day_ref {
+            DayReference::Today => 0,
+            DayReference::Yesterday => -1,
+            DayReference::Tomorrow => 1,
+            DayReference::Weekday { day, modifier } => {
+                let target_offset = to_chrono_weekday(day).num_days_from_monday() as i64;
+                calculate_weekday_offset(current_offset, target_offset, modifier)
+            }
+            DayReference::WeekdayInWeek { day, week_offset } => {
+                let target_offset = to_chrono_weekday(day).num_days_from_monday() as i64;
+                calculate_week_weekday_offset(
+                    week_day(current_offset),
+                    week_day(target_offset),
+                    week_offset,
+                )
+            }
+            DayReference::Relative(rel) => return self.resolve_relative_day(today, rel),
+        };
+
+        today
+            .checked_add_signed(Duration::days(days))
+            .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID))
+    }
+
+    /// Calculate the date a relative offset lands on.
+    ///
+    /// Calendar units move the date directly, so "in 3 days" is three
+    /// calendar days away even across a DST transition.
+    fn resolve_relative_day(&self, today: NaiveDate, rel: RelativeTime) -> Result<NaiveDate> {
+        if rel.amount < 0 {
+            return Err(TempsError::date_calculation(
+                ERR_RELATIVE_AMOUNT_NON_NEGATIVE,
+            ));
+        }
+
+        let invalid = || TempsError::date_calculation(ERR_DATE_CALC_INVALID);
+
+        match rel.unit {
+            TimeUnit::Day | TimeUnit::Week => {
+                let days_per_unit = if rel.unit == TimeUnit::Week { 7 } else { 1 };
+                let days = rel
+                    .amount
+                    .checked_mul(days_per_unit)
+                    .and_then(Duration::try_days)
+                    .ok_or_else(invalid)?;
+
+                match rel.direction {
+                    Direction::Past => today.checked_sub_signed(days),
+                    Direction::Future => today.checked_add_signed(days),
                 }
+                .ok_or_else(invalid)
+            }
+            TimeUnit::Month | TimeUnit::Year => {
+                let months_per_unit = if rel.unit == TimeUnit::Year {
+                    MONTHS_PER_YEAR as i64
+                } else {
+                    1
+                };
+                let months = rel
+                    .amount
+                    .checked_mul(months_per_unit)
+                    .and_then(|months| u32::try_from(months).ok())
+                    .map(Months::new)
+                    .ok_or_else(|| TempsError::arithmetic_overflow(ERR_YEAR_OVERFLOW))?;
 
-                let hour =
-                    convert_12_to_24_hour(day_time.time.hour, day_time.time.meridiem.as_ref())
-                        as u32;
+                match rel.direction {
+                    Direction::Past => today.checked_sub_months(months),
+                    Direction::Future => today.checked_add_months(months),
+                }
+                .ok_or_else(invalid)
+            }
+            TimeUnit::Hour | TimeUnit::Minute | TimeUnit::Second => self
+                .resolve_relative(rel)
+                .map(|datetime| datetime.date_naive()),
+        }
+    }
 
-                Ok(date
-                    .and_hms_opt(
-                        hour,
-                        day_time.time.minute as u32,
-                        day_time.time.second as u32,
-                    )
-                    .ok_or_else(|| {
-                        TempsError::invalid_time(
-                            day_time.time.hour,
-                            day_time.time.minute,
-                            day_time.time.second,
-                        )
-                    })?
-                    .and_local_timezone(Local)
-                    .single()
-                    .ok_or_else(|| TempsError::ambiguous_time("Ambiguous local time"))?)
+    /// Combine a date with a (possibly 12-hour) time of day.
+    fn at_time(date: NaiveDate, time: Time) -> Result<NaiveDateTime> {
+        if !is_valid_time(time.hour, time.minute, time.second, time.meridiem) {
+            return Err(TempsError::invalid_time(
+                time.hour,
+                time.minute,
+                time.second,
+            ));
+        }
+
+        let hour = convert_12_to_24_hour(time.hour, time.meridiem.as_ref()) as u32;
+
+        date.and_hms_opt(hour, time.minute as u32, time.second as u32)
+            .ok_or_else(|| TempsError::invalid_time(time.hour, time.minute, time.second))
+    }
+
+    /// Interpret a naive local datetime in the provider's time zone, applying
+    /// the disambiguation policy to skipped and repeated local times.
+    fn localize(&self, naive: &NaiveDateTime) -> Result<(DateTime<Tz>, Fold)> {
+        let ambiguous = || TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME);
+
+        match self.timezone.from_local_datetime(naive) {
+            LocalResult::Single(datetime) => Ok((datetime, Fold::Unambiguous)),
+            LocalResult::Ambiguous(earlier, later) => {
+                let choice = self.disambiguation.overlap_choice().ok_or_else(ambiguous)?;
+                let datetime = match choice {
+                    FoldChoice::Earlier => earlier,
+                    FoldChoice::Later => later,
+                };
+                Ok((datetime, Fold::Overlap(choice)))
             }
-            TimeExpression::Date(date) => {
-                use chrono::NaiveDate;
-
-                NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32)
-                    .ok_or_else(|| TempsError::invalid_date(date.year, date.month, date.day))?
-                    .and_hms_opt(0, 0, 0)
-                    .ok_or_else(|| TempsError::date_calculation(ERR_MIDNIGHT_FAILED))?
-                    .and_local_timezone(Local)
-                    .single()
-                    .ok_or_else(|| TempsError::ambiguous_time("Ambiguous local time"))
+            LocalResult::None => {
+                let choice = self.disambiguation.gap_choice().ok_or_else(ambiguous)?;
+
+                // Interpreting the local time with the offset from before the
+                // transition lands after the gap, and vice versa.
+                let offset_at = |utc: NaiveDateTime| {
+                    self.timezone
+                        .offset_from_utc_datetime(&utc)
+                        .fix()
+                        .local_minus_utc()
+                };
+                let offset_seconds = match choice {
+                    FoldChoice::Earlier => offset_at(*naive + Duration::days(1)),
+                    FoldChoice::Later => offset_at(*naive - Duration::days(1)),
+                };
+                let utc = *naive - Duration::seconds(i64::from(offset_seconds));
+
+                Ok((self.timezone.from_utc_datetime(&utc), Fold::Gap(choice)))
             }
         }
     }
 }
 
+impl<Tz: TimeZone> TimeParser for ChronoProvider<Tz> {
+    type DateTime = DateTime<Tz>;
+
+    fn now(&self) -> Self::DateTime {
+        self.clock.as_ref().map_or_else(
+            || Utc::now().with_timezone(&self.timezone),
+            |clock| clock.now(),
+        )
+    }
+
+    fn parse_expression(&self, expr: TimeExpression) -> Result<Self::DateTime> {
+        self.parse_expression_with_fold(expr)
+            .map(|(datetime, _)| datetime)
+    }
+}
+
+/// End an iteration of occurrences after its first error
+fn end_at_error<T>(
+    occurrences: impl Iterator<Item = Result<T>>,
+) -> impl Iterator<Item = Result<T>> {
+    occurrences.scan(false, |failed, occurrence| {
+        if *failed {
+            return None;
+        }
+        *failed = occurrence.is_err();
+        Some(occurrence)
+    })
+}
+
+fn to_naive_date(date: StandardDate) -> Result<NaiveDate> {
+    NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32)
+        .ok_or_else(|| TempsError::invalid_date(date.year, date.month, date.day))
+}
+
+fn from_naive_date(date: NaiveDate) -> Result<StandardDate> {
+    Ok(StandardDate {
+        day: date.day() as u8,
+        month: date.month() as u8,
+        year: u16::try_from(date.year())
+            .map_err(|_| TempsError::date_calculation(ERR_DATE_CALC_INVALID))?,
+    })
+}
+
+fn to_chrono_weekday(day: Weekday) -> chrono::Weekday {
+    match day {
+        Weekday::Monday => chrono::Weekday::Mon,
+        Weekday::Tuesday => chrono::Weekday::Tue,
+        Weekday::Wednesday => chrono::Weekday::Wed,
+        Weekday::Thursday => chrono::Weekday::Thu,
+        Weekday::Friday => chrono::Weekday::Fri,
+        Weekday::Saturday => chrono::Weekday::Sat,
+        Weekday::Sunday => chrono::Weekday::Sun,
+    }
+}
+
 /// Parse a natural language time expression into a chrono `DateTime<Local>`.
 ///
 /// This is a convenience function that combines parsing and time calculation
@@ -364,7 +1408,7 @@ impl TimeParser for ChronoProvider {
 /// # Arguments
 ///
 /// * `input` - The natural language time expression to parse
-/// * `language` - The language to use for parsing
+/// * `options` - The language to use for parsing, or [`ParseOptions`]
 ///
 /// # Returns
 ///
@@ -393,7 +1437,138 @@ impl TimeParser for ChronoProvider {
 /// - The input cannot be parsed as a valid time expression
 /// - Date calculation results in an invalid date
 /// - The resulting time is ambiguous due to DST transitions
-pub fn parse_to_datetime(input: &str, language: Language) -> Result<DateTime<Local>> {
-    let expr = temps_core::parse(input, language)?;
-    ChronoProvider.parse_expression(expr)
+pub fn parse_to_datetime(input: &str, options: impl Into<ParseOptions>) -> Result<DateTime<Local>> {
+    let expr = temps_core::parse(input, options)?;
+    ChronoProvider::default().parse_expression(expr)
+}
+
+/// Parse a natural language time expression in a specific time zone.
+///
+/// Works like [`parse_to_datetime`], but the expression is resolved in
+/// `timezone` instead of the system's local time zone, so "tomorrow at 9am"
+/// means 9am in that zone.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{FixedOffset, Timelike};
+/// use temps_chrono::parse_to_datetime_in;
+/// use temps_core::Language;
+///
+/// let berlin_winter = FixedOffset::east_opt(3600).unwrap();
+/// let dt = parse_to_datetime_in("tomorrow at 9:00 am", Language::English, berlin_winter).unwrap();
+/// assert_eq!(dt.hour(), 9);
+/// assert_eq!(dt.offset(), &berlin_winter);
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse_to_datetime`].
+pub fn parse_to_datetime_in<Tz: TimeZone>(
+    input: &str,
+    options: impl Into<ParseOptions>,
+    timezone: Tz,
+) -> Result<DateTime<Tz>> {
+    let expr = temps_core::parse(input, options)?;
+    ChronoProvider::new(timezone).parse_expression(expr)
+}
+
+/// Parse a natural language time expression relative to a fixed reference time.
+///
+/// Works like [`parse_to_datetime`], but every relative part of the expression
+/// ("in 5 minutes", "tomorrow", "next Monday", ...) is resolved against
+/// `reference` instead of the current time, in the reference's time zone.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Local, TimeZone};
+/// use temps_chrono::parse_to_datetime_at;
+/// use temps_core::Language;
+///
+/// let reference = Local.with_ymd_and_hms(2024, 3, 15, 23, 59, 0).unwrap();
+///
+/// let dt = parse_to_datetime_at("tomorrow at 9:00 am", Language::English, reference).unwrap();
+/// assert_eq!(dt, Local.with_ymd_and_hms(2024, 3, 16, 9, 0, 0).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse_to_datetime`].
+pub fn parse_to_datetime_at<Tz: TimeZone>(
+    input: &str,
+    options: impl Into<ParseOptions>,
+    reference: DateTime<Tz>,
+) -> Result<DateTime<Tz>>
+where
+    DateTime<Tz>: Send + Sync + 'static,
+{
+    let expr = temps_core::parse(input, options)?;
+    ChronoProvider::new(reference.timezone())
+        .with_reference(reference)
+        .parse_expression(expr)
+}
+
+/// Convert a parsed duration into a chrono [`TimeDelta`].
+///
+/// Weeks and days are taken as 7 and 1 nominal 24-hour days.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use temps_chrono::to_time_delta;
+/// use temps_core::{Language, parse_duration};
+///
+/// let duration = parse_duration("2h30m", Language::English).unwrap();
+/// assert_eq!(to_time_delta(duration).unwrap(), TimeDelta::minutes(150));
+/// ```
+///
+/// # Errors
+///
+/// Returns `TempsError::UnsupportedOperation` if the duration contains months
+/// or years, which have no fixed length, and `TempsError::ArithmeticOverflow`
+/// if it is out of chrono's range.
+pub fn to_time_delta(duration: temps_core::Duration) -> Result<TimeDelta> {
+    if duration.has_calendar_units() {
+        return Err(TempsError::unsupported_operation(
+            ERR_CALENDAR_UNITS_IN_DURATION,
+        ));
+    }
+
+    [
+        TimeDelta::try_weeks(duration.weeks),
+        TimeDelta::try_days(duration.days),
+        TimeDelta::try_hours(duration.hours),
+        TimeDelta::try_minutes(duration.minutes),
+        TimeDelta::try_seconds(duration.seconds),
+        Some(TimeDelta::nanoseconds(duration.nanoseconds)),
+    ]
+    .into_iter()
+    .try_fold(TimeDelta::zero(), |total, part| total.checked_add(&part?))
+    .ok_or_else(|| TempsError::arithmetic_overflow(ERR_DURATION_OVERFLOW))
+}
+
+/// Parse a standalone duration like "90 minutes" or "1.5 days" into a chrono
+/// [`TimeDelta`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use temps_chrono::parse_to_time_delta;
+/// use temps_core::Language;
+///
+/// let timeout = parse_to_time_delta("90 minutes", Language::English).unwrap();
+/// assert_eq!(timeout, TimeDelta::minutes(90));
+///
+/// let retention = parse_to_time_delta("1,5 Tage", Language::German).unwrap();
+/// assert_eq!(retention, TimeDelta::hours(36));
+/// ```
+///
+/// # Errors
+///
+/// Returns the errors of [`temps_core::parse_duration`] and [`to_time_delta`].
+pub fn parse_to_time_delta(input: &str, language: Language) -> Result<TimeDelta> {
+    to_time_delta(temps_core::parse_duration(input, language)?)
 }
@@ -1,14 +1,14 @@
-use chrono::{DateTime, Datelike, Local, TimeZone, Timelike};
+use chrono::{DateTime, Datelike, FixedOffset, Local, TimeDelta, TimeZone, Timelike, Utc};
 use mockall::*;
 use temps_chrono::*;
 use temps_core::*;
-use temps_testhelpers::chrono::{MockTimeSource, TimeSource, test_dates};
+use temps_testhelpers::chrono::{MockTimeSource, test_dates};
 
 // ===== Integration Tests =====
 
 #[test]
 fn test_time_provider_trait() {
-    let provider = ChronoProvider;
+    let provider = ChronoProvider::default();
     let now = provider.now();
     // Basic test that we can create a provider and get current time
     assert!(now > DateTime::<Local>::default());
@@ -16,7 +16,7 @@ fn test_time_provider_trait() {
 
 #[test]
 fn test_chrono_provider_consistency() {
-    let provider = ChronoProvider;
+    let provider = ChronoProvider::default();
 
     // Test that parsing "now" returns the current time (approximately)
     let now = provider.now();
@@ -77,7 +77,7 @@ fn test_date_arithmetic_consistency() {
     // return to the exact same date (due to month length differences)
     // This is expected behavior
 
-    let provider = ChronoProvider;
+    let provider = ChronoProvider::default();
 
     // Test month arithmetic
     let forward_month = TimeExpression::Relative(RelativeTime {
@@ -208,135 +208,13 @@ fn test_multiple_calls_with_sequence() {
 
 // ===== Advanced Mock Tests with Expression Parsing =====
 
-// Testable wrapper around ChronoProvider
-struct TestableChronoProvider<T: TimeSource> {
-    time_source: T,
-}
-
-impl<T: TimeSource> TestableChronoProvider<T> {
-    fn new(time_source: T) -> Self {
-        Self { time_source }
-    }
-}
-
-impl<T: TimeSource> TimeParser for TestableChronoProvider<T> {
-    type DateTime = DateTime<Local>;
-
-    fn now(&self) -> Self::DateTime {
-        self.time_source.now()
-    }
-
-    fn parse_expression(&self, expr: TimeExpression) -> temps_core::Result<Self::DateTime> {
-        let now = self.now();
-        match expr {
-            TimeExpression::Now => Ok(now),
-            TimeExpression::Relative(rel) => {
-                use chrono::{Duration, Months};
-
-                match rel.unit {
-                    TimeUnit::Second => {
-                        let duration = Duration::seconds(rel.amount);
-                        match rel.direction {
-                            Direction::Past => Ok(now - duration),
-                            Direction::Future => Ok(now + duration),
-                        }
-                    }
-                    TimeUnit::Minute => {
-                        let duration = Duration::minutes(rel.amount);
-                        match rel.direction {
-                            Direction::Past => Ok(now - duration),
-                            Direction::Future => Ok(now + duration),
-                        }
-                    }
-                    TimeUnit::Hour => {
-                        let duration = Duration::hours(rel.amount);
-                        match rel.direction {
-                            Direction::Past => Ok(now - duration),
-                            Direction::Future => Ok(now + duration),
-                        }
-                    }
-                    TimeUnit::Day => {
-                        let duration = Duration::days(rel.amount);
-                        match rel.direction {
-                            Direction::Past => Ok(now - duration),
-                            Direction::Future => Ok(now + duration),
-                        }
-                    }
-                    TimeUnit::Week => {
-                        let duration = Duration::weeks(rel.amount);
-                        match rel.direction {
-                            Direction::Past => Ok(now - duration),
-                            Direction::Future => Ok(now + duration),
-                        }
-                    }
-                    TimeUnit::Month => {
-                        let months = Months::new(rel.amount.try_into().map_err(|_| {
-                            temps_core::TempsError::date_calculation(
-                                "Month amount must be a positive number",
-                            )
-                        })?);
-
-                        match rel.direction {
-                            Direction::Past => now.checked_sub_months(months).ok_or_else(|| {
-                                temps_core::TempsError::date_calculation(
-                                    "Date calculation resulted in invalid date",
-                                )
-                            }),
-                            Direction::Future => now.checked_add_months(months).ok_or_else(|| {
-                                temps_core::TempsError::date_calculation(
-                                    "Date calculation resulted in invalid date",
-                                )
-                            }),
-                        }
-                    }
-                    TimeUnit::Year => {
-                        let months = Months::new((rel.amount * 12).try_into().map_err(|_| {
-                            temps_core::TempsError::date_calculation(
-                                "Year amount must be a positive number",
-                            )
-                        })?);
-
-                        match rel.direction {
-                            Direction::Past => now.checked_sub_months(months).ok_or_else(|| {
-                                temps_core::TempsError::date_calculation(
-                                    "Date calculation resulted in invalid date",
-                                )
-                            }),
-                            Direction::Future => now.checked_add_months(months).ok_or_else(|| {
-                                temps_core::TempsError::date_calculation(
-                                    "Date calculation resulted in invalid date",
-                                )
-                            }),
-                        }
-                    }
-                }
-            }
-            TimeExpression::Absolute(abs) => {
-                ChronoProvider.parse_expression(TimeExpression::Absolute(abs))
-            }
-            TimeExpression::Day(day_ref) => {
-                ChronoProvider.parse_expression(TimeExpression::Day(day_ref))
-            }
-            TimeExpression::Time(time) => {
-                ChronoProvider.parse_expression(TimeExpression::Time(time))
-            }
-            TimeExpression::DayTime(day_time) => {
-                ChronoProvider.parse_expression(TimeExpression::DayTime(day_time))
-            }
-            TimeExpression::Date(date) => {
-                ChronoProvider.parse_expression(TimeExpression::Date(date))
-            }
-        }
-    }
-}
-
 #[test]
 fn test_english_time_expressions_with_mock() {
     let mut mock = MockTimeSource::new();
     let base_time = Local.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
     mock.expect_now().return_const(base_time);
 
-    let provider = TestableChronoProvider::new(mock);
+    let provider = ChronoProvider::default().with_clock(mock);
 
     // Test various English natural language expressions
     let test_cases = vec![
@@ -374,7 +252,7 @@ fn test_german_time_expressions_with_mock() {
     let base_time = Local.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
     mock.expect_now().return_const(base_time);
 
-    let provider = TestableChronoProvider::new(mock);
+    let provider = ChronoProvider::default().with_clock(mock);
 
     // Test various German natural language expressions
     let test_cases = vec![
@@ -414,7 +292,7 @@ fn test_month_arithmetic_edge_cases_with_mock() {
     let jan_31_2024 = Local.with_ymd_and_hms(2024, 1, 31, 10, 0, 0).unwrap();
     mock.expect_now().return_const(jan_31_2024);
 
-    let provider = TestableChronoProvider::new(mock);
+    let provider = ChronoProvider::default().with_clock(mock);
     let expr = parse("in 1 month", Language::English).unwrap();
     let result = provider.parse_expression(expr).unwrap();
 
@@ -431,7 +309,7 @@ fn test_month_arithmetic_non_leap_year() {
     let jan_31_2023 = Local.with_ymd_and_hms(2023, 1, 31, 10, 0, 0).unwrap();
     mock.expect_now().return_const(jan_31_2023);
 
-    let provider = TestableChronoProvider::new(mock);
+    let provider = ChronoProvider::default().with_clock(mock);
     let expr = parse("in einem Monat", Language::German).unwrap();
     let result = provider.parse_expression(expr).unwrap();
 
@@ -448,7 +326,7 @@ fn test_year_arithmetic_with_leap_day() {
     let feb_29 = Local.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap();
     mock.expect_now().return_const(feb_29);
 
-    let provider = TestableChronoProvider::new(mock);
+    let provider = ChronoProvider::default().with_clock(mock);
     let expr = parse("in 1 year", Language::English).unwrap();
     let result = provider.parse_expression(expr).unwrap();
 
@@ -465,7 +343,7 @@ fn test_cross_year_boundary_calculations() {
     let oct_15 = Local.with_ymd_and_hms(2023, 10, 15, 9, 0, 0).unwrap();
     mock.expect_now().return_const(oct_15);
 
-    let provider = TestableChronoProvider::new(mock);
+    let provider = ChronoProvider::default().with_clock(mock);
     let expr = parse("in 6 months", Language::English).unwrap();
     let result = provider.parse_expression(expr).unwrap();
 
@@ -480,7 +358,7 @@ fn test_complex_expressions_with_articles() {
     let base_time = Local.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
     mock.expect_now().return_const(base_time);
 
-    let provider = TestableChronoProvider::new(mock);
+    let provider = ChronoProvider::default().with_clock(mock);
 
     // Test English articles: "a", "an", "one"
     let english_cases = vec![
@@ -505,7 +383,7 @@ fn test_german_articles_with_time_calculations() {
     let base_time = Local.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
     mock.expect_now().return_const(base_time);
 
-    let provider = TestableChronoProvider::new(mock);
+    let provider = ChronoProvider::default().with_clock(mock);
 
     // Test German articles: "ein", "eine", "einem", "einer", etc.
     let german_cases = vec![
@@ -534,7 +412,7 @@ fn test_now_expression_with_mock() {
     let current_time = Local.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
     mock.expect_now().return_const(current_time);
 
-    let provider = TestableChronoProvider::new(mock);
+    let provider = ChronoProvider::default().with_clock(mock);
 
     // Test "now" in English
     let expr = parse("now", Language::English).unwrap();
@@ -549,7 +427,7 @@ fn test_now_expression_with_mock() {
 
 #[test]
 fn test_iso_datetime_absolute_time() {
-    let provider = ChronoProvider;
+    let provider = ChronoProvider::default();
 
     let test_cases = vec![
         // Basic RFC3339 dates
@@ -645,7 +523,7 @@ fn test_time_parsing_with_chrono() {
 
 #[test]
 fn test_chrono_provider_rejects_invalid_programmatic_inputs() {
-    let provider = ChronoProvider;
+    let provider = ChronoProvider::default();
 
     let invalid_time = TimeExpression::Time(Time {
         hour: 0,
@@ -723,3 +601,1210 @@ fn test_date_parsing_with_chrono() {
         assert_eq!(datetime.hour(), 0); // Should be midnight
     }
 }
+
+#[test]
+fn test_reference_time_drives_every_expression() {
+    // Friday, March 15, 2024 at 23:59 - close to midnight on purpose
+    let reference = Local.with_ymd_and_hms(2024, 3, 15, 23, 59, 0).unwrap();
+    let provider = ChronoProvider::default().with_reference(reference);
+
+    let test_cases = vec![
+        ("now", reference),
+        (
+            "in 2 minutes",
+            Local.with_ymd_and_hms(2024, 3, 16, 0, 1, 0).unwrap(),
+        ),
+        (
+            "today",
+            Local.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap(),
+        ),
+        (
+            "yesterday",
+            Local.with_ymd_and_hms(2024, 3, 14, 0, 0, 0).unwrap(),
+        ),
+        (
+            "tomorrow",
+            Local.with_ymd_and_hms(2024, 3, 16, 0, 0, 0).unwrap(),
+        ),
+        (
+            "next monday",
+            Local.with_ymd_and_hms(2024, 3, 18, 0, 0, 0).unwrap(),
+        ),
+        (
+            "last friday",
+            Local.with_ymd_and_hms(2024, 3, 8, 0, 0, 0).unwrap(),
+        ),
+        (
+            "3:30 pm",
+            Local.with_ymd_and_hms(2024, 3, 15, 15, 30, 0).unwrap(),
+        ),
+        (
+            "tomorrow at 9:00 am",
+            Local.with_ymd_and_hms(2024, 3, 16, 9, 0, 0).unwrap(),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        let expr = parse(input, Language::English).unwrap();
+        let result = provider.parse_expression(expr).unwrap();
+        assert_eq!(result, expected, "Failed for input: {input}");
+    }
+}
+
+#[test]
+fn test_mock_time_source_drives_day_references() {
+    let mut mock = MockTimeSource::new();
+    mock.expect_now().return_const(test_dates::jan_31_2024());
+
+    let provider = ChronoProvider::default().with_clock(mock);
+    let expr = parse("morgen um 14:00", Language::German).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+
+    assert_eq!(
+        result,
+        Local.with_ymd_and_hms(2024, 2, 1, 14, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_to_datetime_at() {
+    let reference = test_dates::june_15_2023();
+
+    let result = parse_to_datetime_at("vor 3 Tagen", Language::German, reference).unwrap();
+    assert_eq!(result, reference - chrono::Duration::days(3));
+
+    let result = parse_to_datetime_at("next monday at 9:00 am", Language::English, reference);
+    assert_eq!(
+        result.unwrap(),
+        Local.with_ymd_and_hms(2023, 6, 19, 9, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_provider_resolves_in_configured_time_zone() {
+    use chrono::{FixedOffset, Utc};
+
+    let plus_two = FixedOffset::east_opt(2 * 3600).unwrap();
+    // 23:30 UTC on March 15 is already March 16 at UTC+2
+    let reference = Utc
+        .with_ymd_and_hms(2024, 3, 15, 23, 30, 0)
+        .unwrap()
+        .with_timezone(&plus_two);
+    let provider = ChronoProvider::new(plus_two).with_reference(reference);
+
+    let expr = parse("tomorrow at 9:00 am", Language::English).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+    assert_eq!(
+        result,
+        plus_two.with_ymd_and_hms(2024, 3, 17, 9, 0, 0).unwrap()
+    );
+
+    // Dates and absolute times without offset are interpreted in the provider's zone
+    let expr = parse("24.12.2024", Language::German).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+    assert_eq!(
+        result,
+        plus_two.with_ymd_and_hms(2024, 12, 24, 0, 0, 0).unwrap()
+    );
+
+    // Absolute times with an explicit offset keep their instant
+    let expr = parse("2024-07-01T12:00:00Z", Language::English).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+    assert_eq!(result.hour(), 14);
+    assert_eq!(result.offset(), &plus_two);
+}
+
+#[test]
+fn test_parse_to_datetime_in_utc() {
+    use chrono::Utc;
+
+    let result = parse_to_datetime_in("tomorrow at 9:00 am", Language::English, Utc).unwrap();
+    assert_eq!(result.hour(), 9);
+    assert_eq!(result.timezone(), Utc);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_chrono_tz_provider() {
+    use temps_chrono::chrono_tz::Europe::Berlin;
+
+    let reference = Berlin.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Berlin).with_reference(reference);
+
+    // Berlin switches to summer time overnight
+    let expr = parse("tomorrow at 9:00 am", Language::English).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+    assert_eq!(
+        result,
+        Berlin.with_ymd_and_hms(2024, 3, 31, 9, 0, 0).unwrap()
+    );
+    assert_eq!(result.naive_utc().hour(), 7);
+}
+
+#[test]
+fn test_dst_gap_disambiguation() {
+    use chrono_tz::Europe::Berlin;
+
+    // 02:30 does not exist in Berlin on 2024-03-31 (clocks jump from 02:00 to 03:00)
+    let reference = Berlin.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    let expr = parse("tomorrow at 2:30 am", Language::English).unwrap();
+
+    let provider = ChronoProvider::new(Berlin).with_reference(reference);
+    let (result, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    assert_eq!(fold, Fold::Gap(FoldChoice::Later));
+    assert_eq!(
+        result,
+        Berlin.with_ymd_and_hms(2024, 3, 31, 3, 30, 0).unwrap()
+    );
+
+    let provider = provider.with_disambiguation(Disambiguation::Earlier);
+    let (result, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    assert_eq!(fold, Fold::Gap(FoldChoice::Earlier));
+    assert_eq!(
+        result,
+        Berlin.with_ymd_and_hms(2024, 3, 31, 1, 30, 0).unwrap()
+    );
+
+    let provider = provider.with_disambiguation(Disambiguation::Reject);
+    assert!(matches!(
+        provider.parse_expression(expr),
+        Err(TempsError::AmbiguousTime { .. })
+    ));
+}
+
+#[test]
+fn test_dst_overlap_disambiguation() {
+    use chrono_tz::Europe::Berlin;
+
+    // 02:30 happens twice in Berlin on 2024-10-27 (clocks fall back from 03:00 to 02:00)
+    let provider = ChronoProvider::new(Berlin);
+    let expr = parse("27.10.2024", Language::German).unwrap();
+    let (_, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    assert_eq!(fold, Fold::Unambiguous);
+
+    let expr = parse("2024-10-27T02:30:00", Language::English).unwrap();
+    let (result, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    assert_eq!(fold, Fold::Overlap(FoldChoice::Earlier));
+    assert_eq!(result.naive_utc().hour(), 0);
+
+    for (disambiguation, choice, utc_hour) in [
+        (Disambiguation::Earlier, FoldChoice::Earlier, 0),
+        (Disambiguation::Later, FoldChoice::Later, 1),
+    ] {
+        let provider = ChronoProvider::new(Berlin).with_disambiguation(disambiguation);
+        let (result, fold) = provider.parse_expression_with_fold(expr).unwrap();
+        assert_eq!(fold, Fold::Overlap(choice));
+        assert_eq!(result.naive_local().hour(), 2);
+        assert_eq!(result.naive_utc().hour(), utc_hour);
+    }
+
+    let provider = ChronoProvider::new(Berlin).with_disambiguation(Disambiguation::Reject);
+    assert!(matches!(
+        provider.parse_expression(expr),
+        Err(TempsError::AmbiguousTime { .. })
+    ));
+}
+
+#[test]
+fn test_compound_relative_durations() {
+    let reference = Utc.with_ymd_and_hms(2024, 1, 30, 10, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Utc).with_reference(reference);
+
+    let test_cases = [
+        (
+            "in 2 hours 30 minutes",
+            Language::English,
+            (2024, 1, 30, 12, 30),
+        ),
+        (
+            "1 day and 3 hours ago",
+            Language::English,
+            (2024, 1, 29, 7, 0),
+        ),
+        // Months are applied before days: Feb 29 + 2 days
+        ("in 1 month 2 days", Language::English, (2024, 3, 2, 10, 0)),
+        (
+            "in 2 Stunden und 30 Minuten",
+            Language::German,
+            (2024, 1, 30, 12, 30),
+        ),
+        ("vor 1 Tag 3 Stunden", Language::German, (2024, 1, 29, 7, 0)),
+    ];
+
+    for (input, language, (year, month, day, hour, minute)) in test_cases {
+        let expr = parse(input, language).unwrap();
+        let result = provider.parse_expression(expr).unwrap();
+        assert_eq!(
+            result,
+            Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+                .unwrap(),
+            "Failed for input: {input}"
+        );
+    }
+
+    // Too far out for chrono, or too large for a `TimeDelta`
+    let expr = parse("in 99999999 days and 1 hour", Language::English).unwrap();
+    assert!(matches!(
+        provider.parse_expression(expr),
+        Err(TempsError::DateCalculationError { .. })
+    ));
+    let expr = parse("99999999 days and 1 hour ago", Language::English).unwrap();
+    assert!(matches!(
+        provider.parse_expression(expr),
+        Err(TempsError::DateCalculationError { .. })
+    ));
+    let expr = parse("in 9999999999999999 weeks and 1 hour", Language::English).unwrap();
+    assert!(matches!(
+        provider.parse_expression(expr),
+        Err(TempsError::ArithmeticOverflow { .. })
+    ));
+}
+
+#[test]
+fn test_parse_to_time_delta() {
+    let test_cases = [
+        ("90 minutes", Language::English, TimeDelta::minutes(90)),
+        ("2h30m", Language::English, TimeDelta::minutes(150)),
+        ("1.5 days", Language::English, TimeDelta::hours(36)),
+        ("1 week", Language::English, TimeDelta::days(7)),
+        (
+            "2 Stunden 30 Minuten",
+            Language::German,
+            TimeDelta::minutes(150),
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        assert_eq!(
+            parse_to_time_delta(input, language).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    assert!(matches!(
+        parse_to_time_delta("3 months", Language::English),
+        Err(TempsError::UnsupportedOperation { .. })
+    ));
+    assert!(parse_to_time_delta("in 3 hours", Language::English).is_err());
+}
+
+#[test]
+fn test_relative_day_at_time() {
+    // Wednesday, March 27, 2024, shortly before Berlin switches to summer time
+    use chrono_tz::Europe::Berlin;
+
+    let reference = Berlin.with_ymd_and_hms(2024, 3, 27, 23, 30, 0).unwrap();
+    let provider = ChronoProvider::new(Berlin).with_reference(reference);
+
+    let test_cases = [
+        ("in 3 days at 5pm", Language::English, (2024, 3, 30, 17, 0)),
+        // Calendar days, although one of them is only 23 hours long
+        ("in 4 days at 0:30", Language::English, (2024, 3, 31, 0, 30)),
+        (
+            "2 weeks ago at 10:30",
+            Language::English,
+            (2024, 3, 13, 10, 30),
+        ),
+        ("in a month at 9am", Language::English, (2024, 4, 27, 9, 0)),
+        (
+            "next week Monday at 10:00",
+            Language::English,
+            (2024, 4, 1, 10, 0),
+        ),
+        (
+            "monday this week at 10:00",
+            Language::English,
+            (2024, 3, 25, 10, 0),
+        ),
+        (
+            "vor 2 Tagen um 14:00",
+            Language::German,
+            (2024, 3, 25, 14, 0),
+        ),
+        (
+            "Freitag letzter Woche um 16:00",
+            Language::German,
+            (2024, 3, 22, 16, 0),
+        ),
+    ];
+
+    for (input, language, (year, month, day, hour, minute)) in test_cases {
+        let expr = parse(input, language).unwrap();
+        let result = provider.parse_expression(expr).unwrap();
+        assert_eq!(
+            result,
+            Berlin
+                .with_ymd_and_hms(year, month, day, hour, minute, 0)
+                .unwrap(),
+            "Failed for input: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_month_name_dates() {
+    for (input, language) in [
+        ("March 5th, 2024", Language::English),
+        ("5. März 2024", Language::German),
+    ] {
+        let result = parse_to_datetime_in(input, language, Utc).unwrap();
+        assert_eq!(result, Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap());
+    }
+}
+
+#[test]
+fn test_dates_without_year_use_year_policy() {
+    let reference = Utc.with_ymd_and_hms(2024, 6, 15, 10, 0, 0).unwrap();
+    let test_cases = vec![
+        ("March 5", YearPolicy::Next, (2025, 3, 5)),
+        ("March 5", YearPolicy::Previous, (2024, 3, 5)),
+        ("March 5", YearPolicy::Nearest, (2024, 3, 5)),
+        ("Sept 1st", YearPolicy::Nearest, (2024, 9, 1)),
+        ("15/06", YearPolicy::Next, (2024, 6, 15)),
+        ("Feb 29", YearPolicy::Next, (2028, 2, 29)),
+    ];
+
+    for (input, policy, (year, month, day)) in test_cases {
+        let provider = ChronoProvider::new(Utc)
+            .with_reference(reference)
+            .with_year_policy(policy);
+        let expr = parse(input, Language::English).unwrap();
+        assert_eq!(
+            provider.parse_expression(expr).unwrap(),
+            Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap(),
+            "Failed for input: {input} with {policy:?}"
+        );
+    }
+
+    // Defaults to the next occurrence
+    let provider = ChronoProvider::new(Utc).with_reference(reference);
+    let expr = parse("am 24.12.", Language::German).unwrap();
+    assert_eq!(
+        provider.parse_expression(expr).unwrap(),
+        Utc.with_ymd_and_hms(2024, 12, 24, 0, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_us_date_order() {
+    let result = parse_to_datetime_in("03/05/2024", Language::EnglishUs, Utc).unwrap();
+    assert_eq!(result, Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap());
+
+    let options = ParseOptions::new(Language::English).with_date_order(DateOrder::Mdy);
+    let result = parse_to_datetime_in("12/31/2024", options, Utc).unwrap();
+    assert_eq!(result, Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap());
+}
+
+#[test]
+fn test_parse_interval() {
+    // Friday
+    let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Utc).with_reference(reference);
+    let at = |month, day, hour| Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap();
+
+    let test_cases = vec![
+        (
+            "from 3pm to 5pm",
+            Language::English,
+            (at(3, 15, 15), at(3, 15, 17)),
+        ),
+        (
+            "from 10pm to 2am",
+            Language::English,
+            (at(3, 15, 22), at(3, 16, 2)),
+        ),
+        ("3-5pm", Language::English, (at(3, 15, 15), at(3, 15, 17))),
+        (
+            "from tomorrow at 9am to 5pm",
+            Language::English,
+            (at(3, 16, 9), at(3, 16, 17)),
+        ),
+        // Whole days include the last day
+        (
+            "from Monday to Friday",
+            Language::English,
+            (at(3, 18, 0), at(3, 23, 0)),
+        ),
+        (
+            "between Friday and Monday",
+            Language::English,
+            (at(3, 15, 0), at(3, 19, 0)),
+        ),
+        (
+            "from Monday at 9:00 to Friday at 17:00",
+            Language::English,
+            (at(3, 18, 9), at(3, 22, 17)),
+        ),
+        (
+            "von 9 bis 17 Uhr",
+            Language::German,
+            (at(3, 15, 9), at(3, 15, 17)),
+        ),
+        (
+            "vom 24.12. bis zum 26.12.",
+            Language::German,
+            (at(12, 24, 0), at(12, 27, 0)),
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        let expr = parse(input, language).unwrap();
+        assert_eq!(
+            provider.parse_interval(expr).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    // Ends are resolved from the start across the year boundary
+    let expr = parse("from Dec 30 to Jan 2", Language::English).unwrap();
+    let (start, end) = provider.parse_interval(expr).unwrap();
+    assert_eq!(start, at(12, 30, 0));
+    assert_eq!(end, Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap());
+
+    // On its own, an interval resolves to its start
+    let expr = parse("from 3pm to 5pm", Language::English).unwrap();
+    assert_eq!(provider.parse_expression(expr).unwrap(), at(3, 15, 15));
+
+    let expr = parse("between tomorrow and yesterday", Language::English).unwrap();
+    assert!(provider.parse_interval(expr).is_err());
+
+    let expr = parse("tomorrow", Language::English).unwrap();
+    assert!(provider.parse_interval(expr).is_err());
+}
+
+#[test]
+fn test_parse_period() {
+    // Friday
+    let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Utc).with_reference(reference);
+    let day = |year, month, day| Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap();
+
+    let test_cases = vec![
+        (
+            "last week",
+            Language::English,
+            (day(2024, 3, 4), day(2024, 3, 11)),
+        ),
+        (
+            "this week",
+            Language::English,
+            (day(2024, 3, 11), day(2024, 3, 18)),
+        ),
+        (
+            "next week",
+            Language::English,
+            (day(2024, 3, 18), day(2024, 3, 25)),
+        ),
+        (
+            "this month",
+            Language::English,
+            (day(2024, 3, 1), day(2024, 4, 1)),
+        ),
+        (
+            "letzten Monat",
+            Language::German,
+            (day(2024, 2, 1), day(2024, 3, 1)),
+        ),
+        (
+            "next quarter",
+            Language::English,
+            (day(2024, 4, 1), day(2024, 7, 1)),
+        ),
+        (
+            "last quarter",
+            Language::English,
+            (day(2023, 10, 1), day(2024, 1, 1)),
+        ),
+        (
+            "nächstes Jahr",
+            Language::German,
+            (day(2025, 1, 1), day(2026, 1, 1)),
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        let expr = parse(input, language).unwrap();
+        assert_eq!(
+            provider.parse_interval(expr).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+        assert_eq!(provider.parse_expression(expr).unwrap(), expected.0);
+    }
+}
+
+#[test]
+fn test_parse_boundary() {
+    // Friday
+    let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Utc).with_reference(reference);
+    let day = |year, month, day| Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap();
+    let last_nanosecond = |year, month, d| day(year, month, d) - TimeDelta::nanoseconds(1);
+
+    let test_cases = vec![
+        // (input, language, start or last nanosecond, next period start)
+        (
+            "start of month",
+            Language::English,
+            day(2024, 3, 1),
+            day(2024, 3, 1),
+        ),
+        (
+            "end of month",
+            Language::English,
+            last_nanosecond(2024, 4, 1),
+            day(2024, 4, 1),
+        ),
+        (
+            "start of next week",
+            Language::English,
+            day(2024, 3, 18),
+            day(2024, 3, 18),
+        ),
+        (
+            "EOD",
+            Language::English,
+            last_nanosecond(2024, 3, 16),
+            day(2024, 3, 16),
+        ),
+        (
+            "end of last quarter",
+            Language::English,
+            last_nanosecond(2024, 1, 1),
+            day(2024, 1, 1),
+        ),
+        (
+            "Ende des Jahres",
+            Language::German,
+            last_nanosecond(2025, 1, 1),
+            day(2025, 1, 1),
+        ),
+    ];
+
+    let next_start = provider.clone().with_period_end(PeriodEnd::NextStart);
+    for (input, language, expected, expected_next_start) in test_cases {
+        let expr = parse(input, language).unwrap();
+        assert_eq!(
+            provider.parse_expression(expr).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+        assert_eq!(
+            next_start.parse_expression(expr).unwrap(),
+            expected_next_start,
+            "Failed for input: {input}"
+        );
+    }
+
+    // "end of month" ends an interval
+    let expr = parse("from today until end of month", Language::English).unwrap();
+    assert_eq!(
+        next_start.parse_interval(expr).unwrap(),
+        (day(2024, 3, 15), day(2024, 4, 1))
+    );
+}
+
+#[test]
+fn test_week_start() {
+    // Friday
+    let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0).unwrap();
+    let day = |year, month, day| Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap();
+
+    let test_cases = vec![
+        (
+            WeekStart::Monday,
+            (day(2024, 3, 11), day(2024, 3, 18)),
+            day(2024, 3, 17),
+        ),
+        (
+            WeekStart::Sunday,
+            (day(2024, 3, 10), day(2024, 3, 17)),
+            day(2024, 3, 10),
+        ),
+        (
+            WeekStart::Saturday,
+            (day(2024, 3, 9), day(2024, 3, 16)),
+            day(2024, 3, 10),
+        ),
+    ];
+
+    for (week_start, this_week, sunday_this_week) in test_cases {
+        let provider = ChronoProvider::new(Utc)
+            .with_reference(reference)
+            .with_week_start(week_start);
+        let expr = parse("this week", Language::English).unwrap();
+        assert_eq!(
+            provider.parse_interval(expr).unwrap(),
+            this_week,
+            "Failed for {week_start:?}"
+        );
+        let expr = parse("Sunday this week", Language::English).unwrap();
+        assert_eq!(
+            provider.parse_expression(expr).unwrap(),
+            sunday_this_week,
+            "Failed for {week_start:?}"
+        );
+        // Weekdays without a week are unaffected
+        let expr = parse("next Monday", Language::English).unwrap();
+        assert_eq!(provider.parse_expression(expr).unwrap(), day(2024, 3, 18));
+    }
+}
+
+#[test]
+fn test_occurrences() {
+    // Friday
+    let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Utc).with_reference(reference);
+    let at = |month, day, hour, minute| {
+        Utc.with_ymd_and_hms(2024, month, day, hour, minute, 0)
+            .unwrap()
+    };
+
+    let test_cases = vec![
+        (
+            "every Monday at 9am",
+            Language::English,
+            vec![at(3, 18, 9, 0), at(3, 25, 9, 0), at(4, 1, 9, 0)],
+        ),
+        // Today's Friday at midnight has passed
+        (
+            "every other Friday",
+            Language::English,
+            vec![at(3, 29, 0, 0), at(4, 12, 0, 0), at(4, 26, 0, 0)],
+        ),
+        (
+            "every day at 9am 2 times",
+            Language::English,
+            vec![at(3, 16, 9, 0), at(3, 17, 9, 0)],
+        ),
+        (
+            "every 15 minutes",
+            Language::English,
+            vec![at(3, 15, 10, 0), at(3, 15, 10, 15), at(3, 15, 10, 30)],
+        ),
+        (
+            "monthly",
+            Language::English,
+            vec![at(4, 15, 0, 0), at(5, 15, 0, 0), at(6, 15, 0, 0)],
+        ),
+        (
+            "täglich bis 17.03.2024",
+            Language::German,
+            vec![at(3, 16, 0, 0), at(3, 17, 0, 0)],
+        ),
+        (
+            "every day until March 17th",
+            Language::English,
+            vec![at(3, 16, 0, 0), at(3, 17, 0, 0)],
+        ),
+        (
+            "jeden zweiten Freitag um 17:00",
+            Language::German,
+            vec![at(3, 15, 17, 0), at(3, 29, 17, 0), at(4, 12, 17, 0)],
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        let expr = parse(input, language).unwrap();
+        let occurrences = provider
+            .occurrences(expr)
+            .unwrap()
+            .take(3)
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(occurrences, expected, "Failed for input: {input}");
+        assert_eq!(provider.parse_expression(expr).unwrap(), expected[0]);
+    }
+
+    // Weeks start on Sunday: "every other Sunday" counts from this week's Sunday
+    let provider = provider.with_week_start(WeekStart::Sunday);
+    let expr = parse("every other Sunday", Language::English).unwrap();
+    assert_eq!(
+        provider.occurrences(expr).unwrap().next().unwrap().unwrap(),
+        at(3, 24, 0, 0)
+    );
+
+    assert!(
+        provider
+            .occurrences(parse("tomorrow", Language::English).unwrap())
+            .is_err()
+    );
+    let expr = parse("daily until 01/03/2024", Language::English).unwrap();
+    assert!(provider.parse_expression(expr).is_err());
+
+    // The next period lies past the last representable date
+    for input in ["every 99999999 days", "every 99999999 weeks"] {
+        let expr = parse(input, Language::English).unwrap();
+        assert!(
+            provider.parse_expression(expr).is_err(),
+            "Failed for input: {input}"
+        );
+        assert_eq!(
+            provider.occurrences(expr).unwrap().count(),
+            0,
+            "Failed for input: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_rrule_occurrences() {
+    // Friday
+    let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Utc).with_reference(reference);
+    let at = |month, day, hour| Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap();
+    let occurrences = |provider: &ChronoProvider<Utc>, rule: &str| {
+        let rule = parse_rrule(rule).unwrap();
+        provider
+            .rrule_occurrences(&rule)
+            .unwrap()
+            .take(4)
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    };
+
+    let test_cases = vec![
+        // Without a start, rules start at the reference time
+        (
+            "FREQ=WEEKLY;BYDAY=MO,FR",
+            vec![at(3, 15, 10), at(3, 18, 10), at(3, 22, 10), at(3, 25, 10)],
+        ),
+        // Counting from the start, not the reference time
+        (
+            "DTSTART:20240311T090000Z\nRRULE:FREQ=DAILY;COUNT=7",
+            vec![at(3, 16, 9), at(3, 17, 9)],
+        ),
+        // Until a date includes that day
+        (
+            "DTSTART:20240315T180000\nRRULE:FREQ=DAILY;UNTIL=20240317",
+            vec![at(3, 15, 18), at(3, 16, 18), at(3, 17, 18)],
+        ),
+        (
+            "DTSTART:20240315T180000\nRRULE:FREQ=DAILY;UNTIL=20240316T180000Z",
+            vec![at(3, 15, 18), at(3, 16, 18)],
+        ),
+        (
+            "DTSTART;VALUE=DATE:20240101\nRRULE:FREQ=MONTHLY;BYDAY=-1FR",
+            vec![at(3, 29, 0), at(4, 26, 0), at(5, 31, 0), at(6, 28, 0)],
+        ),
+    ];
+    for (rule, expected) in test_cases {
+        assert_eq!(
+            occurrences(&provider, rule),
+            expected,
+            "Failed for rule: {rule}"
+        );
+    }
+
+    // UTC start times are converted to the provider's time zone
+    let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    let provider = ChronoProvider::new(offset).with_reference(reference.with_timezone(&offset));
+    let rule = parse_rrule("DTSTART:20240315T230000Z\nRRULE:FREQ=WEEKLY").unwrap();
+    assert_eq!(
+        provider
+            .rrule_occurrences(&rule)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap(),
+        offset.with_ymd_and_hms(2024, 3, 16, 1, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_rrule_occurrences_across_dst() {
+    use chrono_tz::Europe::Berlin;
+
+    let reference = Berlin.with_ymd_and_hms(2024, 3, 30, 0, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Berlin).with_reference(reference);
+    let occurrences = |provider: &ChronoProvider<chrono_tz::Tz>, rule: &str| {
+        let rule = parse_rrule(rule).unwrap();
+        provider
+            .rrule_occurrences(&rule)
+            .unwrap()
+            .collect::<Vec<_>>()
+    };
+
+    // 02:30 does not exist on March 31 and moves to 03:30
+    let daily = "DTSTART;TZID=Europe/Berlin:20240330T023000\nRRULE:FREQ=DAILY;COUNT=3";
+    let result = occurrences(&provider, daily);
+    assert_eq!(
+        result.into_iter().collect::<Result<Vec<_>>>().unwrap(),
+        [
+            Berlin.with_ymd_and_hms(2024, 3, 30, 2, 30, 0).unwrap(),
+            Berlin.with_ymd_and_hms(2024, 3, 31, 3, 30, 0).unwrap(),
+            Berlin.with_ymd_and_hms(2024, 4, 1, 2, 30, 0).unwrap(),
+        ]
+    );
+
+    // The skipped hour is not repeated
+    let hourly = "DTSTART:20240331T010000\nRRULE:FREQ=HOURLY;COUNT=3";
+    let hours: Vec<_> = occurrences(&provider, hourly)
+        .into_iter()
+        .map(|occurrence| occurrence.unwrap().hour())
+        .collect();
+    assert_eq!(hours, [1, 3, 4]);
+
+    // Local times keep their time of day when summer time ends
+    let weekly = "DTSTART:20241020T090000\nRRULE:FREQ=WEEKLY;COUNT=2";
+    let utc_hours: Vec<_> = occurrences(&provider, weekly)
+        .into_iter()
+        .map(|occurrence| occurrence.unwrap().naive_utc().hour())
+        .collect();
+    assert_eq!(utc_hours, [7, 8]);
+
+    let provider = provider.with_disambiguation(Disambiguation::Reject);
+    let result = occurrences(&provider, daily);
+    assert_eq!(result.len(), 2);
+    assert!(matches!(result[1], Err(TempsError::AmbiguousTime { .. })));
+}
+
+#[test]
+fn test_cron_schedules() {
+    let provider = ChronoProvider::new(Utc);
+    let monthly = parse_cron("0 0 1 * *").unwrap();
+
+    // Strictly after and before the given time
+    let first = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let next: Vec<_> = provider
+        .next_after(monthly, &first)
+        .unwrap()
+        .take(2)
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(
+        next,
+        [
+            Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap(),
+        ]
+    );
+    let previous: Vec<_> = provider
+        .prev_before(monthly, &first)
+        .unwrap()
+        .take(2)
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(
+        previous,
+        [
+            Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        ]
+    );
+
+    // Sub-second times round towards the next fire time
+    let after = first + TimeDelta::milliseconds(500);
+    let every_second = parse_cron("* * * * * *").unwrap();
+    let next = provider.next_after(every_second, &after).unwrap().next();
+    assert_eq!(next.unwrap().unwrap(), first + TimeDelta::seconds(1));
+    let previous = provider.prev_before(every_second, &after).unwrap().next();
+    assert_eq!(previous.unwrap().unwrap(), first);
+
+    // The schedule follows the provider's time zone
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    let provider = ChronoProvider::new(tokyo);
+    let daily = parse_cron("0 9 * * *").unwrap();
+    let after = Utc.with_ymd_and_hms(2024, 3, 15, 0, 30, 0).unwrap();
+    let next = provider.next_after(daily, &after).unwrap().next().unwrap();
+    assert_eq!(
+        next.unwrap(),
+        tokyo.with_ymd_and_hms(2024, 3, 16, 9, 0, 0).unwrap()
+    );
+
+    // Schedules that never fire end
+    let never = parse_cron("0 0 31 4 *").unwrap();
+    assert_eq!(provider.next_after(never, &after).unwrap().count(), 0);
+}
+
+#[test]
+fn test_cron_schedules_across_dst() {
+    use chrono_tz::Europe::Berlin;
+
+    let provider = ChronoProvider::new(Berlin);
+    let times = |provider: &ChronoProvider<chrono_tz::Tz>, cron: &str, from, direction| -> Vec<_> {
+        let schedule = parse_cron(cron).unwrap();
+        match direction {
+            Direction::Future => provider
+                .next_after(schedule, &from)
+                .unwrap()
+                .take(4)
+                .collect(),
+            Direction::Past => provider
+                .prev_before(schedule, &from)
+                .unwrap()
+                .take(4)
+                .collect(),
+        }
+    };
+    let utc_times = |times: Vec<Result<DateTime<chrono_tz::Tz>>>| {
+        times
+            .into_iter()
+            .map(|time| time.unwrap().naive_utc().format("%d %H:%M").to_string())
+            .collect::<Vec<_>>()
+    };
+
+    // 02:00 to 03:00 does not exist on March 31; skipped times move forward
+    // and are not repeated
+    let before_spring = Berlin.with_ymd_and_hms(2024, 3, 31, 1, 0, 0).unwrap();
+    let result = times(&provider, "*/30 * * * *", before_spring, Direction::Future);
+    let local: Vec<_> = result
+        .into_iter()
+        .map(|time| time.unwrap().format("%H:%M").to_string())
+        .collect();
+    assert_eq!(local, ["01:30", "03:00", "03:30", "04:00"]);
+
+    // 01:00 to 02:00 repeats on October 27 and fires once
+    let before_fall = Berlin.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap();
+    let result = times(&provider, "0 * * * *", before_fall, Direction::Future);
+    assert_eq!(
+        utc_times(result),
+        ["26 23:00", "27 00:00", "27 02:00", "27 03:00"]
+    );
+    let after_fall = Berlin.with_ymd_and_hms(2024, 10, 27, 3, 30, 0).unwrap();
+    let result = times(&provider, "0 * * * *", after_fall, Direction::Past);
+    assert_eq!(
+        utc_times(result),
+        ["27 02:00", "27 00:00", "26 23:00", "26 22:00"]
+    );
+
+    // Skipped times are errors that end the iteration under Reject
+    let provider = provider.with_disambiguation(Disambiguation::Reject);
+    let result = times(&provider, "30 2 * * *", before_spring, Direction::Future);
+    assert_eq!(result.len(), 1);
+    assert!(matches!(result[0], Err(TempsError::AmbiguousTime { .. })));
+}
+
+#[test]
+fn test_humanize() {
+    let reference = Utc.with_ymd_and_hms(2024, 1, 31, 12, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Utc).with_reference(reference);
+    let humanize = |offset: TimeDelta, options: HumanizeOptions| {
+        provider.humanize(&(reference + offset), options).unwrap()
+    };
+    let english = HumanizeOptions::new(Language::English);
+
+    assert_eq!(humanize(TimeDelta::zero(), english), "now");
+    assert_eq!(humanize(TimeDelta::seconds(1), english), "in 1 second");
+    assert_eq!(humanize(TimeDelta::minutes(-5), english), "5 minutes ago");
+    assert_eq!(
+        humanize(TimeDelta::days(-2), english.with_rounding(Rounding::Down)),
+        "2 days ago"
+    );
+
+    // Rounding the last unit
+    let offset = TimeDelta::minutes(90);
+    assert_eq!(humanize(offset, english), "in 2 hours");
+    assert_eq!(
+        humanize(offset, english.with_rounding(Rounding::Down)),
+        "in 1 hour"
+    );
+    let offset = TimeDelta::minutes(61);
+    assert_eq!(humanize(offset, english), "in 1 hour");
+    assert_eq!(
+        humanize(offset, english.with_rounding(Rounding::Up)),
+        "in 2 hours"
+    );
+    assert_eq!(
+        humanize(-offset, english.with_rounding(Rounding::Up)),
+        "2 hours ago"
+    );
+
+    // Granularity, with rounding carried into larger units
+    let two_units = english.with_max_units(2);
+    assert_eq!(
+        humanize(TimeDelta::seconds(5430), two_units),
+        "in 1 hour 31 minutes"
+    );
+    assert_eq!(humanize(TimeDelta::seconds(7170), two_units), "in 2 hours");
+    assert_eq!(
+        humanize(
+            TimeDelta::seconds(90_061),
+            two_units.with_smallest_unit(TimeUnit::Minute)
+        ),
+        "in 1 day 1 hour"
+    );
+    assert_eq!(
+        humanize(
+            TimeDelta::seconds(20),
+            english.with_smallest_unit(TimeUnit::Minute)
+        ),
+        "now"
+    );
+
+    // Months and years are calendar months and years: January 31 to
+    // February 29 is one month
+    assert_eq!(humanize(TimeDelta::days(29), english), "in 1 month");
+    assert_eq!(
+        humanize(
+            TimeDelta::days(-366),
+            HumanizeOptions::new(Language::German)
+        ),
+        "vor 1 Jahr"
+    );
+    assert_eq!(
+        humanize(TimeDelta::weeks(2), HumanizeOptions::new(Language::German)),
+        "in 2 Wochen"
+    );
+
+    // The text resolves to the time, exactly when all units are written
+    for seconds in [
+        1,
+        59,
+        3_601,
+        86_399,
+        1_000_000,
+        10_000_000,
+        40_000_000,
+        123_456_789,
+    ] {
+        for offset in [TimeDelta::seconds(seconds), TimeDelta::seconds(-seconds)] {
+            let target = reference + offset;
+            for language in [Language::English, Language::German] {
+                let options = HumanizeOptions::new(language)
+                    .with_max_units(7)
+                    .with_rounding(Rounding::Down);
+                let text = provider.humanize(&target, options).unwrap();
+                let expr = parse(&text, language).unwrap();
+                assert_eq!(provider.parse_expression(expr).unwrap(), target, "{text}");
+
+                let text = provider.humanize(&target, language).unwrap();
+                let expr = parse(&text, language).unwrap();
+                let resolved = provider.parse_expression(expr).unwrap();
+                assert_eq!(
+                    provider.humanize(&resolved, language).unwrap(),
+                    text,
+                    "{text}"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_adapters() {
+    use serde::Deserialize;
+    use temps_chrono::de::{Settings, with_settings};
+
+    #[derive(Deserialize)]
+    struct Config {
+        #[serde(deserialize_with = "temps_chrono::de::utc::deserialize")]
+        expires_at: DateTime<Utc>,
+        #[serde(deserialize_with = "temps_chrono::de::local::deserialize")]
+        starts_at: DateTime<Local>,
+        #[serde(deserialize_with = "temps_chrono::de::time_delta::deserialize")]
+        timeout: TimeDelta,
+    }
+
+    let reference = Utc.with_ymd_and_hms(2024, 3, 15, 10, 30, 0).unwrap();
+    let read = |settings: Settings, json: &str| {
+        with_settings(settings, || serde_json::from_str::<Config>(json))
+    };
+
+    // Phrases resolve against the reference time
+    let json = r#"{"expires_at": "in 30 days", "starts_at": "now", "timeout": "2 hours"}"#;
+    let config = read(Settings::default().with_reference(reference), json).unwrap();
+    assert_eq!(
+        config.expires_at,
+        Utc.with_ymd_and_hms(2024, 4, 14, 10, 30, 0).unwrap()
+    );
+    assert_eq!(config.starts_at, reference);
+    assert_eq!(config.timeout, TimeDelta::hours(2));
+
+    // ISO 8601 strings and other languages
+    let json = r#"{
+        "expires_at": "2024-12-25T15:30:00+01:00",
+        "starts_at": "morgen um 9:00",
+        "timeout": "PT1H30M"
+    }"#;
+    let config = read(
+        Settings::new(Language::German).with_reference(reference),
+        json,
+    )
+    .unwrap();
+    assert_eq!(
+        config.expires_at,
+        Utc.with_ymd_and_hms(2024, 12, 25, 14, 30, 0).unwrap()
+    );
+    assert_eq!(
+        config.starts_at.date_naive(),
+        reference.date_naive().succ_opt().unwrap()
+    );
+    assert_eq!(config.starts_at.hour(), 9);
+    assert_eq!(config.timeout, TimeDelta::minutes(90));
+
+    // The settings only apply within the scope
+    let german = r#"{"expires_at": "in 30 Tagen", "starts_at": "jetzt", "timeout": "2 Stunden"}"#;
+    assert!(read(Settings::new(Language::German), german).is_ok());
+    assert!(serde_json::from_str::<Config>(german).is_err());
+
+    // Errors carry the parse error message
+    let json = r#"{"expires_at": "in 5 blargs", "starts_at": "now", "timeout": "2 hours"}"#;
+    let error = serde_json::from_str::<Config>(json).err().unwrap();
+    assert!(error.to_string().contains("Failed to parse"), "{error}");
+    let json = r#"{"expires_at": "now", "starts_at": "now", "timeout": "2 months"}"#;
+    assert!(serde_json::from_str::<Config>(json).is_err());
+
+    // The provider's policies apply
+    let provider = ChronoProvider::new(Utc)
+        .with_reference(reference)
+        .with_year_policy(YearPolicy::Previous);
+    let json = r#"{"expires_at": "March 20", "starts_at": "now", "timeout": "2 hours"}"#;
+    let config = read(Settings::default().with_provider(provider), json).unwrap();
+    assert_eq!(
+        config.expires_at,
+        Utc.with_ymd_and_hms(2023, 3, 20, 0, 0, 0).unwrap()
+    );
+}
+
+#[cfg(all(feature = "serde", feature = "chrono-tz"))]
+#[test]
+fn test_serde_tz_adapter() {
+    use chrono_tz::{Europe::Berlin, Tz};
+    use serde::Deserialize;
+    use temps_chrono::de::{Settings, with_settings};
+
+    #[derive(Debug, Deserialize)]
+    struct Job {
+        #[serde(deserialize_with = "temps_chrono::de::tz::deserialize")]
+        start: DateTime<Tz>,
+    }
+
+    // The day before clocks go forward at 2:00 in Berlin
+    let reference = Berlin.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    let provider = ChronoProvider::new(Berlin).with_reference(reference);
+    let read = |provider: ChronoProvider<Tz>, json: &str| {
+        with_settings(Settings::default().with_provider(provider), || {
+            serde_json::from_str::<Job>(json)
+        })
+    };
+
+    // Phrases resolve in the provider's named time zone
+    let job = read(provider.clone(), r#"{"start": "tomorrow at 9am"}"#).unwrap();
+    assert_eq!(
+        job.start,
+        Berlin.with_ymd_and_hms(2024, 3, 31, 9, 0, 0).unwrap()
+    );
+
+    // 2:30 does not exist that night
+    let gap = r#"{"start": "tomorrow at 2:30am"}"#;
+    let job = read(provider.clone(), gap).unwrap();
+    assert_eq!(
+        job.start,
+        Berlin.with_ymd_and_hms(2024, 3, 31, 3, 30, 0).unwrap()
+    );
+    let error = read(provider.with_disambiguation(Disambiguation::Reject), gap).unwrap_err();
+    assert!(error.to_string().contains("Ambiguous"), "{error}");
+
+    // Other time zones resolve in UTC
+    let utc = ChronoProvider::new(Utc).with_reference(reference.to_utc());
+    let job = with_settings(Settings::default().with_provider(utc), || {
+        serde_json::from_str::<Job>(r#"{"start": "now"}"#)
+    })
+    .unwrap();
+    assert_eq!(job.start.timezone(), chrono_tz::UTC);
+    assert_eq!(job.start, reference);
+}
@@ -0,0 +1,246 @@
+//! The temps grammars as reusable [`winnow`] parsers.
+//!
+//! Each language has a module of parsers with the usual winnow signature,
+//! `fn(&mut &str) -> winnow::Result<T>`, so a time expression can be embedded
+//! in a larger grammar. [`english::parse_expression`] and
+//! [`german::parse_expression`] accept everything [`crate::parse`] does; the
+//! other parsers cover one part of it, down to single words like weekdays and
+//! units.
+//!
+//! The parsers neither skip leading whitespace nor check what follows them,
+//! so "now" also matches the start of "nowhere". Combine them with
+//! [`word_boundary`] where that matters. Numeric dates in English depend on a
+//! [`DateOrder`](crate::DateOrder), which those parsers take as a second
+//! argument; wrap them in a closure to use them as a parser.
+//!
+//! The winnow version is part of this API and is re-exported as
+//! [`winnow`] so downstream grammars can use the same one.
+//!
+//! # Examples
+//!
+//! ```
+//! use temps_core::combinators::{english, winnow};
+//! use temps_core::{DateOrder, TimeExpression};
+//! use winnow::ascii::{multispace1, Caseless};
+//! use winnow::combinator::preceded;
+//! use winnow::token::rest;
+//! use winnow::Parser;
+//!
+//! // remind me <time expression> to <task>
+//! let mut reminder = (
+//!     preceded(
+//!         (Caseless("remind me"), multispace1),
+//!         |input: &mut &str| english::parse_expression(input, DateOrder::Dmy),
+//!     ),
+//!     preceded((multispace1, Caseless("to"), multispace1), rest),
+//! );
+//!
+//! let (when, task) = reminder.parse("remind me tomorrow at 9am to call Bob").unwrap();
+//! assert!(matches!(when, TimeExpression::DayTime(_)));
+//! assert_eq!(task, "call Bob");
+//! ```
+
+pub use winnow;
+
+pub use crate::common::{parse_iso_datetime, parse_iso_duration, word_boundary};
+
+/// Parsers for English time expressions.
+pub mod english {
+    use winnow::Parser;
+
+    use crate::language::english::EnglishParser;
+    use crate::spanned::alt;
+    use crate::{
+        DateOrder, DayReference, Duration, Meridiem, Time, TimeExpression, TimeUnit, Weekday,
+        common,
+    };
+
+    /// Parse any time expression, like [`crate::parse`]: "in 5 minutes",
+    /// "tomorrow at 3pm", "from 9am to 5pm", "every Monday"
+    pub fn parse_expression(input: &mut &str, order: DateOrder) -> winnow::Result<TimeExpression> {
+        EnglishParser::parse_expression(input, order)
+    }
+
+    /// Parse a single point in time or a recurrence, but not an interval
+    pub fn parse_point(input: &mut &str, order: DateOrder) -> winnow::Result<TimeExpression> {
+        EnglishParser::parse_point(input, order)
+    }
+
+    /// Parse an interval: "from 3pm to 5pm", "between Monday and Friday", "3-5pm"
+    pub fn parse_interval(input: &mut &str, order: DateOrder) -> winnow::Result<TimeExpression> {
+        EnglishParser::parse_interval(input, order)
+    }
+
+    /// Parse a standalone duration, like [`crate::parse_duration`]: "90 minutes",
+    /// "2h30m", "PT2H"
+    pub fn parse_duration(input: &mut &str) -> winnow::Result<Duration> {
+        alt((
+            common::parse_iso_duration,
+            EnglishParser::parse_standalone_duration,
+        ))
+        .parse_next(input)
+    }
+
+    /// Parse a relative time: "in 2 hours", "3 days ago"
+    pub fn parse_relative(input: &mut &str) -> winnow::Result<TimeExpression> {
+        alt((
+            EnglishParser::parse_relative_past,
+            EnglishParser::parse_relative_future,
+        ))
+        .parse_next(input)
+    }
+
+    /// Parse a date: "2024-03-15", "15/03/2024", "March 5th 2024"
+    pub fn parse_date(input: &mut &str, order: DateOrder) -> winnow::Result<TimeExpression> {
+        EnglishParser::parse_date_format(input, order)
+    }
+
+    /// Parse a named day: "tomorrow", "next Monday", "Friday next week"
+    pub fn parse_day(input: &mut &str) -> winnow::Result<DayReference> {
+        EnglishParser::parse_day(input)
+    }
+
+    /// Parse a time of day: "3:30 pm", "14:45", "9am"
+    pub fn parse_time(input: &mut &str) -> winnow::Result<Time> {
+        EnglishParser::parse_time
+            .verify_map(|expression| match expression {
+                TimeExpression::Time(time) => Some(time),
+                _ => None,
+            })
+            .parse_next(input)
+    }
+
+    /// Parse a period: "last week", "this month", "next quarter"
+    pub fn parse_period(input: &mut &str) -> winnow::Result<TimeExpression> {
+        EnglishParser::parse_period(input)
+    }
+
+    /// Parse a period boundary: "end of month", "start of next week", "EOD"
+    pub fn parse_boundary(input: &mut &str) -> winnow::Result<TimeExpression> {
+        EnglishParser::parse_boundary(input)
+    }
+
+    /// Parse a recurrence: "every Monday at 9am", "daily until 31/12/2024"
+    pub fn parse_recurrence(input: &mut &str, order: DateOrder) -> winnow::Result<TimeExpression> {
+        EnglishParser::parse_recurrence(input, order)
+    }
+
+    /// Parse a weekday name or abbreviation: "Monday", "mon"
+    pub fn parse_weekday(input: &mut &str) -> winnow::Result<Weekday> {
+        EnglishParser::parse_weekday(input)
+    }
+
+    /// Parse a month name or abbreviation as its number: "March", "Mar"
+    pub fn parse_month_name(input: &mut &str) -> winnow::Result<u8> {
+        EnglishParser::parse_month_name(input)
+    }
+
+    /// Parse a time unit: "minutes", "h", "weeks"
+    pub fn parse_time_unit(input: &mut &str) -> winnow::Result<TimeUnit> {
+        EnglishParser::parse_time_unit(input)
+    }
+
+    /// Parse an amount in digits or words: "5", "five", "a"
+    pub fn parse_number(input: &mut &str) -> winnow::Result<i64> {
+        EnglishParser::parse_number(input)
+    }
+
+    /// Parse "am" or "pm", with or without dots
+    pub fn parse_meridiem(input: &mut &str) -> winnow::Result<Meridiem> {
+        EnglishParser::parse_meridiem(input)
+    }
+}
+
+/// Parsers for German time expressions.
+pub mod german {
+    use winnow::Parser;
+
+    use crate::language::german::GermanParser;
+    use crate::spanned::alt;
+    use crate::{DayReference, Duration, Time, TimeExpression, TimeUnit, Weekday, common};
+
+    /// Parse any time expression, like [`crate::parse`]: "in 5 Minuten",
+    /// "morgen um 15:00", "von 9 bis 17 Uhr", "jeden Montag"
+    pub fn parse_expression(input: &mut &str) -> winnow::Result<TimeExpression> {
+        GermanParser::parse_expression(input)
+    }
+
+    /// Parse a single point in time or a recurrence, but not an interval
+    pub fn parse_point(input: &mut &str) -> winnow::Result<TimeExpression> {
+        GermanParser::parse_point(input)
+    }
+
+    /// Parse an interval: "von 9 bis 17 Uhr", "zwischen Montag und Freitag"
+    pub fn parse_interval(input: &mut &str) -> winnow::Result<TimeExpression> {
+        GermanParser::parse_interval(input)
+    }
+
+    /// Parse a standalone duration, like [`crate::parse_duration`]:
+    /// "90 Minuten", "2 Stunden und 30 Minuten", "PT2H"
+    pub fn parse_duration(input: &mut &str) -> winnow::Result<Duration> {
+        alt((
+            common::parse_iso_duration,
+            GermanParser::parse_standalone_duration,
+        ))
+        .parse_next(input)
+    }
+
+    /// Parse a relative time: "in 2 Stunden", "vor 3 Tagen"
+    pub fn parse_relative(input: &mut &str) -> winnow::Result<TimeExpression> {
+        alt((
+            GermanParser::parse_relative_past,
+            GermanParser::parse_relative_future,
+        ))
+        .parse_next(input)
+    }
+
+    /// Parse a date: "15.03.2024", "am 24.12.", "5. März 2024"
+    pub fn parse_date(input: &mut &str) -> winnow::Result<TimeExpression> {
+        GermanParser::parse_date_format(input)
+    }
+
+    /// Parse a named day: "morgen", "nächsten Montag"
+    pub fn parse_day(input: &mut &str) -> winnow::Result<DayReference> {
+        GermanParser::parse_day(input)
+    }
+
+    /// Parse a time of day: "14:30", "9:45 Uhr", "15 Uhr"
+    pub fn parse_time(input: &mut &str) -> winnow::Result<Time> {
+        GermanParser::parse_time_of_day(input)
+    }
+
+    /// Parse a period: "letzte Woche", "diesen Monat", "nächstes Jahr"
+    pub fn parse_period(input: &mut &str) -> winnow::Result<TimeExpression> {
+        GermanParser::parse_period(input)
+    }
+
+    /// Parse a period boundary: "Ende des Monats", "Anfang nächster Woche"
+    pub fn parse_boundary(input: &mut &str) -> winnow::Result<TimeExpression> {
+        GermanParser::parse_boundary(input)
+    }
+
+    /// Parse a recurrence: "jeden Montag um 9:00", "alle 2 Wochen am Dienstag"
+    pub fn parse_recurrence(input: &mut &str) -> winnow::Result<TimeExpression> {
+        GermanParser::parse_recurrence(input)
+    }
+
+    /// Parse a weekday name or abbreviation: "Montag", "mo"
+    pub fn parse_weekday(input: &mut &str) -> winnow::Result<Weekday> {
+        GermanParser::parse_weekday(input)
+    }
+
+    /// Parse a month name or abbreviation as its number: "März", "Mär"
+    pub fn parse_month_name(input: &mut &str) -> winnow::Result<u8> {
+        GermanParser::parse_month_name(input)
+    }
+
+    /// Parse a time unit: "Minuten", "Std", "Wochen"
+    pub fn parse_time_unit(input: &mut &str) -> winnow::Result<TimeUnit> {
+        GermanParser::parse_time_unit(input)
+    }
+
+    /// Parse an amount in digits or words: "5", "fünf", "einem"
+    pub fn parse_number(input: &mut &str) -> winnow::Result<i64> {
+        GermanParser::parse_number(input)
+    }
+}
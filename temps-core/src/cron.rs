@@ -0,0 +1,328 @@
+//! Cron expressions.
+//!
+//! [`parse_cron`] reads standard five-field (`minute hour day month weekday`)
+//! and six-field (`second minute hour day month weekday`) expressions into a
+//! [`CronSchedule`]. [`cron_times`] finds the local times a schedule fires at.
+//!
+//! # Examples
+//!
+//! ```
+//! use temps_core::parse_cron;
+//!
+//! // Every 15 minutes during office hours
+//! let schedule = parse_cron("*/15 9-17 * * MON-FRI").unwrap();
+//! assert_eq!(schedule.hours.iter().collect::<Vec<_>>(), (9..=17).collect::<Vec<_>>());
+//!
+//! // At 30 seconds past every minute
+//! let schedule = parse_cron("30 * * * * *").unwrap();
+//! assert_eq!(schedule.seconds.iter().collect::<Vec<_>>(), [30]);
+//! ```
+
+use std::ops::RangeInclusive;
+
+use crate::{
+    CronField, CronSchedule, Direction, Result, StandardDate, TempsError, Time, Weekday,
+    WeekdaySet,
+    constants::{SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE},
+    errors::*,
+    time_utils::{
+        DAYS_PER_CALENDAR_CYCLE, civil_from_days, convert_12_to_24_hour, days_from_civil,
+        monday_offset,
+    },
+};
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Parse a cron expression.
+///
+/// Fields are separated by whitespace and hold `*`, values, ranges (`9-17`),
+/// steps (`*/15`, `0-30/10`, `5/20`) or comma-separated lists of these.
+/// Months and weekdays may be named (`JAN`, `MON`, case-insensitive), Sunday
+/// is `0` or `7`, and `?` stands for `*` in the day fields. The macros
+/// `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` and
+/// `@hourly` are accepted as well.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Weekday, parse_cron};
+///
+/// // Midnight on the 1st and 15th, and on every Monday
+/// let schedule = parse_cron("0 0 1,15 * MON").unwrap();
+/// assert!(schedule.days.contains(15));
+/// assert!(schedule.weekdays.contains(Weekday::Monday));
+/// assert!(schedule.either_day);
+///
+/// assert_eq!(parse_cron("@weekly"), parse_cron("0 0 * * 0"));
+/// ```
+///
+/// # Errors
+///
+/// Returns `TempsError::ParseError` with the position of the offending field
+/// if the expression does not have five or six fields, a field is malformed
+/// or out of range, or it uses extensions such as `L`, `W` or `#`.
+pub fn parse_cron(input: &str) -> Result<CronSchedule> {
+    let expanded = match input.trim().to_ascii_lowercase().as_str() {
+        "@yearly" | "@annually" => Some("0 0 1 1 *"),
+        "@monthly" => Some("0 0 1 * *"),
+        "@weekly" => Some("0 0 * * 0"),
+        "@daily" | "@midnight" => Some("0 0 * * *"),
+        "@hourly" => Some("0 * * * *"),
+        _ => None,
+    };
+    if let Some(expanded) = expanded {
+        return parse_cron(expanded);
+    }
+
+    let fields: Vec<&str> = input.split_whitespace().collect();
+    let (seconds, fields) = match fields.as_slice() {
+        [_, _, _, _, _] => (None, fields.as_slice()),
+        [seconds, rest @ ..] if rest.len() == 5 => (Some(*seconds), rest),
+        _ => {
+            return Err(TempsError::parse_error_with_position(
+                ERR_CRON_FIELD_COUNT,
+                input,
+                0,
+            ));
+        }
+    };
+    let [minutes, hours, days, months, weekdays] = fields else {
+        unreachable!("five fields remain");
+    };
+
+    let parse = |field: &str, range, names: &[&str]| {
+        parse_field(field, range, names).map_err(|message| {
+            let position = field.as_ptr() as usize - input.as_ptr() as usize;
+            TempsError::parse_error_with_position(message, input, position)
+        })
+    };
+    let seconds = match seconds {
+        Some(seconds) => parse(seconds, 0..=59, &[])?,
+        None => CronField::empty().with(0),
+    };
+    // Cron matches either day field only if both are restricted
+    let unrestricted = |field: &str| field.starts_with(['*', '?']);
+    let either_day = !unrestricted(days) && !unrestricted(weekdays);
+    let weekday_field = parse(open_day(weekdays), 0..=7, &WEEKDAY_NAMES)?;
+    // Both 0 and 7 are Sunday
+    let weekdays = weekday_field
+        .iter()
+        .map(|day| match day {
+            1 => Weekday::Monday,
+            2 => Weekday::Tuesday,
+            3 => Weekday::Wednesday,
+            4 => Weekday::Thursday,
+            5 => Weekday::Friday,
+            6 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        })
+        .collect::<WeekdaySet>();
+
+    Ok(CronSchedule {
+        seconds,
+        minutes: parse(minutes, 0..=59, &[])?,
+        hours: parse(hours, 0..=23, &[])?,
+        days: parse(open_day(days), 1..=31, &[])?,
+        months: parse(months, 1..=12, &MONTH_NAMES)?,
+        weekdays,
+        either_day,
+    })
+}
+
+/// "?" leaves a day field open, like "*"
+fn open_day(field: &str) -> &str {
+    if field == "?" { "*" } else { field }
+}
+
+/// Parse one field, with `names` standing for the values from the start of
+/// `range`
+fn parse_field(
+    field: &str,
+    range: RangeInclusive<u8>,
+    names: &[&str],
+) -> std::result::Result<CronField, &'static str> {
+    let (min, max) = (*range.start(), *range.end());
+    let value = |value: &str| -> std::result::Result<u8, &'static str> {
+        if let Some(index) = names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(value))
+        {
+            return Ok(min + index as u8);
+        }
+        if value.contains(['L', 'W', '#', 'l', 'w']) {
+            return Err(ERR_CRON_UNSUPPORTED);
+        }
+        value
+            .parse()
+            .ok()
+            .filter(|value| range.contains(value))
+            .ok_or(ERR_CRON_INVALID_FIELD)
+    };
+
+    let mut values = CronField::empty();
+    for item in field.split(',') {
+        let (base, step) = match item.split_once('/') {
+            Some((base, step)) => {
+                let step: u8 = step
+                    .parse()
+                    .ok()
+                    .filter(|&step| step > 0)
+                    .ok_or(ERR_CRON_INVALID_FIELD)?;
+                (base, Some(step))
+            }
+            None => (item, None),
+        };
+        let (first, last) = match base {
+            "*" => (min, max),
+            base => match base.split_once('-') {
+                Some((first, last)) => {
+                    let (first, last) = (value(first)?, value(last)?);
+                    // "FRI-SUN" wraps to Sunday as 7
+                    let last = if names == WEEKDAY_NAMES && last == 0 {
+                        7
+                    } else {
+                        last
+                    };
+                    (first, last)
+                }
+                // "5/20" runs from 5 to the end of the range
+                None if step.is_some() => (value(base)?, max),
+                None => (value(base)?, value(base)?),
+            },
+        };
+        if first > last {
+            return Err(ERR_CRON_INVALID_FIELD);
+        }
+        let step = usize::from(step.unwrap_or(1));
+        values = (first..=last).step_by(step).fold(values, CronField::with);
+    }
+    Ok(values)
+}
+
+/// Find the local times that fire a cron schedule
+///
+/// Times are yielded from `start` at `time`, inclusive, into the future
+/// or the past. The iterator ends once no time has been found for 400
+/// years, for schedules such as `0 0 30 2 *` that never fire.
+///
+/// # Examples
+/// ```
+/// use temps_core::{Direction, StandardDate, Time, parse_cron, cron::cron_times};
+///
+/// // Friday
+/// let today = StandardDate { day: 15, month: 3, year: 2024 };
+/// let time = |hour, minute| Time { hour, minute, second: 0, meridiem: None };
+/// let schedule = parse_cron("*/15 9-17 * * MON-FRI").unwrap();
+///
+/// let mut next = cron_times(schedule, today, time(17, 50), Direction::Future);
+/// let monday = StandardDate { day: 18, ..today };
+/// assert_eq!(next.next(), Some((monday, time(9, 0))));
+/// assert_eq!(next.next(), Some((monday, time(9, 15))));
+///
+/// let mut previous = cron_times(schedule, today, time(17, 50), Direction::Past);
+/// assert_eq!(previous.next(), Some((today, time(17, 45))));
+/// ```
+pub fn cron_times(
+    schedule: CronSchedule,
+    start: StandardDate,
+    time: Time,
+    direction: Direction,
+) -> impl Iterator<Item = (StandardDate, Time)> {
+    let seconds_per_day = SECONDS_PER_DAY as i32;
+    let (step, first_of_day) = match direction {
+        Direction::Future => (1, 0),
+        Direction::Past => (-1, seconds_per_day - 1),
+    };
+    // The closest value in `field` from `value` on, up to `max`
+    let find = move |field: CronField, value: u8, max: u8| {
+        match direction {
+            Direction::Future => field.next_from(value),
+            Direction::Past => field.prev_from(value),
+        }
+        .filter(|&found| found <= max)
+    };
+    let advance = move |value: u8| value.checked_add_signed(step as i8);
+
+    // The closest time of day that fires from `second` on
+    let first_time = move |second: i32| -> Option<i32> {
+        let (hour, minute, second) = (
+            (second / SECONDS_PER_HOUR) as u8,
+            (second % SECONDS_PER_HOUR / SECONDS_PER_MINUTE) as u8,
+            (second % SECONDS_PER_MINUTE) as u8,
+        );
+        let reset = match direction {
+            Direction::Future => 0,
+            Direction::Past => 59,
+        };
+        let mut found_hour = find(schedule.hours, hour, 23)?;
+        loop {
+            let from = if found_hour == hour { minute } else { reset };
+            let mut found_minute = find(schedule.minutes, from, 59);
+            while let Some(minute_found) = found_minute {
+                let from = if found_hour == hour && minute_found == minute {
+                    second
+                } else {
+                    reset
+                };
+                if let Some(found_second) = find(schedule.seconds, from, 59) {
+                    return Some(
+                        i32::from(found_hour) * SECONDS_PER_HOUR
+                            + i32::from(minute_found) * SECONDS_PER_MINUTE
+                            + i32::from(found_second),
+                    );
+                }
+                found_minute =
+                    advance(minute_found).and_then(|next| find(schedule.minutes, next, 59));
+            }
+            found_hour = find(schedule.hours, advance(found_hour)?, 23)?;
+        }
+    };
+
+    let mut day = days_from_civil(start);
+    let mut from = i32::from(convert_12_to_24_hour(time.hour, time.meridiem.as_ref()))
+        * SECONDS_PER_HOUR
+        + i32::from(time.minute) * SECONDS_PER_MINUTE
+        + i32::from(time.second);
+    std::iter::from_fn(move || {
+        for _ in 0..=DAYS_PER_CALENDAR_CYCLE {
+            let date = civil_from_days(day)?;
+            let days = schedule.days.contains(date.day);
+            let weekdays = schedule
+                .weekdays
+                .contains(WeekdaySet::DAYS[monday_offset(day) as usize]);
+            let day_matches = if schedule.either_day {
+                days || weekdays
+            } else {
+                days && weekdays
+            };
+            let found = if schedule.months.contains(date.month) && day_matches {
+                first_time(from)
+            } else {
+                None
+            };
+
+            if let Some(second) = found {
+                // Carry on from the next second
+                from = second + step;
+                let found_day = day;
+                if !(0..seconds_per_day).contains(&from) {
+                    day += i64::from(step);
+                    from = first_of_day;
+                }
+                let time = Time {
+                    hour: (second / SECONDS_PER_HOUR) as u8,
+                    minute: (second % SECONDS_PER_HOUR / SECONDS_PER_MINUTE) as u8,
+                    second: (second % SECONDS_PER_MINUTE) as u8,
+                    meridiem: None,
+                };
+                return Some((civil_from_days(found_day)?, time));
+            }
+            day += i64::from(step);
+            from = first_of_day;
+        }
+        None
+    })
+}
@@ -31,7 +31,16 @@ use thiserror::Error;
 ///
 /// This enum represents all possible errors that can occur during
 /// parsing and time calculation operations.
+///
+/// With the `serde` feature, errors serialize tagged by their variant name in
+/// snake case, with the fields in `value`:
+/// `{"type": "parse_error", "value": {"message": ..., "input": ..., "position": 3}}`.
 #[derive(Error, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
 pub enum TempsError {
     /// Error that occurs during parsing of time expressions.
     ///
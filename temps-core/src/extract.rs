@@ -0,0 +1,129 @@
+//! Time expressions in free text.
+//!
+//! [`find_iter`] scans text such as chat messages or emails for time
+//! expressions and yields each with its byte range. At every word it takes
+//! the longest run of words that parses, then continues after it, so matches
+//! never overlap.
+//!
+//! # Examples
+//!
+//! ```
+//! use temps_core::{Language, extract};
+//!
+//! let text = "Morgen um 9:00 geht nicht, lieber nächsten Montag.";
+//! let found: Vec<&str> = extract(text, Language::German)
+//!     .into_iter()
+//!     .map(|found| &text[found.range])
+//!     .collect();
+//! assert_eq!(found, ["Morgen um 9:00", "nächsten Montag"]);
+//! ```
+
+use crate::{Match, ParseOptions, parse};
+
+/// The most words an expression may span, e.g.
+/// "from next Monday at 9:00 am to next Friday at 5:00 pm"
+const MAX_WORDS: usize = 16;
+
+/// Find the time expressions in `text`, from left to right.
+///
+/// Expressions start at the beginning of a word and end at the end of a word
+/// or after punctuation ("24.12."). Where several runs of words from the same
+/// start parse, the longest wins.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{DayReference, Language, TimeExpression, find_iter};
+///
+/// let text = "Ship it tomorrow, or by the end of the week at the latest.";
+/// let mut found = find_iter(text, Language::English);
+///
+/// let tomorrow = found.next().unwrap();
+/// assert_eq!(tomorrow.expression, TimeExpression::Day(DayReference::Tomorrow));
+/// assert_eq!(tomorrow.range, 8..16);
+///
+/// assert_eq!(&text[found.next().unwrap().range], "end of the week");
+/// assert_eq!(found.next(), None);
+/// ```
+pub fn find_iter(text: &str, options: impl Into<ParseOptions>) -> impl Iterator<Item = Match> {
+    let options = options.into();
+    let starts = word_starts(text);
+    let ends = word_ends(text);
+    let mut cursor = 0;
+
+    starts.into_iter().filter_map(move |start| {
+        if start < cursor {
+            return None;
+        }
+        // Ends within reach, the farthest first
+        let window_end = text[start..]
+            .split_whitespace()
+            .nth(MAX_WORDS - 1)
+            .map_or(text.len(), |word| {
+                word.as_ptr() as usize - text.as_ptr() as usize + word.len()
+            });
+        let reachable = ends.partition_point(|&end| end <= start)
+            ..ends.partition_point(|&end| end <= window_end);
+        let found = ends[reachable].iter().rev().find_map(|&end| {
+            parse(&text[start..end], options)
+                .ok()
+                .map(|expression| Match {
+                    expression,
+                    range: start..end,
+                })
+        })?;
+        cursor = found.range.end;
+        Some(found)
+    })
+}
+
+/// Find the time expressions in `text`, like [`find_iter`].
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Language, extract};
+///
+/// let text = "Standup every weekday at 9:30, retro on Friday.";
+/// let found = extract(text, Language::English);
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(&text[found[0].range.clone()], "every weekday at 9:30");
+/// assert_eq!(&text[found[1].range.clone()], "Friday");
+/// ```
+#[must_use]
+pub fn extract(text: &str, options: impl Into<ParseOptions>) -> Vec<Match> {
+    find_iter(text, options).collect()
+}
+
+/// Byte offsets of the alphanumeric characters that follow a non-alphanumeric one
+fn word_starts(text: &str) -> Vec<usize> {
+    let mut previous = None;
+    text.char_indices()
+        .filter(|&(_, c)| {
+            let start = c.is_alphanumeric() && !previous.is_some_and(char::is_alphanumeric);
+            previous = Some(c);
+            start
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Byte offsets after the characters that are followed by whitespace, the
+/// end of the text or, for alphanumeric characters, punctuation
+fn word_ends(text: &str) -> Vec<usize> {
+    let mut chars = text.char_indices().peekable();
+    let mut ends = Vec::new();
+    while let Some((index, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let end = index + c.len_utf8();
+        match chars.peek() {
+            None => ends.push(end),
+            Some(&(_, next)) if next.is_whitespace() => ends.push(end),
+            Some(&(_, next)) if c.is_alphanumeric() && !next.is_alphanumeric() => ends.push(end),
+            Some(_) => {}
+        }
+    }
+    ends
+}
@@ -1,12 +1,17 @@
 use winnow::{
     Parser,
-    ascii::{Caseless, multispace0, multispace1},
-    combinator::{alt, delimited, opt, preceded},
+    ascii::{Caseless, digit1, multispace0, multispace1},
+    combinator::{delimited, fail, preceded, separated_pair, terminated},
 };
 
 use crate::{
-    DayReference, DayTime, Direction, LanguageParser, Meridiem, RelativeTime, Result, StandardDate,
-    Time, TimeExpression, TimeUnit, Weekday, WeekdayModifier, common, error::ParseErrorExt,
+    Boundary, Candidate, Component, DateOrder, DayReference, DayTime, Direction, Duration, Edge,
+    Frequency, Interval, Language, LanguageParser, Meridiem, ParseOptions, Period, PeriodUnit,
+    Recurrence, RecurrenceEnd, RelativeDuration, RelativeTime, Result, Rule, StandardDate, Time,
+    TimeExpression, TimeUnit, Weekday, WeekdayModifier, WeekdaySet, common,
+    error::ParseErrorExt,
+    language::german::GermanParser,
+    spanned::{alt, component, opt, repeat, separated},
     time_utils,
 };
 
@@ -14,112 +19,169 @@ use crate::{
 pub struct EnglishParser;
 
 impl EnglishParser {
-    fn parse_number(input: &mut &str) -> winnow::Result<i64> {
-        alt((
-            common::parse_digit_number,
+    pub(crate) fn parse_number(input: &mut &str) -> winnow::Result<i64> {
+        component(
+            Component::Amount,
             alt((
-                Caseless("an").value(1),
-                Caseless("a").value(1),
-                Caseless("one").value(1),
-                Caseless("two").value(2),
-                Caseless("three").value(3),
-                Caseless("four").value(4),
-                Caseless("five").value(5),
+                common::parse_digit_number,
+                alt((
+                    Caseless("an").value(1),
+                    Caseless("a").value(1),
+                    Caseless("one").value(1),
+                    Caseless("two").value(2),
+                    Caseless("three").value(3),
+                    Caseless("four").value(4),
+                    Caseless("five").value(5),
+                )),
+                alt((
+                    Caseless("six").value(6),
+                    Caseless("seven").value(7),
+                    Caseless("eight").value(8),
+                    Caseless("nine").value(9),
+                    Caseless("ten").value(10),
+                )),
             )),
+        )
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_time_unit(input: &mut &str) -> winnow::Result<TimeUnit> {
+        component(
+            Component::Unit,
             alt((
-                Caseless("six").value(6),
-                Caseless("seven").value(7),
-                Caseless("eight").value(8),
-                Caseless("nine").value(9),
-                Caseless("ten").value(10),
+                alt((
+                    Caseless("seconds").value(TimeUnit::Second),
+                    Caseless("second").value(TimeUnit::Second),
+                    Caseless("secs").value(TimeUnit::Second),
+                    Caseless("sec").value(TimeUnit::Second),
+                    Caseless("s").value(TimeUnit::Second),
+                )),
+                alt((
+                    Caseless("minutes").value(TimeUnit::Minute),
+                    Caseless("minute").value(TimeUnit::Minute),
+                    Caseless("mins").value(TimeUnit::Minute),
+                    Caseless("min").value(TimeUnit::Minute),
+                )),
+                alt((
+                    Caseless("hours").value(TimeUnit::Hour),
+                    Caseless("hour").value(TimeUnit::Hour),
+                    Caseless("hrs").value(TimeUnit::Hour),
+                    Caseless("hr").value(TimeUnit::Hour),
+                    Caseless("h").value(TimeUnit::Hour),
+                )),
+                alt((
+                    Caseless("days").value(TimeUnit::Day),
+                    Caseless("day").value(TimeUnit::Day),
+                    Caseless("d").value(TimeUnit::Day),
+                )),
+                alt((
+                    Caseless("weeks").value(TimeUnit::Week),
+                    Caseless("week").value(TimeUnit::Week),
+                    Caseless("wks").value(TimeUnit::Week),
+                    Caseless("wk").value(TimeUnit::Week),
+                    Caseless("w").value(TimeUnit::Week),
+                )),
+                alt((
+                    Caseless("months").value(TimeUnit::Month),
+                    Caseless("month").value(TimeUnit::Month),
+                    Caseless("mos").value(TimeUnit::Month),
+                    Caseless("mo").value(TimeUnit::Month),
+                )),
+                alt((
+                    Caseless("years").value(TimeUnit::Year),
+                    Caseless("year").value(TimeUnit::Year),
+                    Caseless("yrs").value(TimeUnit::Year),
+                    Caseless("yr").value(TimeUnit::Year),
+                    Caseless("y").value(TimeUnit::Year),
+                )),
+                // Single-letter abbreviations last to avoid ambiguity
+                Caseless("m").value(TimeUnit::Minute),
             )),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_duration_component(input: &mut &str) -> winnow::Result<(i64, TimeUnit)> {
+        (Self::parse_number, multispace1, Self::parse_time_unit)
+            .map(|(amount, _, unit)| (amount, unit))
+            .parse_next(input)
+    }
+
+    fn parse_duration_separator(input: &mut &str) -> winnow::Result<()> {
+        alt((
+            (opt(','), multispace1, Caseless("and"), multispace1).void(),
+            (',', multispace0).void(),
+            multispace1.void(),
         ))
         .parse_next(input)
     }
 
-    fn parse_time_unit(input: &mut &str) -> winnow::Result<TimeUnit> {
+    fn parse_duration_components(input: &mut &str) -> winnow::Result<Vec<(i64, TimeUnit)>> {
+        separated(
+            1..,
+            Self::parse_duration_component,
+            Self::parse_duration_separator,
+        )
+        .parse_next(input)
+    }
+
+    fn parse_duration_amount<'i>(input: &mut &'i str) -> winnow::Result<(i64, Option<&'i str>)> {
         alt((
-            alt((
-                Caseless("seconds").value(TimeUnit::Second),
-                Caseless("second").value(TimeUnit::Second),
-                Caseless("secs").value(TimeUnit::Second),
-                Caseless("sec").value(TimeUnit::Second),
-                Caseless("s").value(TimeUnit::Second),
-            )),
-            alt((
-                Caseless("minutes").value(TimeUnit::Minute),
-                Caseless("minute").value(TimeUnit::Minute),
-                Caseless("mins").value(TimeUnit::Minute),
-                Caseless("min").value(TimeUnit::Minute),
-            )),
-            alt((
-                Caseless("hours").value(TimeUnit::Hour),
-                Caseless("hour").value(TimeUnit::Hour),
-                Caseless("hrs").value(TimeUnit::Hour),
-                Caseless("hr").value(TimeUnit::Hour),
-                Caseless("h").value(TimeUnit::Hour),
-            )),
-            alt((
-                Caseless("days").value(TimeUnit::Day),
-                Caseless("day").value(TimeUnit::Day),
-                Caseless("d").value(TimeUnit::Day),
-            )),
-            alt((
-                Caseless("weeks").value(TimeUnit::Week),
-                Caseless("week").value(TimeUnit::Week),
-                Caseless("wks").value(TimeUnit::Week),
-                Caseless("wk").value(TimeUnit::Week),
-                Caseless("w").value(TimeUnit::Week),
-            )),
-            alt((
-                Caseless("months").value(TimeUnit::Month),
-                Caseless("month").value(TimeUnit::Month),
-                Caseless("mos").value(TimeUnit::Month),
-                Caseless("mo").value(TimeUnit::Month),
-            )),
-            alt((
-                Caseless("years").value(TimeUnit::Year),
-                Caseless("year").value(TimeUnit::Year),
-                Caseless("yrs").value(TimeUnit::Year),
-                Caseless("yr").value(TimeUnit::Year),
-                Caseless("y").value(TimeUnit::Year),
-            )),
-            // Single-letter abbreviations last to avoid ambiguity
-            Caseless("m").value(TimeUnit::Minute),
+            (common::parse_digit_number, opt(preceded('.', digit1))),
+            Self::parse_number.map(|amount| (amount, None)),
         ))
         .parse_next(input)
     }
 
-    fn parse_relative_past(input: &mut &str) -> winnow::Result<TimeExpression> {
+    fn parse_standalone_duration_component<'i>(
+        input: &mut &'i str,
+    ) -> winnow::Result<(i64, Option<&'i str>, TimeUnit)> {
         (
-            Self::parse_number,
-            multispace1,
+            Self::parse_duration_amount,
+            multispace0,
             Self::parse_time_unit,
+        )
+            .map(|((amount, fraction), _, unit)| (amount, fraction, unit))
+            .parse_next(input)
+    }
+
+    pub(crate) fn parse_standalone_duration(input: &mut &str) -> winnow::Result<Duration> {
+        // Units may be attached to the number and separators omitted ("2h30m")
+        (
+            Self::parse_standalone_duration_component,
+            repeat(
+                0..,
+                preceded(
+                    opt(Self::parse_duration_separator),
+                    Self::parse_standalone_duration_component,
+                ),
+            ),
+        )
+            .verify_map(|(first, rest): (_, Vec<_>)| {
+                let components: Vec<_> = std::iter::once(first).chain(rest).collect();
+                common::duration_from_components(&components)
+            })
+            .parse_next(input)
+    }
+
+    pub(crate) fn parse_relative_past(input: &mut &str) -> winnow::Result<TimeExpression> {
+        (
+            Self::parse_duration_components,
             multispace1,
             Caseless("ago"),
         )
-            .map(|(amount, _, unit, _, _)| {
-                TimeExpression::Relative(RelativeTime {
-                    amount,
-                    unit,
-                    direction: Direction::Past,
-                })
+            .verify_map(|(components, _, _)| {
+                common::relative_expression(&components, Direction::Past)
             })
             .parse_next(input)
     }
 
-    fn parse_relative_future(input: &mut &str) -> winnow::Result<TimeExpression> {
+    pub(crate) fn parse_relative_future(input: &mut &str) -> winnow::Result<TimeExpression> {
         preceded(
             (Caseless("in"), multispace1),
-            (Self::parse_number, multispace1, Self::parse_time_unit),
+            Self::parse_duration_components,
         )
-        .map(|(amount, _, unit)| {
-            TimeExpression::Relative(RelativeTime {
-                amount,
-                unit,
-                direction: Direction::Future,
-            })
-        })
+        .verify_map(|components| common::relative_expression(&components, Direction::Future))
         .parse_next(input)
     }
 
@@ -131,54 +193,67 @@ impl EnglishParser {
         common::parse_iso_datetime(input)
     }
 
-    fn parse_weekday(input: &mut &str) -> winnow::Result<Weekday> {
-        alt((
-            alt((
-                Caseless("monday").value(Weekday::Monday),
-                Caseless("mon").value(Weekday::Monday),
-            )),
+    pub(crate) fn parse_weekday(input: &mut &str) -> winnow::Result<Weekday> {
+        let weekday = component(
+            Component::Weekday,
             alt((
-                Caseless("tuesday").value(Weekday::Tuesday),
-                Caseless("tue").value(Weekday::Tuesday),
+                alt((
+                    Caseless("monday").value(Weekday::Monday),
+                    Caseless("mon").value(Weekday::Monday),
+                )),
+                alt((
+                    Caseless("tuesday").value(Weekday::Tuesday),
+                    Caseless("tue").value(Weekday::Tuesday),
+                )),
+                alt((
+                    Caseless("wednesday").value(Weekday::Wednesday),
+                    Caseless("wed").value(Weekday::Wednesday),
+                )),
+                alt((
+                    Caseless("thursday").value(Weekday::Thursday),
+                    Caseless("thu").value(Weekday::Thursday),
+                )),
+                alt((
+                    Caseless("friday").value(Weekday::Friday),
+                    Caseless("fri").value(Weekday::Friday),
+                )),
+                alt((
+                    Caseless("saturday").value(Weekday::Saturday),
+                    Caseless("sat").value(Weekday::Saturday),
+                )),
+                alt((
+                    Caseless("sunday").value(Weekday::Sunday),
+                    Caseless("sun").value(Weekday::Sunday),
+                )),
             )),
-            alt((
-                Caseless("wednesday").value(Weekday::Wednesday),
-                Caseless("wed").value(Weekday::Wednesday),
-            )),
-            alt((
-                Caseless("thursday").value(Weekday::Thursday),
-                Caseless("thu").value(Weekday::Thursday),
-            )),
-            alt((
-                Caseless("friday").value(Weekday::Friday),
-                Caseless("fri").value(Weekday::Friday),
-            )),
-            alt((
-                Caseless("saturday").value(Weekday::Saturday),
-                Caseless("sat").value(Weekday::Saturday),
-            )),
-            alt((
-                Caseless("sunday").value(Weekday::Sunday),
-                Caseless("sun").value(Weekday::Sunday),
-            )),
-        ))
-        .parse_next(input)
+        )
+        .parse_next(input)?;
+
+        // Abbreviations must not be the start of another word ("Monat", "month")
+        common::word_boundary(input)?;
+        Ok(weekday)
     }
 
     fn parse_day_shortcuts(input: &mut &str) -> winnow::Result<DayReference> {
-        alt((
-            Caseless("today").value(DayReference::Today),
-            Caseless("yesterday").value(DayReference::Yesterday),
-            Caseless("tomorrow").value(DayReference::Tomorrow),
-        ))
+        component(
+            Component::Day,
+            alt((
+                Caseless("today").value(DayReference::Today),
+                Caseless("yesterday").value(DayReference::Yesterday),
+                Caseless("tomorrow").value(DayReference::Tomorrow),
+            )),
+        )
         .parse_next(input)
     }
 
     fn parse_weekday_modifier(input: &mut &str) -> winnow::Result<WeekdayModifier> {
-        alt((
-            Caseless("last").value(WeekdayModifier::Last),
-            Caseless("next").value(WeekdayModifier::Next),
-        ))
+        component(
+            Component::Modifier,
+            alt((
+                Caseless("last").value(WeekdayModifier::Last),
+                Caseless("next").value(WeekdayModifier::Next),
+            )),
+        )
         .parse_next(input)
     }
 
@@ -204,17 +279,73 @@ impl EnglishParser {
             .parse_next(input)
     }
 
-    fn parse_day_reference(input: &mut &str) -> winnow::Result<TimeExpression> {
+    fn parse_week_offset(input: &mut &str) -> winnow::Result<i64> {
+        terminated(
+            Self::parse_period_offset,
+            (multispace1, component(Component::Unit, Caseless("week"))),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_weekday_in_week(input: &mut &str) -> winnow::Result<DayReference> {
+        alt((
+            // "next week Monday"
+            (Self::parse_week_offset, multispace1, Self::parse_weekday)
+                .map(|(week_offset, _, day)| (day, week_offset)),
+            // "Monday next week"
+            (Self::parse_weekday, multispace1, Self::parse_week_offset)
+                .map(|(day, _, week_offset)| (day, week_offset)),
+        ))
+        .map(|(day, week_offset)| DayReference::WeekdayInWeek { day, week_offset })
+        .parse_next(input)
+    }
+
+    fn parse_relative_day(input: &mut &str) -> winnow::Result<DayReference> {
+        alt((
+            preceded(
+                (Caseless("in"), multispace1),
+                (Self::parse_number, multispace1, Self::parse_time_unit),
+            )
+            .map(|(amount, _, unit)| (amount, unit, Direction::Future)),
+            (
+                Self::parse_number,
+                multispace1,
+                Self::parse_time_unit,
+                multispace1,
+                Caseless("ago"),
+            )
+                .map(|(amount, _, unit, _, _)| (amount, unit, Direction::Past)),
+        ))
+        .verify_map(|(amount, unit, direction)| {
+            // Only whole-day units can name the day a time of day falls on
+            matches!(
+                unit,
+                TimeUnit::Day | TimeUnit::Week | TimeUnit::Month | TimeUnit::Year
+            )
+            .then_some(DayReference::Relative(RelativeTime {
+                amount,
+                unit,
+                direction,
+            }))
+        })
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_day(input: &mut &str) -> winnow::Result<DayReference> {
         alt((
             Self::parse_day_shortcuts,
+            Self::parse_weekday_in_week,
             Self::parse_modified_weekday,
             Self::parse_simple_weekday,
         ))
-        .map(TimeExpression::Day)
         .parse_next(input)
     }
 
-    fn parse_meridiem(input: &mut &str) -> winnow::Result<Meridiem> {
+    fn parse_day_reference(input: &mut &str) -> winnow::Result<TimeExpression> {
+        Self::parse_day.map(TimeExpression::Day).parse_next(input)
+    }
+
+    pub(crate) fn parse_meridiem(input: &mut &str) -> winnow::Result<Meridiem> {
         alt((
             alt((
                 Caseless("am").value(Meridiem::AM),
@@ -233,38 +364,45 @@ impl EnglishParser {
     }
 
     fn parse_time_with_minutes(input: &mut &str) -> winnow::Result<(u8, u8, u8, Option<Meridiem>)> {
-        (
-            common::parse_two_digit_number,
-            ':',
-            common::parse_two_digit_number,
-            opt(preceded(':', common::parse_two_digit_number)).map(|second| second.unwrap_or(0)),
-            opt(preceded(multispace0, Self::parse_meridiem)),
+        component(
+            Component::Time,
+            (
+                common::parse_two_digit_number,
+                ':',
+                common::parse_two_digit_number,
+                opt(preceded(':', common::parse_two_digit_number))
+                    .map(|second| second.unwrap_or(0)),
+                opt(preceded(multispace0, Self::parse_meridiem)),
+            )
+                .verify_map(|(hour, _, minute, second, meridiem)| {
+                    time_utils::is_valid_time(hour, minute, second, meridiem)
+                        .then_some((hour, minute, second, meridiem))
+                }),
         )
-            .verify_map(|(hour, _, minute, second, meridiem)| {
-                time_utils::is_valid_time(hour, minute, second, meridiem)
-                    .then_some((hour, minute, second, meridiem))
-            })
-            .parse_next(input)
+        .parse_next(input)
     }
 
     fn parse_hour_meridiem(input: &mut &str) -> winnow::Result<(u8, u8, u8, Option<Meridiem>)> {
-        (
-            common::parse_two_digit_number,
-            multispace0,
-            Self::parse_meridiem,
+        component(
+            Component::Time,
+            (
+                common::parse_two_digit_number,
+                multispace0,
+                Self::parse_meridiem,
+            )
+                .verify_map(|(hour, _, meridiem)| {
+                    time_utils::is_valid_time(hour, 0, 0, Some(meridiem)).then_some((
+                        hour,
+                        0,
+                        0,
+                        Some(meridiem),
+                    ))
+                }),
         )
-            .verify_map(|(hour, _, meridiem)| {
-                time_utils::is_valid_time(hour, 0, 0, Some(meridiem)).then_some((
-                    hour,
-                    0,
-                    0,
-                    Some(meridiem),
-                ))
-            })
-            .parse_next(input)
+        .parse_next(input)
     }
 
-    fn parse_time(input: &mut &str) -> winnow::Result<TimeExpression> {
+    pub(crate) fn parse_time(input: &mut &str) -> winnow::Result<TimeExpression> {
         Self::parse_time_digits
             .map(|(hour, minute, second, meridiem)| {
                 TimeExpression::Time(Time {
@@ -279,11 +417,7 @@ impl EnglishParser {
 
     fn parse_day_at_time(input: &mut &str) -> winnow::Result<TimeExpression> {
         (
-            alt((
-                Self::parse_day_shortcuts,
-                Self::parse_modified_weekday,
-                Self::parse_simple_weekday,
-            )),
+            alt((Self::parse_day, Self::parse_relative_day)),
             preceded(
                 multispace1,
                 preceded(
@@ -306,55 +440,869 @@ impl EnglishParser {
             .parse_next(input)
     }
 
-    fn parse_date_format(input: &mut &str) -> winnow::Result<TimeExpression> {
+    pub(crate) fn parse_month_name(input: &mut &str) -> winnow::Result<u8> {
+        terminated(
+            alt((
+                alt((
+                    Caseless("january").value(1),
+                    Caseless("jan").value(1),
+                    Caseless("february").value(2),
+                    Caseless("feb").value(2),
+                    Caseless("march").value(3),
+                    Caseless("mar").value(3),
+                )),
+                alt((
+                    Caseless("april").value(4),
+                    Caseless("apr").value(4),
+                    Caseless("may").value(5),
+                    Caseless("june").value(6),
+                    Caseless("jun").value(6),
+                    Caseless("july").value(7),
+                    Caseless("jul").value(7),
+                )),
+                alt((
+                    Caseless("august").value(8),
+                    Caseless("aug").value(8),
+                    Caseless("september").value(9),
+                    Caseless("sept").value(9),
+                    Caseless("sep").value(9),
+                )),
+                alt((
+                    Caseless("october").value(10),
+                    Caseless("oct").value(10),
+                    Caseless("november").value(11),
+                    Caseless("nov").value(11),
+                    Caseless("december").value(12),
+                    Caseless("dec").value(12),
+                )),
+            )),
+            // Abbreviations may end with a period ("Mar.")
+            opt('.'),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_ordinal_day(input: &mut &str) -> winnow::Result<u8> {
+        (
+            common::parse_two_digit_number,
+            opt(alt((
+                Caseless("st"),
+                Caseless("nd"),
+                Caseless("rd"),
+                Caseless("th"),
+            ))),
+        )
+            .verify_map(|(day, suffix)| match suffix {
+                None => Some(day),
+                Some(suffix) => suffix
+                    .eq_ignore_ascii_case(time_utils::english_ordinal_suffix(day))
+                    .then_some(day),
+            })
+            .parse_next(input)
+    }
+
+    fn parse_month_name_date(input: &mut &str) -> winnow::Result<TimeExpression> {
+        component(
+            Component::Date,
+            alt((
+                // "March 5th, 2024" or "March 5th"
+                (
+                    Self::parse_month_name,
+                    multispace1,
+                    Self::parse_ordinal_day,
+                    Self::parse_optional_year,
+                )
+                    .map(|(month, _, day, year)| (day, month, year)),
+                // "5th of March 2024" or "5th of March"
+                (
+                    Self::parse_ordinal_day,
+                    multispace1,
+                    opt((Caseless("of"), multispace1)),
+                    Self::parse_month_name,
+                    Self::parse_optional_year,
+                )
+                    .map(|(day, _, _, month, year)| (day, month, year)),
+            ))
+            .verify_map(|(day, month, year)| common::date_expression(day, month, year)),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_optional_year(input: &mut &str) -> winnow::Result<Option<u16>> {
+        opt(preceded(
+            (opt(','), multispace1),
+            common::parse_four_digit_number,
+        ))
+        .parse_next(input)
+    }
+
+    fn parse_numeric_date(input: &mut &str, order: DateOrder) -> winnow::Result<TimeExpression> {
+        component(Component::Date, move |input: &mut &str| match order {
+            DateOrder::Dmy | DateOrder::Mdy => {
+                let day_month = move |first, second| match order {
+                    DateOrder::Mdy => (second, first),
+                    _ => (first, second),
+                };
+                alt((
+                    // DD/MM/YYYY or DD-MM-YYYY (MM/DD/YYYY or MM-DD-YYYY in US order)
+                    (
+                        common::parse_two_digit_number,
+                        alt(('/', '-')),
+                        common::parse_two_digit_number,
+                        alt(('/', '-')),
+                        common::parse_four_digit_number,
+                    )
+                        .verify_map(
+                            |(first, first_separator, second, second_separator, year)| {
+                                let (day, month) = day_month(first, second);
+                                (first_separator == second_separator)
+                                    .then(|| common::date_expression(day, month, Some(year)))
+                                    .flatten()
+                            },
+                        ),
+                    // DD/MM or MM/DD (year picked by the provider)
+                    (
+                        common::parse_two_digit_number,
+                        '/',
+                        common::parse_two_digit_number,
+                    )
+                        .verify_map(|(first, _, second)| {
+                            let (day, month) = day_month(first, second);
+                            common::date_expression(day, month, None)
+                        }),
+                ))
+                .parse_next(input)
+            }
+            DateOrder::Ymd => alt((
+                // YYYY/MM/DD
+                (
+                    common::parse_four_digit_number,
+                    '/',
+                    common::parse_two_digit_number,
+                    '/',
+                    common::parse_two_digit_number,
+                )
+                    .verify_map(|(year, _, month, _, day)| {
+                        common::date_expression(day, month, Some(year))
+                    }),
+                // MM/DD (year picked by the provider)
+                (
+                    common::parse_two_digit_number,
+                    '/',
+                    common::parse_two_digit_number,
+                )
+                    .verify_map(|(month, _, day)| common::date_expression(day, month, None)),
+            ))
+            .parse_next(input),
+        })
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_date_format(
+        input: &mut &str,
+        order: DateOrder,
+    ) -> winnow::Result<TimeExpression> {
         alt((
             // YYYY-MM-DD
+            component(
+                Component::Date,
+                (
+                    common::parse_four_digit_number,
+                    '-',
+                    common::parse_two_digit_number,
+                    '-',
+                    common::parse_two_digit_number,
+                )
+                    .verify_map(|(year, _, month, _, day)| {
+                        time_utils::is_valid_calendar_date(year, month, day)
+                            .then_some(TimeExpression::Date(StandardDate { day, month, year }))
+                    }),
+            ),
+            |input: &mut &str| Self::parse_numeric_date(input, order),
+            Self::parse_month_name_date,
+        ))
+        .parse_next(input)
+    }
+
+    fn parse_period_offset(input: &mut &str) -> winnow::Result<i64> {
+        component(
+            Component::Modifier,
+            alt((
+                Caseless("last").value(-1),
+                Caseless("this").value(0),
+                Caseless("next").value(1),
+            )),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_period_unit(input: &mut &str) -> winnow::Result<PeriodUnit> {
+        component(
+            Component::Unit,
+            alt((
+                Caseless("week").value(PeriodUnit::Week),
+                Caseless("month").value(PeriodUnit::Month),
+                Caseless("quarter").value(PeriodUnit::Quarter),
+                Caseless("year").value(PeriodUnit::Year),
+            )),
+        )
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_period(input: &mut &str) -> winnow::Result<TimeExpression> {
+        // "last week", "this month", "next quarter"
+        separated_pair(
+            Self::parse_period_offset,
+            multispace1,
+            Self::parse_period_unit,
+        )
+        .map(|(offset, unit)| TimeExpression::Period(Period { unit, offset }))
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_boundary(input: &mut &str) -> winnow::Result<TimeExpression> {
+        alt((
+            // "end of month", "start of next week", "beginning of the quarter"
             (
-                common::parse_four_digit_number,
-                '-',
-                common::parse_two_digit_number,
-                '-',
-                common::parse_two_digit_number,
+                terminated(
+                    component(
+                        Component::Edge,
+                        alt((
+                            Caseless("start").value(Edge::Start),
+                            Caseless("beginning").value(Edge::Start),
+                            Caseless("end").value(Edge::End),
+                        )),
+                    ),
+                    (multispace1, Caseless("of"), multispace1),
+                ),
+                opt(terminated(Caseless("the"), multispace1)),
+                opt(terminated(Self::parse_period_offset, multispace1)),
+                alt((
+                    component(Component::Unit, Caseless("day").value(PeriodUnit::Day)),
+                    Self::parse_period_unit,
+                )),
             )
-                .verify_map(|(year, _, month, _, day)| {
-                    time_utils::is_valid_calendar_date(year, month, day)
-                        .then_some(TimeExpression::Date(StandardDate { day, month, year }))
+                .map(|(edge, _, offset, unit)| Boundary {
+                    edge,
+                    period: Period {
+                        unit,
+                        offset: offset.unwrap_or(0),
+                    },
                 }),
-            // DD/MM/YYYY or DD-MM-YYYY (International format)
+            // "EOD"
+            component(
+                Component::Edge,
+                terminated(Caseless("eod"), common::word_boundary),
+            )
+            .value(Boundary {
+                edge: Edge::End,
+                period: Period {
+                    unit: PeriodUnit::Day,
+                    offset: 0,
+                },
+            }),
+        ))
+        .map(TimeExpression::Boundary)
+        .parse_next(input)
+    }
+
+    fn parse_weekday_list(input: &mut &str) -> winnow::Result<WeekdaySet> {
+        // "Monday", "Monday and Thursday", "Mon, Wed and Fri"
+        separated(
+            1..,
+            Self::parse_weekday,
+            alt((
+                (
+                    multispace0,
+                    ',',
+                    multispace0,
+                    opt((Caseless("and"), multispace1)),
+                )
+                    .void(),
+                (multispace1, Caseless("and"), multispace1).void(),
+            )),
+        )
+        .map(|days: Vec<Weekday>| days.into_iter().collect())
+        .parse_next(input)
+    }
+
+    fn parse_frequency(input: &mut &str) -> winnow::Result<Frequency> {
+        component(
+            Component::Frequency,
+            terminated(
+                alt((
+                    alt((Caseless("minutes"), Caseless("minute"))).value(Frequency::Minutely),
+                    alt((Caseless("hours"), Caseless("hour"))).value(Frequency::Hourly),
+                    alt((Caseless("days"), Caseless("day"))).value(Frequency::Daily),
+                    alt((Caseless("weeks"), Caseless("week"))).value(Frequency::Weekly),
+                    alt((Caseless("months"), Caseless("month"))).value(Frequency::Monthly),
+                    alt((Caseless("years"), Caseless("year"))).value(Frequency::Yearly),
+                )),
+                common::word_boundary,
+            ),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_recurrence_interval(input: &mut &str) -> winnow::Result<u32> {
+        // "other", "second", "3"
+        terminated(
+            component(
+                Component::Amount,
+                alt((
+                    Caseless("other").value(2),
+                    Caseless("second").value(2),
+                    Caseless("third").value(3),
+                    Caseless("fourth").value(4),
+                    common::parse_digit_number
+                        .verify_map(|interval| u32::try_from(interval).ok().filter(|i| *i > 0)),
+                )),
+            ),
+            multispace1,
+        )
+        .parse_next(input)
+    }
+
+    fn parse_recurrence_rule(input: &mut &str) -> winnow::Result<(Frequency, u32, WeekdaySet)> {
+        alt((
+            // "day", "2 weeks on Monday", "other month"
             (
-                common::parse_two_digit_number,
-                alt(('/', '-')),
-                common::parse_two_digit_number,
-                alt(('/', '-')),
-                common::parse_four_digit_number,
+                opt(Self::parse_recurrence_interval),
+                Self::parse_frequency,
+                opt(preceded(
+                    (multispace1, Caseless("on"), multispace1),
+                    Self::parse_weekday_list,
+                )),
             )
-                .verify_map(|(day, first_separator, month, second_separator, year)| {
-                    (first_separator == second_separator
-                        && time_utils::is_valid_calendar_date(year, month, day))
-                    .then_some(TimeExpression::Date(StandardDate { day, month, year }))
+                .map(|(interval, frequency, weekdays)| {
+                    (
+                        frequency,
+                        interval.unwrap_or(1),
+                        weekdays.unwrap_or_default(),
+                    )
                 }),
+            // "weekday"
+            component(
+                Component::Frequency,
+                terminated(Caseless("weekday"), common::word_boundary),
+            )
+            .value((Frequency::Weekly, 1, WeekdaySet::WORKDAYS)),
+            // "Monday", "other Friday", "Mon and Thu"
+            (
+                opt(Self::parse_recurrence_interval),
+                Self::parse_weekday_list,
+            )
+                .map(|(interval, weekdays)| (Frequency::Weekly, interval.unwrap_or(1), weekdays)),
         ))
         .parse_next(input)
     }
+
+    fn parse_recurrence_end(input: &mut &str, order: DateOrder) -> winnow::Result<RecurrenceEnd> {
+        alt((
+            // "until 31/12/2024", "until December 31st 2024", "until March 5th"
+            preceded((Caseless("until"), multispace1), |input: &mut &str| {
+                Self::parse_date_format(input, order)
+            })
+            .verify_map(|expr| match expr {
+                TimeExpression::Date(date) => Some(RecurrenceEnd::Until(date)),
+                TimeExpression::MonthDay(month_day) => {
+                    Some(RecurrenceEnd::UntilMonthDay(month_day))
+                }
+                _ => None,
+            }),
+            // "10 times", "for 10 occurrences", "1 time"
+            delimited(
+                opt((Caseless("for"), multispace1)),
+                component(
+                    Component::Amount,
+                    common::parse_digit_number.verify_map(|count| u32::try_from(count).ok()),
+                ),
+                (
+                    multispace1,
+                    alt((
+                        Caseless("times"),
+                        Caseless("time"),
+                        Caseless("occurrences"),
+                        Caseless("occurrence"),
+                    )),
+                ),
+            )
+            .map(RecurrenceEnd::Count),
+        ))
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_recurrence(
+        input: &mut &str,
+        order: DateOrder,
+    ) -> winnow::Result<TimeExpression> {
+        (
+            alt((
+                // "every Monday", "each other week"
+                preceded(
+                    (alt((Caseless("every"), Caseless("each"))), multispace1),
+                    Self::parse_recurrence_rule,
+                ),
+                // "daily", "weekly"
+                component(
+                    Component::Frequency,
+                    terminated(
+                        alt((
+                            Caseless("hourly").value(Frequency::Hourly),
+                            Caseless("daily").value(Frequency::Daily),
+                            Caseless("weekly").value(Frequency::Weekly),
+                            Caseless("monthly").value(Frequency::Monthly),
+                            alt((Caseless("yearly"), Caseless("annually")))
+                                .value(Frequency::Yearly),
+                        )),
+                        common::word_boundary,
+                    ),
+                )
+                .map(|frequency| (frequency, 1, WeekdaySet::empty())),
+            )),
+            opt(preceded(
+                (multispace1, Caseless("at"), multispace1),
+                Self::parse_time_digits,
+            )),
+            opt(preceded((opt(','), multispace1), |input: &mut &str| {
+                Self::parse_recurrence_end(input, order)
+            })),
+        )
+            .map(|((frequency, interval, weekdays), time, end)| {
+                TimeExpression::Recurrence(Recurrence {
+                    frequency,
+                    interval,
+                    weekdays,
+                    time: time.map(|(hour, minute, second, meridiem)| Time {
+                        hour,
+                        minute,
+                        second,
+                        meridiem,
+                    }),
+                    end,
+                })
+            })
+            .parse_next(input)
+    }
+
+    pub(crate) fn parse_point(
+        input: &mut &str,
+        order: DateOrder,
+    ) -> winnow::Result<TimeExpression> {
+        alt((
+            Self::parse_iso_datetime,
+            |input: &mut &str| Self::parse_date_format(input, order),
+            Self::parse_day_at_time,
+            Self::parse_now,
+            Self::parse_day_reference,
+            alt((
+                Self::parse_period,
+                Self::parse_boundary,
+                |input: &mut &str| Self::parse_recurrence(input, order),
+            )),
+            Self::parse_time,
+            Self::parse_relative_past,
+            Self::parse_relative_future,
+        ))
+        .parse_next(input)
+    }
+
+    fn parse_time_range(input: &mut &str) -> winnow::Result<(TimeExpression, TimeExpression)> {
+        let dash = (multispace0, '-', multispace0);
+        alt((
+            // "3pm-5pm", "9:00 - 17:00"
+            separated_pair(Self::parse_time, dash, Self::parse_time),
+            // "3-5pm": the start shares the end's meridiem unless that would
+            // put it after the end ("11-1pm")
+            separated_pair(
+                component(Component::Time, common::parse_two_digit_number),
+                dash,
+                Self::parse_hour_meridiem,
+            )
+            .verify_map(|(start_hour, (end_hour, _, _, meridiem))| {
+                let meridiem = meridiem?;
+                if !time_utils::is_valid_time(start_hour, 0, 0, Some(meridiem)) {
+                    return None;
+                }
+                let start_meridiem = if meridiem == Meridiem::PM
+                    && time_utils::convert_12_to_24_hour(start_hour, Some(&meridiem))
+                        >= time_utils::convert_12_to_24_hour(end_hour, Some(&meridiem))
+                {
+                    Meridiem::AM
+                } else {
+                    meridiem
+                };
+                let time = |hour, meridiem| {
+                    TimeExpression::Time(Time {
+                        hour,
+                        minute: 0,
+                        second: 0,
+                        meridiem: Some(meridiem),
+                    })
+                };
+                Some((time(start_hour, start_meridiem), time(end_hour, meridiem)))
+            }),
+        ))
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_interval(
+        input: &mut &str,
+        order: DateOrder,
+    ) -> winnow::Result<TimeExpression> {
+        let point = move |input: &mut &str| Self::parse_point(input, order);
+        alt((
+            // "from 3pm to 5pm", "from Monday until Friday"
+            preceded(
+                (Caseless("from"), multispace1),
+                separated_pair(
+                    point,
+                    (
+                        multispace1,
+                        alt((Caseless("to"), Caseless("until"))),
+                        multispace1,
+                    ),
+                    point,
+                ),
+            ),
+            // "between Monday and Friday"
+            preceded(
+                (Caseless("between"), multispace1),
+                separated_pair(point, (multispace1, Caseless("and"), multispace1), point),
+            ),
+            Self::parse_time_range,
+        ))
+        .verify_map(|(start, end)| common::interval_expression(start, end))
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_expression(
+        input: &mut &str,
+        order: DateOrder,
+    ) -> winnow::Result<TimeExpression> {
+        alt((
+            |input: &mut &str| Self::parse_interval(input, order),
+            |input: &mut &str| Self::parse_point(input, order),
+        ))
+        .parse_next(input)
+    }
+
+    /// Rules after the numeric dates, in the same order of preference as
+    /// `parse_with_options`
+    const WORD_RULES: [Rule; 10] = [
+        Rule::MonthNameDate,
+        Rule::DayAtTime,
+        Rule::Now,
+        Rule::DayReference,
+        Rule::Period,
+        Rule::Boundary,
+        Rule::Recurrence,
+        Rule::Time,
+        Rule::RelativePast,
+        Rule::RelativeFuture,
+    ];
+
+    fn parse_rule(
+        input: &mut &str,
+        rule: Rule,
+        order: DateOrder,
+    ) -> winnow::Result<TimeExpression> {
+        match rule {
+            Rule::Now => Self::parse_now(input),
+            Rule::Iso8601 => Self::parse_iso_datetime(input),
+            Rule::NumericDate(order) => Self::parse_numeric_date(input, order),
+            Rule::MonthNameDate => Self::parse_month_name_date(input),
+            Rule::DayReference => Self::parse_day_reference(input),
+            Rule::Time => Self::parse_time(input),
+            Rule::DayAtTime => Self::parse_day_at_time(input),
+            Rule::RelativePast => Self::parse_relative_past(input),
+            Rule::RelativeFuture => Self::parse_relative_future(input),
+            Rule::Interval => Self::parse_interval(input, order),
+            Rule::Period => Self::parse_period(input),
+            Rule::Boundary => Self::parse_boundary(input),
+            Rule::Recurrence => Self::parse_recurrence(input, order),
+            Rule::Duration => alt((common::parse_iso_duration, Self::parse_standalone_duration))
+                .map(common::future_expression)
+                .parse_next(input),
+            // Only produced by reinterpreting weekdays
+            Rule::WeekdayThisWeek | Rule::WeekdayNextWeek => fail(input),
+        }
+    }
+
+    fn weekday_name(day: Weekday) -> &'static str {
+        match day {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+
+    fn format_offset(offset: i64) -> Result<&'static str> {
+        match offset {
+            -1 => Ok("last"),
+            0 => Ok("this"),
+            1 => Ok("next"),
+            _ => Err(common::unrepresentable()),
+        }
+    }
+
+    fn format_time(time: Time) -> String {
+        let second = match time.second {
+            0 => String::new(),
+            second => format!(":{second:02}"),
+        };
+        match time.meridiem {
+            Some(Meridiem::AM) => format!("{}:{:02}{second} am", time.hour, time.minute),
+            Some(Meridiem::PM) => format!("{}:{:02}{second} pm", time.hour, time.minute),
+            None => format!("{:02}:{:02}{second}", time.hour, time.minute),
+        }
+    }
+
+    /// "5 March 2024", or "March 5, 2024" in American English
+    fn format_date(day: u8, month: u8, year: Option<u16>, language: Language) -> Result<String> {
+        const MONTHS: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        let month = MONTHS
+            .get(usize::from(month).wrapping_sub(1))
+            .ok_or_else(common::unrepresentable)?;
+        Ok(match (language, year) {
+            (Language::EnglishUs, Some(year)) => format!("{month} {day}, {year:04}"),
+            (Language::EnglishUs, None) => format!("{month} {day}"),
+            (_, Some(year)) => format!("{day} {month} {year:04}"),
+            (_, None) => format!("{day} {month}"),
+        })
+    }
+
+    fn format_day(&self, day: DayReference) -> Result<String> {
+        Ok(match day {
+            DayReference::Today => "today".to_string(),
+            DayReference::Yesterday => "yesterday".to_string(),
+            DayReference::Tomorrow => "tomorrow".to_string(),
+            DayReference::Weekday { day, modifier } => match modifier {
+                None => Self::weekday_name(day).to_string(),
+                Some(WeekdayModifier::Last) => format!("last {}", Self::weekday_name(day)),
+                Some(WeekdayModifier::Next) => format!("next {}", Self::weekday_name(day)),
+            },
+            // "Monday next week"
+            DayReference::WeekdayInWeek { day, week_offset } => format!(
+                "{} {} week",
+                Self::weekday_name(day),
+                Self::format_offset(week_offset)?
+            ),
+            DayReference::Relative(relative) => {
+                crate::format_relative(&relative.into(), Language::English)?
+            }
+        })
+    }
+
+    fn format_period(period: Period) -> Result<String> {
+        let unit = match period.unit {
+            PeriodUnit::Week => "week",
+            PeriodUnit::Month => "month",
+            PeriodUnit::Quarter => "quarter",
+            PeriodUnit::Year => "year",
+            // "today" is a day reference
+            PeriodUnit::Day => return Err(common::unrepresentable()),
+        };
+        Ok(format!("{} {unit}", Self::format_offset(period.offset)?))
+    }
+
+    fn format_boundary(boundary: Boundary) -> Result<String> {
+        let edge = match boundary.edge {
+            Edge::Start => "start",
+            Edge::End => "end",
+        };
+        let unit = match boundary.period.unit {
+            PeriodUnit::Day => "day",
+            PeriodUnit::Week => "week",
+            PeriodUnit::Month => "month",
+            PeriodUnit::Quarter => "quarter",
+            PeriodUnit::Year => "year",
+        };
+        // "end of the month", "start of next week"
+        let offset = match boundary.period.offset {
+            0 => "the",
+            offset => Self::format_offset(offset)?,
+        };
+        Ok(format!("{edge} of {offset} {unit}"))
+    }
+
+    fn format_recurrence(recurrence: Recurrence, language: Language) -> Result<String> {
+        let unit = match recurrence.frequency {
+            Frequency::Minutely => "minute",
+            Frequency::Hourly => "hour",
+            Frequency::Daily => "day",
+            Frequency::Weekly => "week",
+            Frequency::Monthly => "month",
+            Frequency::Yearly => "year",
+        };
+        let weekdays = common::format_weekday_list(recurrence.weekdays, Self::weekday_name, "and");
+        let weekly = recurrence.frequency == Frequency::Weekly && recurrence.interval == 1;
+        let mut text = match recurrence.interval {
+            0 => return Err(common::unrepresentable()),
+            _ if weekly && recurrence.weekdays == WeekdaySet::WORKDAYS => {
+                "every weekday".to_string()
+            }
+            _ if weekly && !weekdays.is_empty() => format!("every {weekdays}"),
+            1 if weekdays.is_empty() => format!("every {unit}"),
+            1 => format!("every {unit} on {weekdays}"),
+            interval if weekdays.is_empty() => format!("every {interval} {unit}s"),
+            interval => format!("every {interval} {unit}s on {weekdays}"),
+        };
+
+        if let Some(time) = recurrence.time {
+            text += &format!(" at {}", Self::format_time(time));
+        }
+        match recurrence.end {
+            Some(RecurrenceEnd::Until(date)) => {
+                let date = Self::format_date(date.day, date.month, Some(date.year), language)?;
+                text += &format!(" until {date}");
+            }
+            Some(RecurrenceEnd::UntilMonthDay(month_day)) => {
+                let date = Self::format_date(month_day.day, month_day.month, None, language)?;
+                text += &format!(" until {date}");
+            }
+            Some(RecurrenceEnd::Count(1)) => text += " 1 time",
+            Some(RecurrenceEnd::Count(count)) => text += &format!(" {count} times"),
+            None => {}
+        }
+        Ok(text)
+    }
 }
 
 impl LanguageParser for EnglishParser {
     fn parse(&self, input: &str) -> Result<TimeExpression> {
+        self.parse_with_options(input, &ParseOptions::new(Language::English))
+    }
+
+    fn parse_with_options(&self, input: &str, options: &ParseOptions) -> Result<TimeExpression> {
+        let order = options.date_order();
         delimited(
             multispace0,
-            alt((
-                Self::parse_iso_datetime,
-                Self::parse_date_format,
-                Self::parse_day_at_time,
-                Self::parse_now,
-                Self::parse_day_reference,
-                Self::parse_time,
-                Self::parse_relative_past,
-                Self::parse_relative_future,
-            )),
+            |input: &mut &str| Self::parse_expression(input, order),
             multispace0,
         )
         .parse(input)
         .map_err(|e| e.to_temps_error(input))
     }
+
+    fn parse_candidates(&self, input: &str, options: &ParseOptions) -> Result<Vec<Candidate>> {
+        self.parse_with_options(input, options)?;
+
+        // Same preference as `parse_with_options`, with the other date orders
+        // right after the configured one
+        let order = options.date_order();
+        let other_orders = [DateOrder::Dmy, DateOrder::Mdy, DateOrder::Ymd]
+            .into_iter()
+            .filter(move |other| *other != order);
+        let rules = [Rule::Interval, Rule::Iso8601, Rule::NumericDate(order)]
+            .into_iter()
+            .chain(other_orders.map(Rule::NumericDate))
+            .chain(Self::WORD_RULES);
+
+        Ok(common::collect_candidates(input, rules, |input, rule| {
+            Self::parse_rule(input, rule, order)
+        }))
+    }
+
+    fn parse_prefix<'i>(
+        &self,
+        input: &'i str,
+        options: &ParseOptions,
+    ) -> Result<(TimeExpression, &'i str)> {
+        let order = options.date_order();
+        let rules = [Rule::Interval, Rule::Iso8601, Rule::NumericDate(order)]
+            .into_iter()
+            .chain(Self::WORD_RULES)
+            .chain([Rule::Duration]);
+
+        common::parse_longest_prefix(input, rules, |input, rule| {
+            Self::parse_rule(input, rule, order)
+        })
+    }
+
+    fn parse_duration(&self, input: &str) -> Result<Duration> {
+        let duration = alt((common::parse_iso_duration, Self::parse_standalone_duration));
+        delimited(multispace0, duration, multispace0)
+            .parse(input)
+            .map_err(|e| e.to_temps_error(input))
+    }
+
+    fn format(&self, expr: &TimeExpression, language: Language) -> Result<String> {
+        if language == Language::German {
+            return GermanParser.format(expr, language);
+        }
+        match *expr {
+            TimeExpression::Now => Ok("now".to_string()),
+            TimeExpression::Relative(relative) => {
+                crate::format_relative(&relative.into(), Language::English)
+            }
+            TimeExpression::RelativeDuration(relative) => {
+                crate::format_relative(&relative, Language::English)
+            }
+            TimeExpression::Absolute(time) => Ok(common::format_iso_datetime(&time)),
+            TimeExpression::Day(day) => self.format_day(day),
+            TimeExpression::Time(time) => Ok(Self::format_time(time)),
+            TimeExpression::Date(date) => {
+                Self::format_date(date.day, date.month, Some(date.year), language)
+            }
+            TimeExpression::MonthDay(date) => {
+                Self::format_date(date.day, date.month, None, language)
+            }
+            TimeExpression::DayTime(DayTime { day, time }) => Ok(format!(
+                "{} at {}",
+                self.format_day(day)?,
+                Self::format_time(time)
+            )),
+            TimeExpression::Interval(Interval { start, end }) => Ok(format!(
+                "from {} to {}",
+                self.format(&start.into(), language)?,
+                self.format(&end.into(), language)?
+            )),
+            TimeExpression::Period(period) => Self::format_period(period),
+            TimeExpression::Boundary(boundary) => Self::format_boundary(boundary),
+            TimeExpression::Recurrence(recurrence) => Self::format_recurrence(recurrence, language),
+        }
+    }
+
+    fn format_relative(&self, relative: &RelativeDuration) -> Result<String> {
+        let amounts =
+            common::format_components(&relative.duration, |unit, amount| match (unit, amount) {
+                (TimeUnit::Second, 1) => "second",
+                (TimeUnit::Second, _) => "seconds",
+                (TimeUnit::Minute, 1) => "minute",
+                (TimeUnit::Minute, _) => "minutes",
+                (TimeUnit::Hour, 1) => "hour",
+                (TimeUnit::Hour, _) => "hours",
+                (TimeUnit::Day, 1) => "day",
+                (TimeUnit::Day, _) => "days",
+                (TimeUnit::Week, 1) => "week",
+                (TimeUnit::Week, _) => "weeks",
+                (TimeUnit::Month, 1) => "month",
+                (TimeUnit::Month, _) => "months",
+                (TimeUnit::Year, 1) => "year",
+                (TimeUnit::Year, _) => "years",
+            });
+        Ok(match (amounts, relative.direction) {
+            (None, _) => "now".to_string(),
+            (Some(amounts), Direction::Future) => format!("in {amounts}"),
+            (Some(amounts), Direction::Past) => format!("{amounts} ago"),
+        })
+    }
 }
@@ -1,12 +1,18 @@
 use winnow::{
     Parser,
-    ascii::{Caseless, multispace0, multispace1},
-    combinator::{alt, delimited, opt, preceded},
+    ascii::{Caseless, digit1, multispace0, multispace1},
+    combinator::{delimited, fail, preceded, separated_pair, terminated},
 };
 
 use crate::{
-    DayReference, DayTime, Direction, LanguageParser, RelativeTime, Result, StandardDate, Time,
-    TimeExpression, TimeUnit, Weekday, WeekdayModifier, common, error::ParseErrorExt, time_utils,
+    Boundary, Candidate, Component, DateOrder, DayReference, DayTime, Direction, Duration, Edge,
+    Frequency, Interval, Language, LanguageParser, ParseOptions, Period, PeriodUnit, Recurrence,
+    RecurrenceEnd, RelativeDuration, RelativeTime, Result, Rule, Time, TimeExpression, TimeUnit,
+    Weekday, WeekdayModifier, WeekdaySet, common,
+    error::ParseErrorExt,
+    language::english::EnglishParser,
+    spanned::{alt, component, opt, repeat, separated},
+    time_utils,
 };
 
 /// Parser for German natural language time expressions.
@@ -17,106 +23,157 @@ use crate::{
 pub struct GermanParser;
 
 impl GermanParser {
-    fn parse_number(input: &mut &str) -> winnow::Result<i64> {
-        alt((
-            common::parse_digit_number,
-            alt((
-                "einem".value(1),
-                "einer".value(1),
-                "einen".value(1),
-                "eine".value(1),
-                "ein".value(1),
-            )),
+    pub(crate) fn parse_number(input: &mut &str) -> winnow::Result<i64> {
+        component(
+            Component::Amount,
             alt((
-                "zwei".value(2),
-                "drei".value(3),
-                "vier".value(4),
-                "fünf".value(5),
-                "sechs".value(6),
+                common::parse_digit_number,
+                alt((
+                    "einem".value(1),
+                    "einer".value(1),
+                    "einen".value(1),
+                    "eine".value(1),
+                    "ein".value(1),
+                )),
+                alt((
+                    "zwei".value(2),
+                    "drei".value(3),
+                    "vier".value(4),
+                    "fünf".value(5),
+                    "sechs".value(6),
+                )),
+                alt((
+                    "sieben".value(7),
+                    "acht".value(8),
+                    "neun".value(9),
+                    "zehn".value(10),
+                )),
             )),
+        )
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_time_unit(input: &mut &str) -> winnow::Result<TimeUnit> {
+        component(
+            Component::Unit,
             alt((
-                "sieben".value(7),
-                "acht".value(8),
-                "neun".value(9),
-                "zehn".value(10),
+                alt((
+                    "Sekunden".value(TimeUnit::Second),
+                    "Sekunde".value(TimeUnit::Second),
+                    Caseless("sek").value(TimeUnit::Second), // Abbreviations can be case-insensitive
+                )),
+                alt((
+                    "Minuten".value(TimeUnit::Minute),
+                    "Minute".value(TimeUnit::Minute),
+                    Caseless("min").value(TimeUnit::Minute), // Abbreviations can be case-insensitive
+                )),
+                alt((
+                    "Stunden".value(TimeUnit::Hour),
+                    "Stunde".value(TimeUnit::Hour),
+                    Caseless("std").value(TimeUnit::Hour), // Abbreviations can be case-insensitive
+                )),
+                alt((
+                    "Tagen".value(TimeUnit::Day),
+                    "Tage".value(TimeUnit::Day),
+                    "Tag".value(TimeUnit::Day),
+                )),
+                alt((
+                    "Wochen".value(TimeUnit::Week),
+                    "Woche".value(TimeUnit::Week),
+                )),
+                alt((
+                    "Monaten".value(TimeUnit::Month),
+                    "Monate".value(TimeUnit::Month),
+                    "Monat".value(TimeUnit::Month),
+                )),
+                alt((
+                    "Jahren".value(TimeUnit::Year),
+                    "Jahre".value(TimeUnit::Year),
+                    "Jahr".value(TimeUnit::Year),
+                )),
             )),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_duration_component(input: &mut &str) -> winnow::Result<(i64, TimeUnit)> {
+        (Self::parse_number, multispace1, Self::parse_time_unit)
+            .map(|(amount, _, unit)| (amount, unit))
+            .parse_next(input)
+    }
+
+    fn parse_duration_separator(input: &mut &str) -> winnow::Result<()> {
+        alt((
+            (opt(','), multispace1, Caseless("und"), multispace1).void(),
+            (',', multispace0).void(),
+            multispace1.void(),
         ))
         .parse_next(input)
     }
 
-    fn parse_time_unit(input: &mut &str) -> winnow::Result<TimeUnit> {
+    fn parse_duration_components(input: &mut &str) -> winnow::Result<Vec<(i64, TimeUnit)>> {
+        separated(
+            1..,
+            Self::parse_duration_component,
+            Self::parse_duration_separator,
+        )
+        .parse_next(input)
+    }
+
+    fn parse_duration_amount<'i>(input: &mut &'i str) -> winnow::Result<(i64, Option<&'i str>)> {
         alt((
-            alt((
-                "Sekunden".value(TimeUnit::Second),
-                "Sekunde".value(TimeUnit::Second),
-                Caseless("sek").value(TimeUnit::Second), // Abbreviations can be case-insensitive
-            )),
-            alt((
-                "Minuten".value(TimeUnit::Minute),
-                "Minute".value(TimeUnit::Minute),
-                Caseless("min").value(TimeUnit::Minute), // Abbreviations can be case-insensitive
-            )),
-            alt((
-                "Stunden".value(TimeUnit::Hour),
-                "Stunde".value(TimeUnit::Hour),
-                Caseless("std").value(TimeUnit::Hour), // Abbreviations can be case-insensitive
-            )),
-            alt((
-                "Tagen".value(TimeUnit::Day),
-                "Tage".value(TimeUnit::Day),
-                "Tag".value(TimeUnit::Day),
-            )),
-            alt((
-                "Wochen".value(TimeUnit::Week),
-                "Woche".value(TimeUnit::Week),
-            )),
-            alt((
-                "Monaten".value(TimeUnit::Month),
-                "Monate".value(TimeUnit::Month),
-                "Monat".value(TimeUnit::Month),
-            )),
-            alt((
-                "Jahren".value(TimeUnit::Year),
-                "Jahre".value(TimeUnit::Year),
-                "Jahr".value(TimeUnit::Year),
-            )),
+            (common::parse_digit_number, opt(preceded(',', digit1))),
+            Self::parse_number.map(|amount| (amount, None)),
         ))
         .parse_next(input)
     }
 
-    fn parse_relative_past(input: &mut &str) -> winnow::Result<TimeExpression> {
-        preceded(
-            Caseless("vor"),
-            preceded(
-                multispace1,
-                (Self::parse_number, multispace1, Self::parse_time_unit),
+    fn parse_standalone_duration_component<'i>(
+        input: &mut &'i str,
+    ) -> winnow::Result<(i64, Option<&'i str>, TimeUnit)> {
+        (
+            Self::parse_duration_amount,
+            multispace0,
+            Self::parse_time_unit,
+        )
+            .map(|((amount, fraction), _, unit)| (amount, fraction, unit))
+            .parse_next(input)
+    }
+
+    pub(crate) fn parse_standalone_duration(input: &mut &str) -> winnow::Result<Duration> {
+        // Units may be attached to the number and separators omitted ("2h30m")
+        (
+            Self::parse_standalone_duration_component,
+            repeat(
+                0..,
+                preceded(
+                    opt(Self::parse_duration_separator),
+                    Self::parse_standalone_duration_component,
+                ),
             ),
         )
-        .map(|(amount, _, unit)| {
-            TimeExpression::Relative(RelativeTime {
-                amount,
-                unit,
-                direction: Direction::Past,
+            .verify_map(|(first, rest): (_, Vec<_>)| {
+                let components: Vec<_> = std::iter::once(first).chain(rest).collect();
+                common::duration_from_components(&components)
             })
-        })
+            .parse_next(input)
+    }
+
+    pub(crate) fn parse_relative_past(input: &mut &str) -> winnow::Result<TimeExpression> {
+        preceded(
+            Caseless("vor"),
+            preceded(multispace1, Self::parse_duration_components),
+        )
+        .verify_map(|components| common::relative_expression(&components, Direction::Past))
         .parse_next(input)
     }
 
-    fn parse_relative_future(input: &mut &str) -> winnow::Result<TimeExpression> {
+    pub(crate) fn parse_relative_future(input: &mut &str) -> winnow::Result<TimeExpression> {
         preceded(
             Caseless("in"),
-            preceded(
-                multispace1,
-                (Self::parse_number, multispace1, Self::parse_time_unit),
-            ),
+            preceded(multispace1, Self::parse_duration_components),
         )
-        .map(|(amount, _, unit)| {
-            TimeExpression::Relative(RelativeTime {
-                amount,
-                unit,
-                direction: Direction::Future,
-            })
-        })
+        .verify_map(|components| common::relative_expression(&components, Direction::Future))
         .parse_next(input)
     }
 
@@ -130,60 +187,73 @@ impl GermanParser {
         common::parse_iso_datetime(input)
     }
 
-    fn parse_weekday(input: &mut &str) -> winnow::Result<Weekday> {
-        alt((
-            alt((
-                "Montag".value(Weekday::Monday),
-                Caseless("mo").value(Weekday::Monday), // Abbreviations can be case-insensitive
-            )),
-            alt((
-                "Dienstag".value(Weekday::Tuesday),
-                Caseless("di").value(Weekday::Tuesday), // Abbreviations can be case-insensitive
-            )),
-            alt((
-                "Mittwoch".value(Weekday::Wednesday),
-                Caseless("mi").value(Weekday::Wednesday), // Abbreviations can be case-insensitive
-            )),
-            alt((
-                "Donnerstag".value(Weekday::Thursday),
-                Caseless("do").value(Weekday::Thursday), // Abbreviations can be case-insensitive
-            )),
-            alt((
-                "Freitag".value(Weekday::Friday),
-                Caseless("fr").value(Weekday::Friday), // Abbreviations can be case-insensitive
-            )),
-            alt((
-                "Samstag".value(Weekday::Saturday),
-                Caseless("sa").value(Weekday::Saturday), // Abbreviations can be case-insensitive
-            )),
+    pub(crate) fn parse_weekday(input: &mut &str) -> winnow::Result<Weekday> {
+        let weekday = component(
+            Component::Weekday,
             alt((
-                "Sonntag".value(Weekday::Sunday),
-                Caseless("so").value(Weekday::Sunday), // Abbreviations can be case-insensitive
+                alt((
+                    "Montag".value(Weekday::Monday),
+                    Caseless("mo").value(Weekday::Monday), // Abbreviations can be case-insensitive
+                )),
+                alt((
+                    "Dienstag".value(Weekday::Tuesday),
+                    Caseless("di").value(Weekday::Tuesday), // Abbreviations can be case-insensitive
+                )),
+                alt((
+                    "Mittwoch".value(Weekday::Wednesday),
+                    Caseless("mi").value(Weekday::Wednesday), // Abbreviations can be case-insensitive
+                )),
+                alt((
+                    "Donnerstag".value(Weekday::Thursday),
+                    Caseless("do").value(Weekday::Thursday), // Abbreviations can be case-insensitive
+                )),
+                alt((
+                    "Freitag".value(Weekday::Friday),
+                    Caseless("fr").value(Weekday::Friday), // Abbreviations can be case-insensitive
+                )),
+                alt((
+                    "Samstag".value(Weekday::Saturday),
+                    Caseless("sa").value(Weekday::Saturday), // Abbreviations can be case-insensitive
+                )),
+                alt((
+                    "Sonntag".value(Weekday::Sunday),
+                    Caseless("so").value(Weekday::Sunday), // Abbreviations can be case-insensitive
+                )),
             )),
-        ))
-        .parse_next(input)
+        )
+        .parse_next(input)?;
+
+        // Abbreviations must not be the start of another word ("Monat", "month")
+        common::word_boundary(input)?;
+        Ok(weekday)
     }
 
     fn parse_day_shortcuts(input: &mut &str) -> winnow::Result<DayReference> {
-        alt((
-            Caseless("heute").value(DayReference::Today),
-            Caseless("gestern").value(DayReference::Yesterday),
-            Caseless("morgen").value(DayReference::Tomorrow),
-        ))
+        component(
+            Component::Day,
+            alt((
+                Caseless("heute").value(DayReference::Today),
+                Caseless("gestern").value(DayReference::Yesterday),
+                Caseless("morgen").value(DayReference::Tomorrow),
+            )),
+        )
         .parse_next(input)
     }
 
     fn parse_weekday_modifier(input: &mut &str) -> winnow::Result<WeekdayModifier> {
-        alt((
-            alt((
-                "letzten".value(WeekdayModifier::Last),
-                "letzte".value(WeekdayModifier::Last),
-            )),
+        component(
+            Component::Modifier,
             alt((
-                "nächsten".value(WeekdayModifier::Next),
-                "nächste".value(WeekdayModifier::Next),
+                alt((
+                    "letzten".value(WeekdayModifier::Last),
+                    "letzte".value(WeekdayModifier::Last),
+                )),
+                alt((
+                    "nächsten".value(WeekdayModifier::Next),
+                    "nächste".value(WeekdayModifier::Next),
+                )),
             )),
-        ))
+        )
         .parse_next(input)
     }
 
@@ -209,107 +279,850 @@ impl GermanParser {
             .parse_next(input)
     }
 
-    fn parse_day_reference(input: &mut &str) -> winnow::Result<TimeExpression> {
+    fn parse_week_offset(input: &mut &str) -> winnow::Result<i64> {
+        (
+            component(
+                Component::Modifier,
+                alt((
+                    alt(("letzter", "letzte")).value(-1),
+                    alt(("dieser", "diese")).value(0),
+                    alt(("nächster", "nächste")).value(1),
+                )),
+            ),
+            multispace1,
+            component(Component::Unit, "Woche"),
+        )
+            .map(|(week_offset, _, _)| week_offset)
+            .parse_next(input)
+    }
+
+    fn parse_weekday_in_week(input: &mut &str) -> winnow::Result<DayReference> {
+        alt((
+            // "nächste Woche Montag"
+            (Self::parse_week_offset, multispace1, Self::parse_weekday)
+                .map(|(week_offset, _, day)| (day, week_offset)),
+            // "Montag nächster Woche"
+            (Self::parse_weekday, multispace1, Self::parse_week_offset)
+                .map(|(day, _, week_offset)| (day, week_offset)),
+        ))
+        .map(|(day, week_offset)| DayReference::WeekdayInWeek { day, week_offset })
+        .parse_next(input)
+    }
+
+    fn parse_relative_day(input: &mut &str) -> winnow::Result<DayReference> {
+        (
+            alt((
+                Caseless("vor").value(Direction::Past),
+                Caseless("in").value(Direction::Future),
+            )),
+            multispace1,
+            Self::parse_number,
+            multispace1,
+            Self::parse_time_unit,
+        )
+            .verify_map(|(direction, _, amount, _, unit)| {
+                // Only whole-day units can name the day a time of day falls on
+                matches!(
+                    unit,
+                    TimeUnit::Day | TimeUnit::Week | TimeUnit::Month | TimeUnit::Year
+                )
+                .then_some(DayReference::Relative(RelativeTime {
+                    amount,
+                    unit,
+                    direction,
+                }))
+            })
+            .parse_next(input)
+    }
+
+    pub(crate) fn parse_day(input: &mut &str) -> winnow::Result<DayReference> {
         alt((
             Self::parse_day_shortcuts,
+            Self::parse_weekday_in_week,
             Self::parse_modified_weekday,
             Self::parse_simple_weekday,
         ))
-        .map(TimeExpression::Day)
         .parse_next(input)
     }
 
+    fn parse_day_reference(input: &mut &str) -> winnow::Result<TimeExpression> {
+        Self::parse_day.map(TimeExpression::Day).parse_next(input)
+    }
+
     fn parse_time_digits(input: &mut &str) -> winnow::Result<(u8, u8, u8)> {
-        (
-            common::parse_two_digit_number,
-            ':',
-            common::parse_two_digit_number,
-            opt(preceded(':', common::parse_two_digit_number)).map(|second| second.unwrap_or(0)),
+        component(
+            Component::Time,
+            (
+                common::parse_two_digit_number,
+                ':',
+                common::parse_two_digit_number,
+                opt(preceded(':', common::parse_two_digit_number))
+                    .map(|second| second.unwrap_or(0)),
+            )
+                .verify_map(|(hour, _, minute, second)| {
+                    time_utils::is_valid_24_hour_time(hour, minute, second)
+                        .then_some((hour, minute, second))
+                }),
         )
-            .verify_map(|(hour, _, minute, second)| {
-                time_utils::is_valid_24_hour_time(hour, minute, second)
-                    .then_some((hour, minute, second))
-            })
+        .parse_next(input)
+    }
+
+    /// "15:30", "9:45 Uhr", "15 Uhr"
+    pub(crate) fn parse_time_of_day(input: &mut &str) -> winnow::Result<Time> {
+        alt((
+            terminated(
+                Self::parse_time_digits,
+                opt(preceded(multispace1, Caseless("uhr"))),
+            ),
+            terminated(
+                component(Component::Time, common::parse_two_digit_number),
+                (multispace1, Caseless("uhr")),
+            )
+            .verify(|&hour| time_utils::is_valid_24_hour_time(hour, 0, 0))
+            .map(|hour| (hour, 0, 0)),
+        ))
+        .map(|(hour, minute, second)| Time {
+            hour,
+            minute,
+            second,
+            meridiem: None, // German typically uses 24-hour format
+        })
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_time(input: &mut &str) -> winnow::Result<TimeExpression> {
+        Self::parse_time_of_day
+            .map(TimeExpression::Time)
             .parse_next(input)
     }
 
-    fn parse_time(input: &mut &str) -> winnow::Result<TimeExpression> {
+    fn parse_day_at_time(input: &mut &str) -> winnow::Result<TimeExpression> {
         (
-            Self::parse_time_digits,
-            opt(preceded(multispace1, Caseless("uhr"))),
+            alt((Self::parse_day, Self::parse_relative_day)),
+            multispace1,
+            Caseless("um"),
+            multispace1,
+            Self::parse_time_of_day,
         )
-            .map(|((hour, minute, second), _)| {
-                TimeExpression::Time(Time {
-                    hour,
-                    minute,
-                    second,
-                    meridiem: None, // German typically uses 24-hour format
-                })
-            })
+            .map(|(day, _, _, _, time)| TimeExpression::DayTime(DayTime { day, time }))
             .parse_next(input)
     }
 
-    fn parse_day_at_time(input: &mut &str) -> winnow::Result<TimeExpression> {
-        (
+    pub(crate) fn parse_month_name(input: &mut &str) -> winnow::Result<u8> {
+        alt((
             alt((
-                Self::parse_day_shortcuts,
-                Self::parse_modified_weekday,
-                Self::parse_simple_weekday,
+                "Januar".value(1),
+                "Jänner".value(1),
+                "Februar".value(2),
+                "März".value(3),
+                "April".value(4),
+                "Mai".value(5),
+                "Juni".value(6),
+                "Juli".value(7),
             )),
+            alt((
+                "August".value(8),
+                "September".value(9),
+                "Oktober".value(10),
+                "November".value(11),
+                "Dezember".value(12),
+            )),
+            // Abbreviations can be case-insensitive and may end with a period
+            terminated(
+                alt((
+                    alt((
+                        Caseless("jan").value(1),
+                        Caseless("feb").value(2),
+                        Caseless("mär").value(3),
+                        Caseless("apr").value(4),
+                        Caseless("jun").value(6),
+                        Caseless("jul").value(7),
+                    )),
+                    alt((
+                        Caseless("aug").value(8),
+                        Caseless("sept").value(9),
+                        Caseless("sep").value(9),
+                        Caseless("okt").value(10),
+                        Caseless("nov").value(11),
+                        Caseless("dez").value(12),
+                    )),
+                )),
+                opt('.'),
+            ),
+        ))
+        .parse_next(input)
+    }
+
+    fn parse_month_name_date(input: &mut &str) -> winnow::Result<TimeExpression> {
+        component(
+            Component::Date,
+            // "5. März 2024" or "5. März"
+            (
+                common::parse_two_digit_number,
+                alt((('.', multispace0).void(), multispace1.void())),
+                Self::parse_month_name,
+                opt(preceded(multispace1, common::parse_four_digit_number)),
+            )
+                .verify_map(|(day, _, month, year)| common::date_expression(day, month, year)),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_numeric_date(input: &mut &str) -> winnow::Result<TimeExpression> {
+        component(
+            Component::Date,
+            // DD.MM.YYYY (German format) or DD.MM. (year picked by the provider)
+            (
+                common::parse_two_digit_number,
+                '.',
+                common::parse_two_digit_number,
+                '.',
+                opt(common::parse_four_digit_number),
+            )
+                .verify_map(|(day, _, month, _, year)| common::date_expression(day, month, year)),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_date_prefix(input: &mut &str) -> winnow::Result<()> {
+        opt((Caseless("am"), multispace1)).void().parse_next(input)
+    }
+
+    pub(crate) fn parse_date_format(input: &mut &str) -> winnow::Result<TimeExpression> {
+        preceded(
+            Self::parse_date_prefix,
+            alt((Self::parse_numeric_date, Self::parse_month_name_date)),
+        )
+        .parse_next(input)
+    }
+
+    fn parse_period_offset(input: &mut &str) -> winnow::Result<i64> {
+        component(
+            Component::Modifier,
+            // "letzte", "diesen", "nächstes", "nächster"
+            terminated(
+                alt((
+                    Caseless("letzt").value(-1),
+                    Caseless("dies").value(0),
+                    Caseless("nächst").value(1),
+                )),
+                alt(("en", "er", "es", "e")),
+            ),
+        )
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_period(input: &mut &str) -> winnow::Result<TimeExpression> {
+        // "letzte Woche", "diesen Monat", "nächstes Quartal", "nächstes Jahr"
+        separated_pair(
+            Self::parse_period_offset,
             multispace1,
-            Caseless("um"),
-            multispace1,
-            Self::parse_time_digits,
-            opt(preceded(multispace1, Caseless("uhr"))),
+            component(
+                Component::Unit,
+                alt((
+                    "Woche".value(PeriodUnit::Week),
+                    "Monat".value(PeriodUnit::Month),
+                    "Quartal".value(PeriodUnit::Quarter),
+                    "Jahr".value(PeriodUnit::Year),
+                )),
+            ),
         )
-            .map(|(day, _, _, _, time_digits, _)| {
-                TimeExpression::DayTime(DayTime {
-                    day,
-                    time: Time {
-                        hour: time_digits.0,
-                        minute: time_digits.1,
-                        second: time_digits.2,
-                        meridiem: None,
+        .map(|(offset, unit)| TimeExpression::Period(Period { unit, offset }))
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_boundary(input: &mut &str) -> winnow::Result<TimeExpression> {
+        // "Ende des Monats", "Anfang nächster Woche", "Beginn des nächsten Jahres"
+        (
+            terminated(
+                component(
+                    Component::Edge,
+                    alt((
+                        "Anfang".value(Edge::Start),
+                        "Beginn".value(Edge::Start),
+                        "Ende".value(Edge::End),
+                    )),
+                ),
+                multispace1,
+            ),
+            opt(terminated(
+                alt((Caseless("des"), Caseless("der"))),
+                multispace1,
+            )),
+            opt(terminated(Self::parse_period_offset, multispace1)),
+            // Genitive forms
+            component(
+                Component::Unit,
+                alt((
+                    "Tages".value(PeriodUnit::Day),
+                    "Woche".value(PeriodUnit::Week),
+                    "Monats".value(PeriodUnit::Month),
+                    "Quartals".value(PeriodUnit::Quarter),
+                    alt(("Jahres", "Jahrs")).value(PeriodUnit::Year),
+                )),
+            ),
+        )
+            .map(|(edge, _, offset, unit)| {
+                TimeExpression::Boundary(Boundary {
+                    edge,
+                    period: Period {
+                        unit,
+                        offset: offset.unwrap_or(0),
                     },
                 })
             })
             .parse_next(input)
     }
 
-    fn parse_date_format(input: &mut &str) -> winnow::Result<TimeExpression> {
-        // DD.MM.YYYY (German format)
+    fn parse_weekday_list(input: &mut &str) -> winnow::Result<WeekdaySet> {
+        // "Montag", "Montag und Donnerstag", "Mo, Mi und Fr"
+        separated(
+            1..,
+            Self::parse_weekday,
+            alt((
+                (multispace0, ',', multispace0).void(),
+                (multispace1, Caseless("und"), multispace1).void(),
+            )),
+        )
+        .map(|days: Vec<Weekday>| days.into_iter().collect())
+        .parse_next(input)
+    }
+
+    fn parse_recurrence_ordinal(input: &mut &str) -> winnow::Result<u32> {
+        // "zweiten", "dritte", "2."
+        terminated(
+            component(
+                Component::Amount,
+                alt((
+                    terminated(
+                        alt(("zweit".value(2), "dritt".value(3), "viert".value(4))),
+                        alt(("en", "er", "es", "e")),
+                    ),
+                    terminated(common::parse_digit_number, '.')
+                        .verify_map(|interval| u32::try_from(interval).ok().filter(|i| *i > 0)),
+                )),
+            ),
+            multispace1,
+        )
+        .parse_next(input)
+    }
+
+    fn parse_recurrence_rule(input: &mut &str) -> winnow::Result<(Frequency, u32, WeekdaySet)> {
+        let on_weekdays = |input: &mut &str| {
+            opt(preceded(
+                (multispace1, Caseless("am"), multispace1),
+                Self::parse_weekday_list,
+            ))
+            .parse_next(input)
+        };
+        alt((
+            // "jeden Tag", "jede zweite Woche am Montag", "jeden Werktag", "jeden zweiten Freitag"
+            preceded(
+                (
+                    alt((Caseless("jeden"), Caseless("jedes"), Caseless("jede"))),
+                    multispace1,
+                ),
+                (
+                    opt(Self::parse_recurrence_ordinal),
+                    alt((
+                        (
+                            component(
+                                Component::Frequency,
+                                terminated(
+                                    alt((
+                                        "Minute".value(Frequency::Minutely),
+                                        "Stunde".value(Frequency::Hourly),
+                                        "Tag".value(Frequency::Daily),
+                                        "Woche".value(Frequency::Weekly),
+                                        "Monat".value(Frequency::Monthly),
+                                        "Jahr".value(Frequency::Yearly),
+                                    )),
+                                    common::word_boundary,
+                                ),
+                            ),
+                            on_weekdays,
+                        )
+                            .map(|(frequency, weekdays)| (frequency, weekdays.unwrap_or_default())),
+                        component(
+                            Component::Frequency,
+                            terminated("Werktag", common::word_boundary),
+                        )
+                        .value((Frequency::Weekly, WeekdaySet::WORKDAYS)),
+                        Self::parse_weekday_list.map(|weekdays| (Frequency::Weekly, weekdays)),
+                    )),
+                )
+                    .map(|(interval, (frequency, weekdays))| {
+                        (frequency, interval.unwrap_or(1), weekdays)
+                    }),
+            ),
+            // "alle 2 Wochen", "alle 15 Minuten"
+            preceded(
+                (Caseless("alle"), multispace1),
+                (
+                    Self::parse_number
+                        .verify_map(|interval| u32::try_from(interval).ok().filter(|i| *i > 0)),
+                    multispace1,
+                    component(
+                        Component::Frequency,
+                        terminated(
+                            alt((
+                                "Minuten".value(Frequency::Minutely),
+                                "Stunden".value(Frequency::Hourly),
+                                "Tage".value(Frequency::Daily),
+                                "Wochen".value(Frequency::Weekly),
+                                "Monate".value(Frequency::Monthly),
+                                "Jahre".value(Frequency::Yearly),
+                            )),
+                            common::word_boundary,
+                        ),
+                    ),
+                    on_weekdays,
+                )
+                    .map(|(interval, _, frequency, weekdays)| {
+                        (frequency, interval, weekdays.unwrap_or_default())
+                    }),
+            ),
+            // "täglich", "wöchentlich"
+            component(
+                Component::Frequency,
+                terminated(
+                    alt((
+                        Caseless("stündlich").value(Frequency::Hourly),
+                        Caseless("täglich").value(Frequency::Daily),
+                        Caseless("wöchentlich").value(Frequency::Weekly),
+                        Caseless("monatlich").value(Frequency::Monthly),
+                        Caseless("jährlich").value(Frequency::Yearly),
+                    )),
+                    common::word_boundary,
+                ),
+            )
+            .map(|frequency| (frequency, 1, WeekdaySet::empty())),
+        ))
+        .parse_next(input)
+    }
+
+    fn parse_recurrence_time(input: &mut &str) -> winnow::Result<Time> {
+        // "um 9:00", "um 9 Uhr"
+        preceded((Caseless("um"), multispace1), Self::parse_time_of_day).parse_next(input)
+    }
+
+    fn parse_recurrence_end(input: &mut &str) -> winnow::Result<RecurrenceEnd> {
+        alt((
+            // "bis 31.12.2024", "bis zum 31. Dezember 2024", "bis 5. März"
+            preceded(
+                (
+                    Caseless("bis"),
+                    multispace1,
+                    opt((Caseless("zum"), multispace1)),
+                ),
+                alt((Self::parse_numeric_date, Self::parse_month_name_date)),
+            )
+            .verify_map(|expr| match expr {
+                TimeExpression::Date(date) => Some(RecurrenceEnd::Until(date)),
+                TimeExpression::MonthDay(month_day) => {
+                    Some(RecurrenceEnd::UntilMonthDay(month_day))
+                }
+                _ => None,
+            }),
+            // "10 Mal", "10-mal"
+            terminated(
+                component(
+                    Component::Amount,
+                    common::parse_digit_number.verify_map(|count| u32::try_from(count).ok()),
+                ),
+                (alt((multispace1, "-")), Caseless("mal")),
+            )
+            .map(RecurrenceEnd::Count),
+        ))
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_recurrence(input: &mut &str) -> winnow::Result<TimeExpression> {
         (
-            common::parse_two_digit_number,
-            '.',
-            common::parse_two_digit_number,
-            '.',
-            common::parse_four_digit_number,
+            Self::parse_recurrence_rule,
+            opt(preceded(multispace1, Self::parse_recurrence_time)),
+            opt(preceded(
+                (opt(','), multispace1),
+                Self::parse_recurrence_end,
+            )),
         )
-            .verify_map(|(day, _, month, _, year)| {
-                time_utils::is_valid_calendar_date(year, month, day)
-                    .then_some(TimeExpression::Date(StandardDate { day, month, year }))
+            .map(|((frequency, interval, weekdays), time, end)| {
+                TimeExpression::Recurrence(Recurrence {
+                    frequency,
+                    interval,
+                    weekdays,
+                    time,
+                    end,
+                })
             })
             .parse_next(input)
     }
+
+    pub(crate) fn parse_point(input: &mut &str) -> winnow::Result<TimeExpression> {
+        alt((
+            Self::parse_iso_datetime,
+            Self::parse_date_format,
+            Self::parse_day_at_time,
+            Self::parse_now,
+            Self::parse_day_reference,
+            alt((
+                Self::parse_period,
+                Self::parse_boundary,
+                Self::parse_recurrence,
+            )),
+            Self::parse_time,
+            Self::parse_relative_past,
+            Self::parse_relative_future,
+        ))
+        .parse_next(input)
+    }
+
+    fn parse_range_separator(input: &mut &str) -> winnow::Result<()> {
+        alt((
+            (multispace0, '-', multispace0).void(),
+            (multispace1, Caseless("bis"), multispace1).void(),
+        ))
+        .parse_next(input)
+    }
+
+    fn parse_time_range(input: &mut &str) -> winnow::Result<(TimeExpression, TimeExpression)> {
+        let time = |hour| {
+            TimeExpression::Time(Time {
+                hour,
+                minute: 0,
+                second: 0,
+                meridiem: None,
+            })
+        };
+        preceded(
+            opt((Caseless("von"), multispace1)),
+            alt((
+                // "15:00-17:00", "9:00 bis 17:00 Uhr", "von 15 Uhr bis 17 Uhr"
+                separated_pair(
+                    Self::parse_time,
+                    Self::parse_range_separator,
+                    Self::parse_time,
+                ),
+                // "15-17 Uhr", "von 9 bis 17 Uhr"
+                (
+                    component(Component::Time, common::parse_two_digit_number),
+                    Self::parse_range_separator,
+                    component(Component::Time, common::parse_two_digit_number),
+                    multispace1,
+                    Caseless("uhr"),
+                )
+                    .verify_map(move |(start, _, end, _, _)| {
+                        (time_utils::is_valid_24_hour_time(start, 0, 0)
+                            && time_utils::is_valid_24_hour_time(end, 0, 0))
+                        .then(|| (time(start), time(end)))
+                    }),
+            )),
+        )
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_interval(input: &mut &str) -> winnow::Result<TimeExpression> {
+        alt((
+            // "von Montag bis Freitag", "vom 24.12. bis zum 26.12."
+            preceded(
+                (alt((Caseless("von"), Caseless("vom"))), multispace1),
+                separated_pair(
+                    Self::parse_point,
+                    (
+                        multispace1,
+                        Caseless("bis"),
+                        multispace1,
+                        opt((Caseless("zum"), multispace1)),
+                    ),
+                    Self::parse_point,
+                ),
+            ),
+            // "zwischen Montag und Freitag"
+            preceded(
+                (Caseless("zwischen"), multispace1),
+                separated_pair(
+                    Self::parse_point,
+                    (multispace1, Caseless("und"), multispace1),
+                    Self::parse_point,
+                ),
+            ),
+            Self::parse_time_range,
+        ))
+        .verify_map(|(start, end)| common::interval_expression(start, end))
+        .parse_next(input)
+    }
+
+    pub(crate) fn parse_expression(input: &mut &str) -> winnow::Result<TimeExpression> {
+        alt((Self::parse_interval, Self::parse_point)).parse_next(input)
+    }
+
+    /// Rules in the same order of preference as `parse`
+    const RULES: [Rule; 13] = [
+        Rule::Interval,
+        Rule::Iso8601,
+        Rule::NumericDate(DateOrder::Dmy),
+        Rule::MonthNameDate,
+        Rule::DayAtTime,
+        Rule::Now,
+        Rule::DayReference,
+        Rule::Period,
+        Rule::Boundary,
+        Rule::Recurrence,
+        Rule::Time,
+        Rule::RelativePast,
+        Rule::RelativeFuture,
+    ];
+
+    fn parse_rule(input: &mut &str, rule: Rule) -> winnow::Result<TimeExpression> {
+        match rule {
+            Rule::Now => Self::parse_now(input),
+            Rule::Iso8601 => Self::parse_iso_datetime(input),
+            // German numeric dates are always day-first
+            Rule::NumericDate(DateOrder::Dmy) => {
+                preceded(Self::parse_date_prefix, Self::parse_numeric_date).parse_next(input)
+            }
+            Rule::MonthNameDate => {
+                preceded(Self::parse_date_prefix, Self::parse_month_name_date).parse_next(input)
+            }
+            Rule::DayReference => Self::parse_day_reference(input),
+            Rule::Time => Self::parse_time(input),
+            Rule::DayAtTime => Self::parse_day_at_time(input),
+            Rule::RelativePast => Self::parse_relative_past(input),
+            Rule::RelativeFuture => Self::parse_relative_future(input),
+            Rule::Interval => Self::parse_interval(input),
+            Rule::Period => Self::parse_period(input),
+            Rule::Boundary => Self::parse_boundary(input),
+            Rule::Recurrence => Self::parse_recurrence(input),
+            Rule::Duration => alt((common::parse_iso_duration, Self::parse_standalone_duration))
+                .map(common::future_expression)
+                .parse_next(input),
+            // Only produced by reinterpreting weekdays
+            Rule::NumericDate(_) | Rule::WeekdayThisWeek | Rule::WeekdayNextWeek => fail(input),
+        }
+    }
+
+    fn weekday_name(day: Weekday) -> &'static str {
+        match day {
+            Weekday::Monday => "Montag",
+            Weekday::Tuesday => "Dienstag",
+            Weekday::Wednesday => "Mittwoch",
+            Weekday::Thursday => "Donnerstag",
+            Weekday::Friday => "Freitag",
+            Weekday::Saturday => "Samstag",
+            Weekday::Sunday => "Sonntag",
+        }
+    }
+
+    /// The stem of "letzte", "diese" and "nächste", to be declined
+    fn format_offset(offset: i64) -> Result<&'static str> {
+        match offset {
+            -1 => Ok("letzt"),
+            0 => Ok("dies"),
+            1 => Ok("nächst"),
+            _ => Err(common::unrepresentable()),
+        }
+    }
+
+    /// "9:30 Uhr", always on the 24-hour clock
+    fn format_time(time: Time) -> String {
+        let hour = time_utils::convert_12_to_24_hour(time.hour, time.meridiem.as_ref());
+        match time.second {
+            0 => format!("{hour}:{:02} Uhr", time.minute),
+            second => format!("{hour}:{:02}:{second:02} Uhr", time.minute),
+        }
+    }
+
+    fn format_day(&self, day: DayReference) -> Result<String> {
+        Ok(match day {
+            DayReference::Today => "heute".to_string(),
+            DayReference::Yesterday => "gestern".to_string(),
+            DayReference::Tomorrow => "morgen".to_string(),
+            DayReference::Weekday { day, modifier } => match modifier {
+                None => Self::weekday_name(day).to_string(),
+                Some(WeekdayModifier::Last) => format!("letzten {}", Self::weekday_name(day)),
+                Some(WeekdayModifier::Next) => format!("nächsten {}", Self::weekday_name(day)),
+            },
+            // "Montag nächster Woche"
+            DayReference::WeekdayInWeek { day, week_offset } => format!(
+                "{} {}er Woche",
+                Self::weekday_name(day),
+                Self::format_offset(week_offset)?
+            ),
+            DayReference::Relative(relative) => {
+                crate::format_relative(&relative.into(), Language::German)?
+            }
+        })
+    }
+
+    fn format_period(period: Period) -> Result<String> {
+        let offset = Self::format_offset(period.offset)?;
+        match period.unit {
+            PeriodUnit::Week => Ok(format!("{offset}e Woche")),
+            PeriodUnit::Month => Ok(format!("{offset}en Monat")),
+            PeriodUnit::Quarter => Ok(format!("{offset}es Quartal")),
+            PeriodUnit::Year => Ok(format!("{offset}es Jahr")),
+            // "heute" is a day reference
+            PeriodUnit::Day => Err(common::unrepresentable()),
+        }
+    }
+
+    fn format_boundary(boundary: Boundary) -> Result<String> {
+        let edge = match boundary.edge {
+            Edge::Start => "Anfang",
+            Edge::End => "Ende",
+        };
+        // Genitive: "Ende des Monats", "Anfang nächster Woche"
+        let period = match (boundary.period.unit, boundary.period.offset) {
+            (PeriodUnit::Week, 0) => "der Woche".to_string(),
+            (PeriodUnit::Week, offset) => format!("{}er Woche", Self::format_offset(offset)?),
+            (unit, offset) => {
+                let unit = match unit {
+                    PeriodUnit::Day => "Tages",
+                    PeriodUnit::Month => "Monats",
+                    PeriodUnit::Quarter => "Quartals",
+                    _ => "Jahres",
+                };
+                match offset {
+                    0 => format!("des {unit}"),
+                    offset => format!("des {}en {unit}", Self::format_offset(offset)?),
+                }
+            }
+        };
+        Ok(format!("{edge} {period}"))
+    }
+
+    fn format_recurrence(recurrence: Recurrence) -> Result<String> {
+        let (every, unit, units) = match recurrence.frequency {
+            Frequency::Minutely => ("jede", "Minute", "Minuten"),
+            Frequency::Hourly => ("jede", "Stunde", "Stunden"),
+            Frequency::Daily => ("jeden", "Tag", "Tage"),
+            Frequency::Weekly => ("jede", "Woche", "Wochen"),
+            Frequency::Monthly => ("jeden", "Monat", "Monate"),
+            Frequency::Yearly => ("jedes", "Jahr", "Jahre"),
+        };
+        let weekdays = common::format_weekday_list(recurrence.weekdays, Self::weekday_name, "und");
+        let weekly = recurrence.frequency == Frequency::Weekly && recurrence.interval == 1;
+        let mut text = match recurrence.interval {
+            0 => return Err(common::unrepresentable()),
+            _ if weekly && recurrence.weekdays == WeekdaySet::WORKDAYS => {
+                "jeden Werktag".to_string()
+            }
+            _ if weekly && !weekdays.is_empty() => format!("jeden {weekdays}"),
+            1 if weekdays.is_empty() => format!("{every} {unit}"),
+            1 => format!("{every} {unit} am {weekdays}"),
+            interval if weekdays.is_empty() => format!("alle {interval} {units}"),
+            interval => format!("alle {interval} {units} am {weekdays}"),
+        };
+
+        if let Some(time) = recurrence.time {
+            text += &format!(" um {}", Self::format_time(time));
+        }
+        match recurrence.end {
+            Some(RecurrenceEnd::Until(date)) => {
+                text += &format!(" bis {:02}.{:02}.{:04}", date.day, date.month, date.year);
+            }
+            Some(RecurrenceEnd::UntilMonthDay(month_day)) => {
+                text += &format!(" bis {:02}.{:02}.", month_day.day, month_day.month);
+            }
+            Some(RecurrenceEnd::Count(count)) => text += &format!(" {count} Mal"),
+            None => {}
+        }
+        Ok(text)
+    }
 }
 
 impl LanguageParser for GermanParser {
     fn parse(&self, input: &str) -> Result<TimeExpression> {
-        delimited(
-            multispace0,
-            alt((
-                Self::parse_iso_datetime,
-                Self::parse_date_format,
-                Self::parse_day_at_time,
-                Self::parse_now,
-                Self::parse_day_reference,
-                Self::parse_time,
-                Self::parse_relative_past,
-                Self::parse_relative_future,
+        delimited(multispace0, Self::parse_expression, multispace0)
+            .parse(input)
+            .map_err(|e| e.to_temps_error(input))
+    }
+
+    fn parse_candidates(&self, input: &str, options: &ParseOptions) -> Result<Vec<Candidate>> {
+        self.parse_with_options(input, options)?;
+
+        Ok(common::collect_candidates(
+            input,
+            Self::RULES,
+            Self::parse_rule,
+        ))
+    }
+
+    fn parse_prefix<'i>(
+        &self,
+        input: &'i str,
+        _options: &ParseOptions,
+    ) -> Result<(TimeExpression, &'i str)> {
+        let rules = Self::RULES.into_iter().chain([Rule::Duration]);
+        common::parse_longest_prefix(input, rules, Self::parse_rule)
+    }
+
+    fn parse_duration(&self, input: &str) -> Result<Duration> {
+        let duration = alt((common::parse_iso_duration, Self::parse_standalone_duration));
+        delimited(multispace0, duration, multispace0)
+            .parse(input)
+            .map_err(|e| e.to_temps_error(input))
+    }
+
+    fn format(&self, expr: &TimeExpression, language: Language) -> Result<String> {
+        if language != Language::German {
+            return EnglishParser.format(expr, language);
+        }
+        match *expr {
+            TimeExpression::Now => Ok("jetzt".to_string()),
+            TimeExpression::Relative(relative) => {
+                crate::format_relative(&relative.into(), Language::German)
+            }
+            TimeExpression::RelativeDuration(relative) => {
+                crate::format_relative(&relative, Language::German)
+            }
+            TimeExpression::Absolute(time) => Ok(common::format_iso_datetime(&time)),
+            TimeExpression::Day(day) => self.format_day(day),
+            TimeExpression::Time(time) => Ok(Self::format_time(time)),
+            TimeExpression::Date(date) => Ok(format!(
+                "{:02}.{:02}.{:04}",
+                date.day, date.month, date.year
             )),
-            multispace0,
-        )
-        .parse(input)
-        .map_err(|e| e.to_temps_error(input))
+            TimeExpression::MonthDay(date) => Ok(format!("{:02}.{:02}.", date.day, date.month)),
+            TimeExpression::DayTime(DayTime { day, time }) => Ok(format!(
+                "{} um {}",
+                self.format_day(day)?,
+                Self::format_time(time)
+            )),
+            TimeExpression::Interval(Interval { start, end }) => Ok(format!(
+                "von {} bis {}",
+                self.format(&start.into(), language)?,
+                self.format(&end.into(), language)?
+            )),
+            TimeExpression::Period(period) => Self::format_period(period),
+            TimeExpression::Boundary(boundary) => Self::format_boundary(boundary),
+            TimeExpression::Recurrence(recurrence) => Self::format_recurrence(recurrence),
+        }
+    }
+
+    fn format_relative(&self, relative: &RelativeDuration) -> Result<String> {
+        // "in" and "vor" take the dative: "in 3 Tagen", "vor 2 Monaten"
+        let amounts =
+            common::format_components(&relative.duration, |unit, amount| match (unit, amount) {
+                (TimeUnit::Second, 1) => "Sekunde",
+                (TimeUnit::Second, _) => "Sekunden",
+                (TimeUnit::Minute, 1) => "Minute",
+                (TimeUnit::Minute, _) => "Minuten",
+                (TimeUnit::Hour, 1) => "Stunde",
+                (TimeUnit::Hour, _) => "Stunden",
+                (TimeUnit::Day, 1) => "Tag",
+                (TimeUnit::Day, _) => "Tagen",
+                (TimeUnit::Week, 1) => "Woche",
+                (TimeUnit::Week, _) => "Wochen",
+                (TimeUnit::Month, 1) => "Monat",
+                (TimeUnit::Month, _) => "Monaten",
+                (TimeUnit::Year, 1) => "Jahr",
+                (TimeUnit::Year, _) => "Jahren",
+            });
+        Ok(match (amounts, relative.direction) {
+            (None, _) => "jetzt".to_string(),
+            (Some(amounts), Direction::Future) => format!("in {amounts}"),
+            (Some(amounts), Direction::Past) => format!("vor {amounts}"),
+        })
     }
 }
@@ -36,7 +36,7 @@
 //!
 //! ## Supported Languages
 //!
-//! - English
+//! - English (`EnglishUs` and `EnglishGb` pick month-first or day-first numeric dates)
 //! - German
 //!
 //! ## Error Handling
@@ -44,10 +44,39 @@
 //! All parsing operations return a `Result<T, TempsError>` where `TempsError`
 //! provides detailed information about what went wrong during parsing or
 //! date calculations.
+//!
+//! ## Serde
+//!
+//! With the `serde` feature, the types of parsed expressions, recurrence rules
+//! and cron schedules, the enums and [`TempsError`] implement `Serialize` and
+//! `Deserialize` in a representation that stays stable across releases:
+//!
+//! - Structs are objects with their field names (`{"hour": 9, "minute": 0, ...}`).
+//! - Enums without data are their variant names in snake case (`"minute"`,
+//!   `"english_us"`).
+//! - Enums with data are objects tagged by `type`, the variant name in snake
+//!   case, with the data in `value`: `{"type": "now"}`,
+//!   `{"type": "relative", "value": {"amount": 5, "unit": "minute", "direction": "future"}}`.
+//! - Sets of weekdays and cron fields are lists of their members
+//!   (`["monday", "friday"]`, `[0, 15, 30, 45]`).
+//!
+//! ```
+//! # #[cfg(feature = "serde")] {
+//! use temps_core::{Language, TempsError, TimeExpression, parse};
+//!
+//! let expr = parse("every day at 9am", Language::English).unwrap();
+//! let json = serde_json::to_string(&expr).unwrap();
+//! assert_eq!(serde_json::from_str::<TimeExpression>(&json).unwrap(), expr);
+//!
+//! let error = parse("in 5 blargs", Language::English).unwrap_err();
+//! let json = serde_json::to_value(&error).unwrap();
+//! assert_eq!(json["type"], "parse_error");
+//! assert_eq!(serde_json::from_value::<TempsError>(json).unwrap(), error);
+//! # }
+//! ```
 
 use winnow::{
     ascii::digit1,
-    combinator::{alt, opt},
     prelude::*,
     token::{one_of, take_while},
 };
@@ -56,6 +85,25 @@ use winnow::{
 pub mod error;
 pub use error::{Result, TempsError};
 
+// ===== iCalendar Module =====
+pub mod rrule;
+pub use rrule::parse_rrule;
+
+// ===== Cron Module =====
+pub mod cron;
+pub use cron::parse_cron;
+
+// ===== Extract Module =====
+pub mod extract;
+pub use extract::{extract, find_iter};
+
+// ===== Combinators Module =====
+pub mod combinators;
+
+// ===== Spanned Module =====
+pub mod spanned;
+pub use spanned::parse_spanned;
+
 // ===== Core Types =====
 
 /// Represents a parsed time expression.
@@ -70,12 +118,18 @@ pub use error::{Result, TempsError};
 ///
 /// // "now" -> TimeExpression::Now
 /// // "in 5 minutes" -> TimeExpression::Relative(...)
+/// // "in 2 hours 30 minutes" -> TimeExpression::RelativeDuration(...)
 /// // "2024-01-15T14:30:00Z" -> TimeExpression::Absolute(...)
 /// // "tomorrow" -> TimeExpression::Day(...)
 /// // "3:30 pm" -> TimeExpression::Time(...)
 /// // "tomorrow at 3:30 pm" -> TimeExpression::DayTime(...)
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
 pub enum TimeExpression {
     /// The current moment in time (e.g., "now", "jetzt")
     Now,
@@ -91,6 +145,18 @@ pub enum TimeExpression {
     Date(StandardDate),
     /// A day with a specific time (e.g., "tomorrow at 3:30 pm")
     DayTime(DayTime),
+    /// A time relative to now spanning several units (e.g., "in 2 hours 30 minutes")
+    RelativeDuration(RelativeDuration),
+    /// A calendar date without a year (e.g., "March 5", "24.12.")
+    MonthDay(MonthDay),
+    /// A span between two points in time (e.g., "from 3pm to 5pm", "between Monday and Friday")
+    Interval(Interval),
+    /// A calendar period relative to the current one (e.g., "last week", "nächstes Jahr")
+    Period(Period),
+    /// The start or end of a calendar period (e.g., "end of month", "Anfang nächster Woche")
+    Boundary(Boundary),
+    /// A rule for repeating points in time (e.g., "every Monday at 9am", "jeden zweiten Freitag")
+    Recurrence(Recurrence),
 }
 
 /// Represents a time relative to the current moment.
@@ -115,6 +181,7 @@ pub enum TimeExpression {
 /// };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelativeTime {
     /// The numeric amount (e.g., 5 in "5 minutes")
     pub amount: i64,
@@ -124,6 +191,204 @@ pub struct RelativeTime {
     pub direction: Direction,
 }
 
+/// A duration made up of several time units.
+///
+/// Each unit is kept separately instead of being normalized, because calendar
+/// units like months and years have no fixed length. Amounts are applied
+/// largest unit first when resolving a point in time.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Duration, TimeUnit};
+///
+/// // "2 hours 30 minutes"
+/// let duration = Duration::default()
+///     .with(TimeUnit::Hour, 2)
+///     .with(TimeUnit::Minute, 30);
+///
+/// assert_eq!(duration.hours, 2);
+/// assert_eq!(duration.get(TimeUnit::Minute), 30);
+/// assert_eq!(
+///     duration.components().collect::<Vec<_>>(),
+///     vec![(TimeUnit::Hour, 2), (TimeUnit::Minute, 30)]
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Duration {
+    /// Number of years
+    pub years: i64,
+    /// Number of months
+    pub months: i64,
+    /// Number of weeks
+    pub weeks: i64,
+    /// Number of days
+    pub days: i64,
+    /// Number of hours
+    pub hours: i64,
+    /// Number of minutes
+    pub minutes: i64,
+    /// Number of seconds
+    pub seconds: i64,
+    /// Number of nanoseconds (from fractional amounts like "1.5 seconds")
+    pub nanoseconds: i64,
+}
+
+impl Duration {
+    /// All time units, largest first.
+    const UNITS: [TimeUnit; 7] = [
+        TimeUnit::Year,
+        TimeUnit::Month,
+        TimeUnit::Week,
+        TimeUnit::Day,
+        TimeUnit::Hour,
+        TimeUnit::Minute,
+        TimeUnit::Second,
+    ];
+
+    /// The amount of the given unit.
+    #[must_use]
+    pub fn get(&self, unit: TimeUnit) -> i64 {
+        match unit {
+            TimeUnit::Year => self.years,
+            TimeUnit::Month => self.months,
+            TimeUnit::Week => self.weeks,
+            TimeUnit::Day => self.days,
+            TimeUnit::Hour => self.hours,
+            TimeUnit::Minute => self.minutes,
+            TimeUnit::Second => self.seconds,
+        }
+    }
+
+    /// Set the amount of the given unit.
+    #[must_use]
+    pub fn with(mut self, unit: TimeUnit, amount: i64) -> Self {
+        let field = match unit {
+            TimeUnit::Year => &mut self.years,
+            TimeUnit::Month => &mut self.months,
+            TimeUnit::Week => &mut self.weeks,
+            TimeUnit::Day => &mut self.days,
+            TimeUnit::Hour => &mut self.hours,
+            TimeUnit::Minute => &mut self.minutes,
+            TimeUnit::Second => &mut self.seconds,
+        };
+        *field = amount;
+        self
+    }
+
+    /// The non-zero units of this duration with their amounts, largest unit first.
+    pub fn components(&self) -> impl Iterator<Item = (TimeUnit, i64)> + '_ {
+        Self::UNITS
+            .into_iter()
+            .map(|unit| (unit, self.get(unit)))
+            .filter(|&(_, amount)| amount != 0)
+    }
+
+    /// Whether every unit of this duration is zero.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.nanoseconds == 0 && self.components().next().is_none()
+    }
+
+    /// Whether this duration contains months or years, which have no fixed length.
+    #[must_use]
+    pub fn has_calendar_units(&self) -> bool {
+        self.years != 0 || self.months != 0
+    }
+}
+
+/// Converts a duration into a [`std::time::Duration`].
+///
+/// Weeks and days are taken as 7 and 1 nominal 24-hour days.
+///
+/// # Errors
+///
+/// Fails with `TempsError::UnsupportedOperation` if the duration contains
+/// months or years or is negative, and with `TempsError::ArithmeticOverflow`
+/// if it does not fit.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Language, parse_duration};
+///
+/// let duration = parse_duration("1.5 days", Language::English).unwrap();
+/// let std_duration = std::time::Duration::try_from(duration).unwrap();
+/// assert_eq!(std_duration.as_secs(), 36 * 3600);
+/// ```
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = TempsError;
+
+    fn try_from(duration: Duration) -> Result<Self> {
+        if duration.has_calendar_units() {
+            return Err(TempsError::unsupported_operation(
+                errors::ERR_CALENDAR_UNITS_IN_DURATION,
+            ));
+        }
+
+        let overflow = || TempsError::arithmetic_overflow(errors::ERR_DURATION_OVERFLOW);
+        let seconds = [
+            (duration.weeks, constants::SECONDS_PER_WEEK),
+            (duration.days, constants::SECONDS_PER_DAY),
+            (duration.hours, i64::from(constants::SECONDS_PER_HOUR)),
+            (duration.minutes, i64::from(constants::SECONDS_PER_MINUTE)),
+            (duration.seconds, 1),
+        ]
+        .into_iter()
+        .try_fold(0_i64, |total, (amount, factor)| {
+            amount
+                .checked_mul(factor)
+                .and_then(|seconds| total.checked_add(seconds))
+        })
+        .ok_or_else(overflow)?;
+
+        let nanoseconds = i128::from(seconds) * i128::from(constants::NANOS_PER_SECOND)
+            + i128::from(duration.nanoseconds);
+        let nanoseconds = u128::try_from(nanoseconds)
+            .map_err(|_| TempsError::unsupported_operation(errors::ERR_NEGATIVE_DURATION))?;
+        let nanos_per_second = constants::NANOS_PER_SECOND as u128;
+
+        Ok(Self::new(
+            u64::try_from(nanoseconds / nanos_per_second).map_err(|_| overflow())?,
+            (nanoseconds % nanos_per_second) as u32,
+        ))
+    }
+}
+
+/// Represents a time relative to the current moment spanning several units.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Direction, Duration, RelativeDuration, TimeUnit};
+///
+/// // "1 day and 3 hours ago"
+/// let past = RelativeDuration {
+///     duration: Duration::default()
+///         .with(TimeUnit::Day, 1)
+///         .with(TimeUnit::Hour, 3),
+///     direction: Direction::Past,
+/// };
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeDuration {
+    /// The amount of time, per unit
+    pub duration: Duration,
+    /// Whether this is in the past or future
+    pub direction: Direction,
+}
+
+impl From<RelativeTime> for RelativeDuration {
+    fn from(relative: RelativeTime) -> Self {
+        Self {
+            duration: Duration::default().with(relative.unit, relative.amount),
+            direction: relative.direction,
+        }
+    }
+}
+
 /// Represents an absolute date and time.
 ///
 /// This type can represent various levels of precision, from just a date
@@ -159,6 +424,7 @@ pub struct RelativeTime {
 /// };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbsoluteTime {
     /// The year (e.g., 2024)
     pub year: u16,
@@ -195,6 +461,11 @@ pub struct AbsoluteTime {
 /// let negative = Timezone::Offset { hours: -5, minutes: 30 };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
 pub enum Timezone {
     /// UTC timezone (represented as "Z" in ISO format)
     Utc,
@@ -228,8 +499,19 @@ pub enum Timezone {
 ///     day: Weekday::Friday,
 ///     modifier: None,
 /// };
+///
+/// // "next week Monday" (Monday of the following week)
+/// let monday_next_week = DayReference::WeekdayInWeek {
+///     day: Weekday::Monday,
+///     week_offset: 1,
+/// };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
 pub enum DayReference {
     /// Today's date
     Today,
@@ -244,6 +526,15 @@ pub enum DayReference {
         /// Optional modifier (next/last)
         modifier: Option<WeekdayModifier>,
     },
+    /// A weekday in a week relative to the current one (e.g., "next week Monday")
+    WeekdayInWeek {
+        /// The day of the week
+        day: Weekday,
+        /// Weeks from the current week (-1 for last week, 1 for next week)
+        week_offset: i64,
+    },
+    /// The day a relative offset lands on (e.g., "in 3 days" in "in 3 days at 5pm")
+    Relative(RelativeTime),
 }
 
 /// Represents a time of day.
@@ -272,6 +563,7 @@ pub enum DayReference {
 /// };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Time {
     /// Hour (0-23 for 24-hour format, 1-12 for 12-hour format)
     pub hour: u8,
@@ -300,6 +592,7 @@ pub struct Time {
 /// };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StandardDate {
     /// Day of month (1-31)
     pub day: u8,
@@ -309,9 +602,32 @@ pub struct StandardDate {
     pub year: u16,
 }
 
+/// Represents a calendar date without a year.
+///
+/// Used for parsing dates like "March 5", "05/03" or "5.3.". Providers pick the
+/// year relative to the reference time according to a [`YearPolicy`].
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::MonthDay;
+///
+/// // "24.12."
+/// let christmas_eve = MonthDay { day: 24, month: 12 };
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonthDay {
+    /// Day of month (1-31)
+    pub day: u8,
+    /// Month (1-12)
+    pub month: u8,
+}
+
 /// Represents a combination of a day reference and a specific time.
 ///
-/// Used for expressions like "tomorrow at 3:30 pm" or "next Monday at 9:00 am".
+/// Used for expressions like "tomorrow at 3:30 pm", "next Monday at 9:00 am"
+/// or "in 3 days at 5pm".
 ///
 /// # Examples
 ///
@@ -330,6 +646,7 @@ pub struct StandardDate {
 /// };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DayTime {
     /// The day reference
     pub day: DayReference,
@@ -337,199 +654,1491 @@ pub struct DayTime {
     pub time: Time,
 }
 
-/// Units of time used in relative expressions.
+/// Represents a calendar period relative to the current one.
+///
+/// Used for expressions like "last week", "this month", "next quarter" or
+/// "nächstes Jahr". Providers resolve periods to the half-open interval
+/// covering them; weeks start on the provider's [`WeekStart`].
 ///
 /// # Examples
 ///
 /// ```
-/// use temps_core::TimeUnit;
+/// use temps_core::{Period, PeriodUnit};
 ///
-/// // Used in expressions like:
-/// // "5 seconds", "10 minutes", "2 hours", "3 days",
-/// // "1 week", "6 months", "2 years"
+/// // "last month"
+/// let last_month = Period { unit: PeriodUnit::Month, offset: -1 };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum TimeUnit {
-    Second,
-    Minute,
-    Hour,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Period {
+    /// The kind of period
+    pub unit: PeriodUnit,
+    /// Periods from the current one (-1 for "last", 0 for "this", 1 for "next")
+    pub offset: i64,
+}
+
+/// Calendar periods that can be referenced with "this", "last" or "next".
+///
+/// Days only appear in boundaries ("end of day"); "next day" is not a period.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PeriodUnit {
+    /// "end of day", "EOD", "Ende des Tages"
     Day,
+    /// "next week", "diese Woche"
     Week,
+    /// "this month", "letzten Monat"
     Month,
+    /// "next quarter", "dieses Quartal"
+    Quarter,
+    /// "last year", "nächstes Jahr"
     Year,
 }
 
-/// Direction of time relative to now.
+/// Represents the start or end of a calendar period.
+///
+/// Used for expressions like "end of month", "start of next week", "EOD" or
+/// "Ende des Jahres". Providers resolve the start to midnight on the first day
+/// of the period and the end according to a [`PeriodEnd`].
 ///
 /// # Examples
 ///
 /// ```
-/// use temps_core::Direction;
+/// use temps_core::{Boundary, Edge, Period, PeriodUnit};
 ///
-/// // "5 minutes ago" -> Direction::Past
-/// // "in 5 minutes" -> Direction::Future
+/// // "beginning of next quarter"
+/// let next_quarter = Boundary {
+///     edge: Edge::Start,
+///     period: Period { unit: PeriodUnit::Quarter, offset: 1 },
+/// };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum Direction {
-    Past,
-    Future,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Boundary {
+    /// Which end of the period
+    pub edge: Edge,
+    /// The period whose start or end is meant
+    pub period: Period,
 }
 
-/// Days of the week.
-///
-/// Used in expressions like "next Monday" or "last Friday".
+/// Which end of a [`Boundary`]'s period is meant.
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum Weekday {
-    Monday,
-    Tuesday,
-    Wednesday,
-    Thursday,
-    Friday,
-    Saturday,
-    Sunday,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Edge {
+    /// "start of", "beginning of", "Anfang"
+    Start,
+    /// "end of", "Ende"
+    End,
 }
 
-/// Modifiers for weekday references.
+/// Represents a rule for repeating points in time.
+///
+/// Used for expressions like "every Monday at 9am", "every other week",
+/// "daily until 31/12/2024" or "jeden zweiten Freitag". Providers yield the
+/// occurrences at or after the reference time, with the first period of the
+/// recurrence being the one that contains the reference time.
 ///
 /// # Examples
 ///
 /// ```
-/// use temps_core::WeekdayModifier;
-///
-/// // "last Monday" -> WeekdayModifier::Last
-/// // "next Friday" -> WeekdayModifier::Next
-/// // "Monday" (no modifier) -> finds the next occurrence
+/// use temps_core::{Frequency, Recurrence, Time, Weekday, WeekdaySet};
+///
+/// // "every other Friday at 17:00"
+/// let payday = Recurrence {
+///     frequency: Frequency::Weekly,
+///     interval: 2,
+///     weekdays: WeekdaySet::from(Weekday::Friday),
+///     time: Some(Time { hour: 17, minute: 0, second: 0, meridiem: None }),
+///     end: None,
+/// };
 /// ```
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum WeekdayModifier {
-    Last,
-    Next,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recurrence {
+    /// How often the recurrence repeats
+    pub frequency: Frequency,
+    /// Repeat every `interval` periods (2 for "every other week")
+    pub interval: u32,
+    /// The days of the week occurrences fall on, or empty for the reference day
+    pub weekdays: WeekdaySet,
+    /// The time of day of each occurrence, midnight if unset
+    pub time: Option<Time>,
+    /// When the recurrence stops, never if unset
+    pub end: Option<RecurrenceEnd>,
 }
 
-/// AM/PM indicator for 12-hour time format.
+/// How often a [`Recurrence`] repeats.
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum Meridiem {
-    AM,
-    PM,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Frequency {
+    /// "every 15 minutes", "jede Minute"
+    Minutely,
+    /// "hourly", "alle 2 Stunden"
+    Hourly,
+    /// "every day", "täglich"
+    Daily,
+    /// "every Monday", "jede zweite Woche"
+    Weekly,
+    /// "monthly", "jeden Monat"
+    Monthly,
+    /// "every year", "jährlich"
+    Yearly,
 }
 
-/// Supported languages for parsing time expressions.
+/// When a [`Recurrence`] stops.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
+pub enum RecurrenceEnd {
+    /// No occurrences after this date (e.g., "until 31/12/2024", "bis 31.12.2024")
+    Until(StandardDate),
+    /// No occurrences after the next time this day of the year comes around,
+    /// today included (e.g., "until March 5th", "bis 5. März")
+    UntilMonthDay(MonthDay),
+    /// A fixed number of occurrences (e.g., "10 times", "10 Mal")
+    Count(u32),
+}
+
+/// An iCalendar (RFC 5545) recurrence rule, as found in `RRULE` properties.
+///
+/// Unlike a [`Recurrence`], a rule is anchored to its `DTSTART`: parts the
+/// rule leaves open, such as the weekday of a weekly rule or the day of a
+/// monthly rule, come from the start, and `COUNT` counts occurrences from it.
+/// Rules are read with [`parse_rrule`] and written back as RRULE text by
+/// their [`Display`](std::fmt::Display) implementation.
 ///
 /// # Examples
 ///
 /// ```
-/// use temps_core::{parse, Language};
+/// use temps_core::{ByDay, Frequency, RRule, Weekday, parse_rrule};
 ///
-/// // Parse English
-/// let expr = parse("in 5 minutes", Language::English);
+/// // The last Friday of every month
+/// let rule = parse_rrule("RRULE:FREQ=MONTHLY;BYDAY=-1FR").unwrap();
+/// assert_eq!(rule.frequency, Frequency::Monthly);
+/// assert_eq!(rule.by_day, [ByDay { weekday: Weekday::Friday, ordinal: Some(-1) }]);
 ///
-/// // Parse German
-/// let expr = parse("in 5 Minuten", Language::German);
+/// let mut rule = RRule::new(Frequency::Weekly);
+/// rule.interval = 2;
+/// rule.by_day = vec![Weekday::Tuesday.into(), Weekday::Thursday.into()];
+/// assert_eq!(rule.to_string(), "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH");
 /// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RRule {
+    /// The first occurrence (`DTSTART`), the reference time if unset.
+    ///
+    /// [`Timezone::Utc`] marks a UTC time; without a time zone the start is
+    /// a local time, and without an hour a date.
+    pub start: Option<AbsoluteTime>,
+    /// The `TZID` of the start, kept for serialization
+    pub time_zone: Option<String>,
+    /// How often the rule repeats (`FREQ`)
+    pub frequency: Frequency,
+    /// Repeat every `interval` periods (`INTERVAL`)
+    pub interval: u32,
+    /// Months of the year, 1-12 (`BYMONTH`)
+    pub by_month: Vec<u8>,
+    /// Days of the month, negative from the end of the month (`BYMONTHDAY`)
+    pub by_month_day: Vec<i8>,
+    /// Days of the week, optionally numbered within the period (`BYDAY`)
+    pub by_day: Vec<ByDay>,
+    /// Hours, 0-23 (`BYHOUR`)
+    pub by_hour: Vec<u8>,
+    /// Minutes, 0-59 (`BYMINUTE`)
+    pub by_minute: Vec<u8>,
+    /// Seconds, 0-59 (`BYSECOND`)
+    pub by_second: Vec<u8>,
+    /// Positions within each period's occurrences, negative from the end (`BYSETPOS`)
+    pub by_set_pos: Vec<i16>,
+    /// When the rule stops (`UNTIL` or `COUNT`), never if unset
+    pub end: Option<RRuleEnd>,
+    /// The first day of the week (`WKST`)
+    pub week_start: WeekStart,
+}
+
+impl RRule {
+    /// A rule repeating every period of `frequency`, without any other parts.
+    #[must_use]
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            start: None,
+            time_zone: None,
+            frequency,
+            interval: 1,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_day: Vec::new(),
+            by_hour: Vec::new(),
+            by_minute: Vec::new(),
+            by_second: Vec::new(),
+            by_set_pos: Vec::new(),
+            end: None,
+            week_start: WeekStart::Monday,
+        }
+    }
+}
+
+/// A day of the week in an [`RRule`], such as `MO` or `-1FR`.
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum Language {
-    English,
-    German,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ByDay {
+    /// The day of the week
+    pub weekday: Weekday,
+    /// Which of these days within the month or year (1 for the first, -1 for
+    /// the last), every one if unset. Only monthly and yearly rules number
+    /// their days.
+    pub ordinal: Option<i8>,
 }
 
-// ===== Traits =====
+impl From<Weekday> for ByDay {
+    fn from(weekday: Weekday) -> Self {
+        Self {
+            weekday,
+            ordinal: None,
+        }
+    }
+}
 
-/// Trait for implementing time parsing with a specific datetime backend.
+/// When an [`RRule`] stops.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
+pub enum RRuleEnd {
+    /// No occurrences after this date or time (`UNTIL`)
+    Until(AbsoluteTime),
+    /// A fixed number of occurrences from the start (`COUNT`)
+    Count(u32),
+}
+
+/// A cron schedule, such as `*/15 9-17 * * MON-FRI`.
 ///
-/// This trait should be implemented by datetime libraries (chrono, jiff, etc.)
-/// to provide the actual time calculation logic.
+/// A time fires the schedule when its second, minute, hour and month are in
+/// the schedule and its day matches. As in cron, a day matches either of the
+/// two day fields when both are restricted, and the restricted one otherwise.
+/// Schedules are read with [`parse_cron`].
 ///
 /// # Examples
 ///
 /// ```
-/// use temps_core::{TimeParser, TimeExpression, Result};
-///
-/// struct MyTimeParser;
-///
-/// impl TimeParser for MyTimeParser {
-///     type DateTime = String; // Your datetime type
+/// use temps_core::{Weekday, parse_cron};
 ///
-///     fn now(&self) -> Self::DateTime {
-///         "2024-01-15T14:30:00Z".to_string()
-///     }
-///
-///     fn parse_expression(&self, expr: TimeExpression) -> Result<Self::DateTime> {
-///         // Implementation here
-///         Ok(self.now())
-///     }
-/// }
+/// let schedule = parse_cron("*/15 9-17 * * MON-FRI").unwrap();
+/// assert!(schedule.minutes.contains(45));
+/// assert!(!schedule.hours.contains(18));
+/// assert!(schedule.weekdays.contains(Weekday::Friday));
+/// assert!(!schedule.either_day);
 /// ```
-pub trait TimeParser {
-    /// The datetime type used by this implementation
-    type DateTime;
-
-    /// Get the current date and time
-    fn now(&self) -> Self::DateTime;
-
-    /// Parse a time expression into a concrete datetime
-    ///
-    /// # Errors
-    ///
-    /// Returns `TempsError` if:
-    /// - Date calculation results in an invalid date
-    /// - Arithmetic overflow occurs
-    /// - The backend library returns an error
-    fn parse_expression(&self, expr: TimeExpression) -> Result<Self::DateTime>;
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CronSchedule {
+    /// Seconds, 0-59 (only `0` in five-field expressions)
+    pub seconds: CronField,
+    /// Minutes, 0-59
+    pub minutes: CronField,
+    /// Hours, 0-23
+    pub hours: CronField,
+    /// Days of the month, 1-31
+    pub days: CronField,
+    /// Months, 1-12
+    pub months: CronField,
+    /// Days of the week
+    pub weekdays: WeekdaySet,
+    /// Whether a day matching either day field fires the schedule, which is
+    /// the case when neither field starts with `*` or `?`
+    pub either_day: bool,
 }
 
-/// Trait for implementing language-specific parsers.
-///
-/// This trait is implemented by language modules to provide
-/// natural language parsing for different languages.
+/// The values of one [`CronSchedule`] field, each between 0 and 63.
 ///
 /// # Examples
 ///
 /// ```
-/// use temps_core::{LanguageParser, TimeExpression, Result};
-///
-/// struct MyLanguageParser;
+/// use temps_core::CronField;
 ///
-/// impl LanguageParser for MyLanguageParser {
-///     fn parse(&self, input: &str) -> Result<TimeExpression> {
-///         // Parse language-specific input
-///         Ok(TimeExpression::Now)
-///     }
-/// }
+/// let quarters: CronField = [0, 15, 30, 45].into_iter().collect();
+/// assert!(quarters.contains(30));
+/// assert_eq!(quarters.iter().collect::<Vec<_>>(), [0, 15, 30, 45]);
 /// ```
-pub trait LanguageParser {
-    /// Parse a natural language time expression
-    ///
-    /// # Errors
-    ///
-    /// Returns `TempsError::ParseError` if the input cannot be parsed
-    fn parse(&self, input: &str) -> Result<TimeExpression>;
-}
-
-// ===== Constants Module =====
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct CronField(u64);
 
-pub mod constants {
-    //! Common constants used across the temps library
+impl CronField {
+    /// The field without any values.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
 
-    /// Number of seconds in one hour
-    pub const SECONDS_PER_HOUR: i32 = 3600;
+    /// The field with `value` added, unchanged if `value` is above 63.
+    #[must_use]
+    pub const fn with(self, value: u8) -> Self {
+        match 1u64.checked_shl(value as u32) {
+            Some(bit) => Self(self.0 | bit),
+            None => self,
+        }
+    }
 
-    /// Number of seconds in one minute  
-    pub const SECONDS_PER_MINUTE: i32 = 60;
+    /// Whether `value` is in the field.
+    #[must_use]
+    pub const fn contains(self, value: u8) -> bool {
+        value < 64 && self.0 & 1 << value != 0
+    }
 
-    /// Number of minutes in one hour
-    pub const MINUTES_PER_HOUR: i32 = 60;
+    /// The values in the field, in ascending order.
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        (0..64).filter(move |&value| self.contains(value))
+    }
 
-    /// Number of hours in one day
-    pub const HOURS_PER_DAY: i32 = 24;
+    /// The smallest value at or above `value`.
+    #[must_use]
+    pub fn next_from(self, value: u8) -> Option<u8> {
+        let above = self.0.checked_shr(u32::from(value))?;
+        (above != 0).then(|| value + above.trailing_zeros() as u8)
+    }
 
-    /// Number of days in one week
-    pub const DAYS_PER_WEEK: i32 = 7;
+    /// The largest value at or below `value`.
+    #[must_use]
+    pub fn prev_from(self, value: u8) -> Option<u8> {
+        let value = value.min(63);
+        let below = self.0 << (63 - value);
+        (below != 0).then(|| value - below.leading_zeros() as u8)
+    }
+}
 
-    /// Number of months in one year
-    pub const MONTHS_PER_YEAR: i32 = 12;
+impl FromIterator<u8> for CronField {
+    fn from_iter<I: IntoIterator<Item = u8>>(values: I) -> Self {
+        values.into_iter().fold(Self::empty(), Self::with)
+    }
 }
 
-// ===== Errors Module =====
+/// Serialized as the list of its values, e.g. `[0, 15, 30, 45]`
+#[cfg(feature = "serde")]
+impl serde::Serialize for CronField {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CronField {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let values = Vec::<u8>::deserialize(deserializer)?;
+        if values.iter().any(|&value| value > 63) {
+            return Err(serde::de::Error::custom(errors::ERR_CRON_FIELD_VALUE));
+        }
+        Ok(values.into_iter().collect())
+    }
+}
+
+/// Represents a span between two points in time.
+///
+/// Used for expressions like "from 3pm to 5pm", "between Monday and Friday",
+/// "3-5pm" or "von 9:00 bis 17:00". Intervals are half-open: an end that names
+/// a whole day ("Friday", "March 10") includes that day.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Endpoint, Interval, Time};
+///
+/// // "from 15:00 to 17:00"
+/// let time = |hour| Endpoint::Time(Time { hour, minute: 0, second: 0, meridiem: None });
+/// let afternoon = Interval { start: time(15), end: time(17) };
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interval {
+    /// Where the interval starts
+    pub start: Endpoint,
+    /// Where the interval ends
+    pub end: Endpoint,
+}
+
+/// One end of an [`Interval`]: any time expression except another interval.
+///
+/// A bare time, an unmodified weekday or a date without a year at the end of
+/// an interval is resolved from the start ("tomorrow at 3pm to 5pm" ends
+/// tomorrow, "Friday to Monday" ends on the Monday after that Friday).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
+pub enum Endpoint {
+    /// "now", "jetzt"
+    Now,
+    /// A relative time (e.g., "in 2 hours")
+    Relative(RelativeTime),
+    /// An ISO 8601 date or datetime
+    Absolute(AbsoluteTime),
+    /// A day reference (e.g., "Monday", "tomorrow")
+    Day(DayReference),
+    /// A time of day (e.g., "3pm")
+    Time(Time),
+    /// A calendar date (e.g., "15/03/2024")
+    Date(StandardDate),
+    /// A day at a specific time (e.g., "tomorrow at 3pm")
+    DayTime(DayTime),
+    /// A relative time spanning several units (e.g., "in 2 hours 30 minutes")
+    RelativeDuration(RelativeDuration),
+    /// A calendar date without a year (e.g., "March 5")
+    MonthDay(MonthDay),
+    /// The start or end of a period (e.g., "end of month")
+    Boundary(Boundary),
+}
+
+impl From<Endpoint> for TimeExpression {
+    fn from(endpoint: Endpoint) -> Self {
+        match endpoint {
+            Endpoint::Now => TimeExpression::Now,
+            Endpoint::Relative(rel) => TimeExpression::Relative(rel),
+            Endpoint::Absolute(abs) => TimeExpression::Absolute(abs),
+            Endpoint::Day(day) => TimeExpression::Day(day),
+            Endpoint::Time(time) => TimeExpression::Time(time),
+            Endpoint::Date(date) => TimeExpression::Date(date),
+            Endpoint::DayTime(day_time) => TimeExpression::DayTime(day_time),
+            Endpoint::RelativeDuration(rel) => TimeExpression::RelativeDuration(rel),
+            Endpoint::MonthDay(month_day) => TimeExpression::MonthDay(month_day),
+            Endpoint::Boundary(boundary) => TimeExpression::Boundary(boundary),
+        }
+    }
+}
+
+impl TryFrom<TimeExpression> for Endpoint {
+    type Error = TempsError;
+
+    fn try_from(expr: TimeExpression) -> Result<Self> {
+        match expr {
+            TimeExpression::Now => Ok(Endpoint::Now),
+            TimeExpression::Relative(rel) => Ok(Endpoint::Relative(rel)),
+            TimeExpression::Absolute(abs) => Ok(Endpoint::Absolute(abs)),
+            TimeExpression::Day(day) => Ok(Endpoint::Day(day)),
+            TimeExpression::Time(time) => Ok(Endpoint::Time(time)),
+            TimeExpression::Date(date) => Ok(Endpoint::Date(date)),
+            TimeExpression::DayTime(day_time) => Ok(Endpoint::DayTime(day_time)),
+            TimeExpression::RelativeDuration(rel) => Ok(Endpoint::RelativeDuration(rel)),
+            TimeExpression::MonthDay(month_day) => Ok(Endpoint::MonthDay(month_day)),
+            TimeExpression::Boundary(boundary) => Ok(Endpoint::Boundary(boundary)),
+            TimeExpression::Interval(_) | TimeExpression::Period(_) => Err(
+                TempsError::unsupported_operation(errors::ERR_NESTED_INTERVAL),
+            ),
+            TimeExpression::Recurrence(_) => Err(TempsError::unsupported_operation(
+                errors::ERR_RECURRENCE_ENDPOINT,
+            )),
+        }
+    }
+}
+
+/// Units of time used in relative expressions.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::TimeUnit;
+///
+/// // Used in expressions like:
+/// // "5 seconds", "10 minutes", "2 hours", "3 days",
+/// // "1 week", "6 months", "2 years"
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// Direction of time relative to now.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::Direction;
+///
+/// // "5 minutes ago" -> Direction::Past
+/// // "in 5 minutes" -> Direction::Future
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Direction {
+    Past,
+    Future,
+}
+
+/// Days of the week.
+///
+/// Used in expressions like "next Monday" or "last Friday".
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// A set of days of the week.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Weekday, WeekdaySet};
+///
+/// let weekend = WeekdaySet::from(Weekday::Saturday).with(Weekday::Sunday);
+/// assert!(weekend.contains(Weekday::Sunday));
+/// assert!(!weekend.contains(Weekday::Monday));
+/// assert_eq!(weekend.iter().collect::<Vec<_>>(), [Weekday::Saturday, Weekday::Sunday]);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct WeekdaySet(u8);
+
+impl WeekdaySet {
+    const DAYS: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Monday to Friday
+    pub const WORKDAYS: Self = Self(0b0001_1111);
+
+    /// The set without any days.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// The set with `day` added.
+    #[must_use]
+    pub const fn with(self, day: Weekday) -> Self {
+        Self(self.0 | 1 << day as u8)
+    }
+
+    /// Whether `day` is in the set.
+    #[must_use]
+    pub const fn contains(self, day: Weekday) -> bool {
+        self.0 & 1 << day as u8 != 0
+    }
+
+    /// Whether the set has no days.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The days in the set, from Monday to Sunday.
+    pub fn iter(self) -> impl Iterator<Item = Weekday> {
+        Self::DAYS
+            .into_iter()
+            .filter(move |day| self.contains(*day))
+    }
+}
+
+impl From<Weekday> for WeekdaySet {
+    fn from(day: Weekday) -> Self {
+        Self::empty().with(day)
+    }
+}
+
+impl FromIterator<Weekday> for WeekdaySet {
+    fn from_iter<I: IntoIterator<Item = Weekday>>(days: I) -> Self {
+        days.into_iter().fold(Self::empty(), Self::with)
+    }
+}
+
+/// Serialized as the list of its days, e.g. `["monday", "friday"]`
+#[cfg(feature = "serde")]
+impl serde::Serialize for WeekdaySet {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for WeekdaySet {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Ok(Vec::<Weekday>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Modifiers for weekday references.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::WeekdayModifier;
+///
+/// // "last Monday" -> WeekdayModifier::Last
+/// // "next Friday" -> WeekdayModifier::Next
+/// // "Monday" (no modifier) -> finds the next occurrence
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum WeekdayModifier {
+    Last,
+    Next,
+}
+
+/// AM/PM indicator for 12-hour time format.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Meridiem {
+    AM,
+    PM,
+}
+
+/// Supported languages for parsing time expressions.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{parse, Language};
+///
+/// // Parse English
+/// let expr = parse("in 5 minutes", Language::English);
+///
+/// // Parse German
+/// let expr = parse("in 5 Minuten", Language::German);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Language {
+    /// English without a region; numeric dates are day-first
+    English,
+    /// American English; numeric dates are month-first ("03/05/2024" is March 5)
+    EnglishUs,
+    /// British English; numeric dates are day-first ("03/05/2024" is 3 May)
+    EnglishGb,
+    German,
+}
+
+impl Language {
+    /// The order numeric dates are written in by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use temps_core::{DateOrder, Language};
+    ///
+    /// assert_eq!(Language::EnglishUs.date_order(), DateOrder::Mdy);
+    /// assert_eq!(Language::German.date_order(), DateOrder::Dmy);
+    /// ```
+    #[must_use]
+    pub fn date_order(self) -> DateOrder {
+        match self {
+            Language::EnglishUs => DateOrder::Mdy,
+            Language::English | Language::EnglishGb | Language::German => DateOrder::Dmy,
+        }
+    }
+}
+
+/// Order of the day, month and year in numeric dates such as "03/05/2024".
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{DateOrder, Language, ParseOptions, StandardDate, TimeExpression, parse};
+///
+/// let options = ParseOptions::new(Language::English).with_date_order(DateOrder::Mdy);
+/// let expr = parse("03/05/2024", options).unwrap();
+/// assert_eq!(expr, TimeExpression::Date(StandardDate { day: 5, month: 3, year: 2024 }));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DateOrder {
+    /// Day first: "31/12/2024"
+    Dmy,
+    /// Month first: "12/31/2024"
+    Mdy,
+    /// Year first: "2024/12/31"
+    Ymd,
+}
+
+/// Options for [`parse`].
+///
+/// A [`Language`] converts into default options, so `parse(input, Language::English)`
+/// and `parse(input, ParseOptions::new(Language::English))` are equivalent.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{DateOrder, Language, ParseOptions};
+///
+/// let options = ParseOptions::new(Language::English).with_date_order(DateOrder::Ymd);
+/// assert_eq!(options.date_order(), DateOrder::Ymd);
+///
+/// // Without an explicit order, the language decides
+/// assert_eq!(ParseOptions::from(Language::EnglishUs).date_order(), DateOrder::Mdy);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct ParseOptions {
+    language: Language,
+    date_order: Option<DateOrder>,
+}
+
+impl ParseOptions {
+    /// Create options for the given language.
+    #[must_use]
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            date_order: None,
+        }
+    }
+
+    /// Read numeric dates in the given order instead of the language's default.
+    #[must_use]
+    pub fn with_date_order(mut self, date_order: DateOrder) -> Self {
+        self.date_order = Some(date_order);
+        self
+    }
+
+    /// The language to parse.
+    #[must_use]
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// The order numeric dates are read in.
+    #[must_use]
+    pub fn date_order(&self) -> DateOrder {
+        self.date_order
+            .unwrap_or_else(|| self.language.date_order())
+    }
+}
+
+impl From<Language> for ParseOptions {
+    fn from(language: Language) -> Self {
+        Self::new(language)
+    }
+}
+
+/// How the last unit of a humanized time is rounded.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Rounding {
+    /// Towards the reference time: 1 hour 50 minutes is "1 hour"
+    Down,
+    /// To the closest amount, halfway away from the reference time: 1 hour 30
+    /// minutes is "2 hours"
+    #[default]
+    Nearest,
+    /// Away from the reference time: 1 hour 10 minutes is "2 hours"
+    Up,
+}
+
+/// Options for humanizing a point in time relative to the reference time.
+///
+/// A [`Language`] converts into default options, which write a single unit
+/// down to seconds, rounded to the nearest amount.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{HumanizeOptions, Language, Rounding, TimeUnit};
+///
+/// // "in 2 hours 30 minutes" rather than "in 3 hours"
+/// let options = HumanizeOptions::new(Language::English)
+///     .with_max_units(2)
+///     .with_smallest_unit(TimeUnit::Minute)
+///     .with_rounding(Rounding::Down);
+/// assert_eq!(
+///     options.units().collect::<Vec<_>>(),
+///     [TimeUnit::Year, TimeUnit::Month, TimeUnit::Week, TimeUnit::Day, TimeUnit::Hour, TimeUnit::Minute]
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct HumanizeOptions {
+    language: Language,
+    max_units: usize,
+    smallest_unit: TimeUnit,
+    rounding: Rounding,
+}
+
+impl HumanizeOptions {
+    /// Create options for the given language.
+    #[must_use]
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            max_units: 1,
+            smallest_unit: TimeUnit::Second,
+            rounding: Rounding::default(),
+        }
+    }
+
+    /// Write up to this many units, such as 2 for "in 2 hours 30 minutes".
+    ///
+    /// Zero is treated as one.
+    #[must_use]
+    pub fn with_max_units(mut self, max_units: usize) -> Self {
+        self.max_units = max_units.max(1);
+        self
+    }
+
+    /// Leave out units smaller than this one.
+    #[must_use]
+    pub fn with_smallest_unit(mut self, unit: TimeUnit) -> Self {
+        self.smallest_unit = unit;
+        self
+    }
+
+    /// Round the last unit written this way.
+    #[must_use]
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// The language to write.
+    #[must_use]
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// The largest number of units written.
+    #[must_use]
+    pub fn max_units(&self) -> usize {
+        self.max_units
+    }
+
+    /// The smallest unit written.
+    #[must_use]
+    pub fn smallest_unit(&self) -> TimeUnit {
+        self.smallest_unit
+    }
+
+    /// How the last unit written is rounded.
+    #[must_use]
+    pub fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    /// The units that may be written, largest first.
+    pub fn units(&self) -> impl Iterator<Item = TimeUnit> {
+        let smallest = self.smallest_unit;
+        let count = Duration::UNITS
+            .iter()
+            .position(|&unit| unit == smallest)
+            .map_or(Duration::UNITS.len(), |index| index + 1);
+        Duration::UNITS.into_iter().take(count)
+    }
+}
+
+impl From<Language> for HumanizeOptions {
+    fn from(language: Language) -> Self {
+        Self::new(language)
+    }
+}
+
+/// The grammar rule that produced a [`Candidate`].
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{DateOrder, Language, Rule, parse_candidates};
+///
+/// let candidates = parse_candidates("04/05/2024", Language::English).unwrap();
+/// assert_eq!(candidates[0].rule, Rule::NumericDate(DateOrder::Dmy));
+/// assert_eq!(candidates[1].rule, Rule::NumericDate(DateOrder::Mdy));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
+pub enum Rule {
+    /// "now", "jetzt"
+    Now,
+    /// ISO 8601 dates and datetimes: "2024-01-15T14:30:00Z"
+    Iso8601,
+    /// Numeric dates read in the given order: "03/05/2024", "24.12."
+    NumericDate(DateOrder),
+    /// Dates with a month name: "March 5th 2024", "5. März"
+    MonthNameDate,
+    /// Day references: "tomorrow", "next Monday", "in 3 days"
+    DayReference,
+    /// Times of day: "3:30 pm", "14:30 Uhr"
+    Time,
+    /// A day and a time: "tomorrow at 3:30 pm"
+    DayAtTime,
+    /// Relative times in the past: "2 hours ago", "vor 2 Stunden"
+    RelativePast,
+    /// Relative times in the future: "in 2 hours"
+    RelativeFuture,
+    /// Intervals: "from 3pm to 5pm", "between Monday and Friday", "3-5pm"
+    Interval,
+    /// Calendar periods: "last week", "this month", "nächstes Jahr"
+    Period,
+    /// Period boundaries: "end of month", "start of next week", "Ende des Jahres"
+    Boundary,
+    /// Recurrences: "every Monday at 9am", "jeden zweiten Freitag"
+    Recurrence,
+    /// A bare duration read as that long from now: "2h", "90 Minuten". Only
+    /// [`parse_prefix`] uses this rule.
+    Duration,
+    /// A weekday read as the day in the current week: "Monday" as "this week Monday"
+    WeekdayThisWeek,
+    /// A weekday read as the day in the next week: "next Monday" as "next week Monday"
+    WeekdayNextWeek,
+}
+
+/// One plausible interpretation of an input, as returned by [`parse_candidates`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Candidate {
+    /// The interpretation
+    pub expression: TimeExpression,
+    /// The rule that produced it
+    pub rule: Rule,
+}
+
+/// A time expression found in free text, as returned by [`find_iter`].
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Language, find_iter};
+///
+/// let text = "Can we meet next Friday at 3pm?";
+/// let found = find_iter(text, Language::English).next().unwrap();
+/// assert_eq!(&text[found.range], "next Friday at 3pm");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Match {
+    /// The expression
+    pub expression: TimeExpression,
+    /// Where the expression is in the text, in bytes
+    pub range: std::ops::Range<usize>,
+}
+
+/// The kind of a part of an expression, as reported by [`parse_spanned`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Component {
+    /// An amount or count: "5", "five", "other", "10" in "10 times"
+    Amount,
+    /// A unit of time or calendar period: "minutes", "week", "Monats"
+    Unit,
+    /// A weekday: "Monday", "Fr"
+    Weekday,
+    /// A named day: "today", "morgen"
+    Day,
+    /// A word that moves a day or period: "next", "last", "this", "nächsten"
+    Modifier,
+    /// A time of day: "3:30 pm", "9am", "14:30"
+    Time,
+    /// A calendar date: "15/03/2024", "March 5th", "24.12."
+    Date,
+    /// A UTC offset: "Z", "+02:00"
+    Timezone,
+    /// The start or end of a period: "end", "Anfang", "EOD"
+    Edge,
+    /// How often a recurrence repeats: "week" in "every week", "daily", "Werktag"
+    Frequency,
+}
+
+/// A part of an expression and where it is in the input.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// What the part is
+    pub component: Component,
+    /// Where the part is in the input, in bytes
+    pub range: std::ops::Range<usize>,
+}
+
+/// A parsed expression with the parts it was read from, as returned by
+/// [`parse_spanned`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spanned {
+    /// The expression
+    pub expression: TimeExpression,
+    /// The parts of the input, from left to right
+    pub spans: Vec<Span>,
+}
+
+/// Policy for local times that are skipped or repeated by a DST transition.
+///
+/// When clocks spring forward, local times inside the gap (e.g. "2:30" on the
+/// night of the change) do not exist. When clocks fall back, local times in the
+/// overlap occur twice. Providers apply this policy uniformly whenever they turn
+/// a local date and time into an instant.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Disambiguation, FoldChoice};
+///
+/// // The default matches RFC 5545 and `java.time`/Temporal behaviour
+/// let policy = Disambiguation::default();
+/// assert_eq!(policy, Disambiguation::Compatible);
+/// assert_eq!(policy.gap_choice(), Some(FoldChoice::Later));
+/// assert_eq!(policy.overlap_choice(), Some(FoldChoice::Earlier));
+///
+/// // Reject refuses to guess
+/// assert_eq!(Disambiguation::Reject.gap_choice(), None);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Disambiguation {
+    /// Use the later side of a gap and the earlier side of an overlap
+    #[default]
+    Compatible,
+    /// Always use the earlier side
+    Earlier,
+    /// Always use the later side
+    Later,
+    /// Return `TempsError::AmbiguousTime` instead of guessing
+    Reject,
+}
+
+impl Disambiguation {
+    /// The side to use for a local time inside a gap, or `None` to reject it.
+    #[must_use]
+    pub fn gap_choice(self) -> Option<FoldChoice> {
+        match self {
+            Self::Compatible | Self::Later => Some(FoldChoice::Later),
+            Self::Earlier => Some(FoldChoice::Earlier),
+            Self::Reject => None,
+        }
+    }
+
+    /// The side to use for a local time inside an overlap, or `None` to reject it.
+    #[must_use]
+    pub fn overlap_choice(self) -> Option<FoldChoice> {
+        match self {
+            Self::Compatible | Self::Earlier => Some(FoldChoice::Earlier),
+            Self::Later => Some(FoldChoice::Later),
+            Self::Reject => None,
+        }
+    }
+}
+
+/// One side of a DST transition.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FoldChoice {
+    /// The offset in effect before the transition was used for overlaps;
+    /// for gaps, the result lies before the skipped local times
+    Earlier,
+    /// The offset in effect after the transition was used for overlaps;
+    /// for gaps, the result lies after the skipped local times
+    Later,
+}
+
+/// How a local time was mapped onto the timeline.
+///
+/// Providers report this alongside the resolved datetime so callers can tell
+/// whether a [`Disambiguation`] policy had to be applied.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Fold, FoldChoice};
+///
+/// // "2:30" on the night clocks spring forward, resolved to 3:30
+/// let fold = Fold::Gap(FoldChoice::Later);
+/// assert!(fold.is_ambiguous());
+/// assert!(!Fold::Unambiguous.is_ambiguous());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
+pub enum Fold {
+    /// The local time exists exactly once (or no local time was involved)
+    #[default]
+    Unambiguous,
+    /// The local time was skipped by a transition; the given side was used
+    Gap(FoldChoice),
+    /// The local time occurred twice; the given occurrence was used
+    Overlap(FoldChoice),
+}
+
+impl Fold {
+    /// Whether a disambiguation policy had to be applied.
+    #[must_use]
+    pub fn is_ambiguous(self) -> bool {
+        !matches!(self, Self::Unambiguous)
+    }
+}
+
+/// How to pick the year of a date written without one (e.g., "March 5").
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{MonthDay, StandardDate, YearPolicy, time_utils::resolve_month_day};
+///
+/// let today = StandardDate { day: 15, month: 6, year: 2024 };
+/// let march_5 = MonthDay { day: 5, month: 3 };
+///
+/// assert_eq!(resolve_month_day(today, march_5, YearPolicy::Next).unwrap().year, 2025);
+/// assert_eq!(resolve_month_day(today, march_5, YearPolicy::Previous).unwrap().year, 2024);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum YearPolicy {
+    /// The next occurrence, including today
+    #[default]
+    Next,
+    /// The most recent occurrence, including today
+    Previous,
+    /// Whichever occurrence is closest to today, preferring the next one on ties
+    Nearest,
+}
+
+/// Which instant the end of a period (e.g., "end of month") resolves to.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::PeriodEnd;
+///
+/// // "end of March 2024" is either 2024-03-31T23:59:59.999999999
+/// // or 2024-04-01T00:00:00
+/// assert_eq!(PeriodEnd::default(), PeriodEnd::LastNanosecond);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PeriodEnd {
+    /// The last nanosecond inside the period, for inclusive comparisons
+    #[default]
+    LastNanosecond,
+    /// The start of the following period, for exclusive comparisons
+    NextStart,
+}
+
+/// The first day of the week, for "this week", "next week Monday" and the like.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{WeekStart, time_utils::days_from_week_start};
+///
+/// // Sunday (6 days from Monday) opens a US week
+/// assert_eq!(days_from_week_start(6, WeekStart::Sunday), 0);
+/// assert_eq!(WeekStart::default(), WeekStart::Monday);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum WeekStart {
+    /// ISO 8601 weeks, as in most of Europe
+    #[default]
+    Monday,
+    /// Weeks as in the US, Canada or Japan
+    Sunday,
+    /// Weeks as in much of the Middle East
+    Saturday,
+}
+
+// ===== Traits =====
+
+/// Trait for implementing time parsing with a specific datetime backend.
+///
+/// This trait should be implemented by datetime libraries (chrono, jiff, etc.)
+/// to provide the actual time calculation logic.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{TimeParser, TimeExpression, Result};
+///
+/// struct MyTimeParser;
+///
+/// impl TimeParser for MyTimeParser {
+///     type DateTime = String; // Your datetime type
+///
+///     fn now(&self) -> Self::DateTime {
+///         "2024-01-15T14:30:00Z".to_string()
+///     }
+///
+///     fn parse_expression(&self, expr: TimeExpression) -> Result<Self::DateTime> {
+///         // Implementation here
+///         Ok(self.now())
+///     }
+/// }
+/// ```
+pub trait TimeParser {
+    /// The datetime type used by this implementation
+    type DateTime;
+
+    /// Get the current date and time
+    fn now(&self) -> Self::DateTime;
+
+    /// Parse a time expression into a concrete datetime
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError` if:
+    /// - Date calculation results in an invalid date
+    /// - Arithmetic overflow occurs
+    /// - The backend library returns an error
+    fn parse_expression(&self, expr: TimeExpression) -> Result<Self::DateTime>;
+}
+
+/// Trait for supplying the reference time that expressions are resolved against.
+///
+/// Providers ask their clock for "now" whenever an expression is relative to the
+/// current moment. Injecting a clock makes resolution deterministic, which is
+/// especially useful in tests.
+///
+/// Any `Fn() -> T` closure is a clock, and [`FixedClock`] always returns the
+/// same instant.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Clock, FixedClock};
+///
+/// let clock = FixedClock(1_700_000_000_i64);
+/// assert_eq!(clock.now(), 1_700_000_000);
+///
+/// let counter = || 42_i64;
+/// assert_eq!(Clock::now(&counter), 42);
+/// ```
+pub trait Clock<T>: Send + Sync {
+    /// Get the current date and time
+    fn now(&self) -> T;
+}
+
+impl<T, F> Clock<T> for F
+where
+    F: Fn() -> T + Send + Sync,
+{
+    fn now(&self) -> T {
+        self()
+    }
+}
+
+/// A clock that is frozen at a fixed reference time.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Clock, FixedClock};
+///
+/// let clock = FixedClock("2024-01-15T14:30:00Z");
+/// assert_eq!(clock.now(), "2024-01-15T14:30:00Z");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct FixedClock<T>(pub T);
+
+impl<T: Clone + Send + Sync> Clock<T> for FixedClock<T> {
+    fn now(&self) -> T {
+        self.0.clone()
+    }
+}
+
+/// Trait for implementing language-specific parsers.
+///
+/// This trait is implemented by language modules to provide
+/// natural language parsing for different languages.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{LanguageParser, TimeExpression, Result};
+///
+/// struct MyLanguageParser;
+///
+/// impl LanguageParser for MyLanguageParser {
+///     fn parse(&self, input: &str) -> Result<TimeExpression> {
+///         // Parse language-specific input
+///         Ok(TimeExpression::Now)
+///     }
+/// }
+/// ```
+pub trait LanguageParser {
+    /// Parse a natural language time expression
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::ParseError` if the input cannot be parsed
+    fn parse(&self, input: &str) -> Result<TimeExpression>;
+
+    /// Parse a natural language time expression with the given options
+    ///
+    /// The default implementation ignores the options.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::ParseError` if the input cannot be parsed
+    fn parse_with_options(&self, input: &str, _options: &ParseOptions) -> Result<TimeExpression> {
+        self.parse(input)
+    }
+
+    /// List every plausible interpretation of a time expression
+    ///
+    /// The first candidate is the one [`LanguageParser::parse_with_options`]
+    /// returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::ParseError` if the input cannot be parsed, or
+    /// `TempsError::UnsupportedOperation` if the language cannot list
+    /// candidates
+    fn parse_candidates(&self, _input: &str, _options: &ParseOptions) -> Result<Vec<Candidate>> {
+        Err(TempsError::unsupported_operation(
+            errors::ERR_CANDIDATES_UNSUPPORTED,
+        ))
+    }
+
+    /// Parse the time expression at the start of the input
+    ///
+    /// Returns the expression and the unconsumed rest of the input, see
+    /// [`parse_prefix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::ParseError` if the input does not start with a
+    /// time expression, or `TempsError::UnsupportedOperation` if the language
+    /// cannot parse prefixes
+    fn parse_prefix<'i>(
+        &self,
+        _input: &'i str,
+        _options: &ParseOptions,
+    ) -> Result<(TimeExpression, &'i str)> {
+        Err(TempsError::unsupported_operation(
+            errors::ERR_PREFIX_UNSUPPORTED,
+        ))
+    }
+
+    /// Parse a standalone duration (e.g., "90 minutes", "2h30m")
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::ParseError` if the input cannot be parsed, or
+    /// `TempsError::UnsupportedOperation` if the language has no duration
+    /// grammar
+    fn parse_duration(&self, _input: &str) -> Result<Duration> {
+        Err(TempsError::unsupported_operation(
+            errors::ERR_DURATION_PARSING_UNSUPPORTED,
+        ))
+    }
+
+    /// Write a relative time as text this parser reads back (e.g., "in 2
+    /// hours 30 minutes", "now" if it is empty)
+    ///
+    /// Nanoseconds are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::UnsupportedOperation` if the language cannot
+    /// write relative times
+    fn format_relative(&self, _relative: &RelativeDuration) -> Result<String> {
+        Err(TempsError::unsupported_operation(
+            errors::ERR_FORMATTING_UNSUPPORTED,
+        ))
+    }
+
+    /// Write a time expression as canonical text this parser reads back
+    ///
+    /// `language` picks regional conventions, such as month-first dates in
+    /// American English. Other languages are written by their own parser.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::UnsupportedOperation` if the language cannot
+    /// write time expressions or has no words for this one (e.g., a period
+    /// three weeks ago)
+    fn format(&self, _expr: &TimeExpression, _language: Language) -> Result<String> {
+        Err(TempsError::unsupported_operation(
+            errors::ERR_FORMATTING_UNSUPPORTED,
+        ))
+    }
+}
+
+// ===== Constants Module =====
+
+pub mod constants {
+    //! Common constants used across the temps library
+
+    /// Number of seconds in one hour
+    pub const SECONDS_PER_HOUR: i32 = 3600;
+
+    /// Number of seconds in one minute  
+    pub const SECONDS_PER_MINUTE: i32 = 60;
+
+    /// Number of minutes in one hour
+    pub const MINUTES_PER_HOUR: i32 = 60;
+
+    /// Number of hours in one day
+    pub const HOURS_PER_DAY: i32 = 24;
+
+    /// Number of days in one week
+    pub const DAYS_PER_WEEK: i32 = 7;
+
+    /// Number of months in one year
+    pub const MONTHS_PER_YEAR: i32 = 12;
+
+    /// Number of months in one quarter
+    pub const MONTHS_PER_QUARTER: i32 = 3;
+
+    /// Number of seconds in one (nominal) day
+    pub const SECONDS_PER_DAY: i64 = 86_400;
+
+    /// Number of seconds in one (nominal) week
+    pub const SECONDS_PER_WEEK: i64 = 604_800;
+
+    /// Number of nanoseconds in one second
+    pub const NANOS_PER_SECOND: i64 = 1_000_000_000;
+}
+
+// ===== Errors Module =====
 
 pub mod errors {
     //! Common error messages and error handling utilities
@@ -567,6 +2176,77 @@ pub mod errors {
     /// Error message for negative relative amounts
     pub const ERR_RELATIVE_AMOUNT_NON_NEGATIVE: &str = "Relative amount must be non-negative";
 
+    /// Error message for converting months or years into a fixed-length duration
+    pub const ERR_CALENDAR_UNITS_IN_DURATION: &str =
+        "Months and years have no fixed length and cannot be converted";
+
+    /// Error message for converting a negative duration into an unsigned one
+    pub const ERR_NEGATIVE_DURATION: &str = "Negative durations cannot be converted";
+
+    /// Error message for languages without duration parsing
+    pub const ERR_DURATION_PARSING_UNSUPPORTED: &str = "Duration parsing is not supported";
+
+    /// Error message for languages that cannot write time expressions
+    pub const ERR_FORMATTING_UNSUPPORTED: &str = "Formatting is not supported";
+
+    /// Error message for expressions a language has no words for
+    pub const ERR_FORMAT_UNREPRESENTABLE: &str = "Expression cannot be written in this language";
+
+    /// Error message for an interval used as the end of another interval
+    pub const ERR_NESTED_INTERVAL: &str = "Intervals cannot be nested";
+
+    /// Error message for a recurrence used as the end of an interval
+    pub const ERR_RECURRENCE_ENDPOINT: &str = "Recurrences cannot be interval endpoints";
+
+    /// Error message for resolving occurrences of something that does not recur
+    pub const ERR_NOT_A_RECURRENCE: &str = "Expression is not a recurrence";
+
+    /// Error message for a recurrence without occurrences after the reference time
+    pub const ERR_NO_OCCURRENCES: &str = "Recurrence has no further occurrences";
+
+    /// Error message for an RRULE without a FREQ part
+    pub const ERR_RRULE_MISSING_FREQ: &str = "Recurrence rule must specify FREQ";
+
+    /// Error message for a malformed RRULE or DTSTART part
+    pub const ERR_RRULE_INVALID_PART: &str = "Invalid recurrence rule part";
+
+    /// Error message for a valid RRULE part that is not supported
+    pub const ERR_RRULE_UNSUPPORTED_PART: &str = "Unsupported recurrence rule part";
+
+    /// Error message for an RRULE with both COUNT and UNTIL
+    pub const ERR_RRULE_COUNT_AND_UNTIL: &str =
+        "Recurrence rule cannot specify both COUNT and UNTIL";
+
+    /// Error message for a cron expression without five or six fields
+    pub const ERR_CRON_FIELD_COUNT: &str = "Cron expression must have 5 or 6 fields";
+
+    /// Error message for a malformed cron field
+    pub const ERR_CRON_INVALID_FIELD: &str = "Invalid cron field";
+
+    /// Error message for cron syntax that is not supported
+    pub const ERR_CRON_UNSUPPORTED: &str = "Unsupported cron syntax";
+
+    /// Error message for a cron field value that does not fit in a field
+    pub const ERR_CRON_FIELD_VALUE: &str = "Cron field values must be between 0 and 63";
+
+    /// Error message for resolving a single point in time as an interval
+    pub const ERR_NOT_AN_INTERVAL: &str = "Expression is not an interval";
+
+    /// Error message for an interval that ends before it starts
+    pub const ERR_INTERVAL_END_BEFORE_START: &str = "Interval ends before it starts";
+
+    /// Error message for languages that cannot list parse candidates
+    pub const ERR_CANDIDATES_UNSUPPORTED: &str = "Listing parse candidates is not supported";
+
+    /// Error message for languages that cannot parse a prefix of the input
+    pub const ERR_PREFIX_UNSUPPORTED: &str = "Prefix parsing is not supported";
+
+    /// Error message for input that does not start with a time expression
+    pub const ERR_NO_TIME_PREFIX: &str = "Input does not start with a time expression";
+
+    /// Error message for duration overflow
+    pub const ERR_DURATION_OVERFLOW: &str = "Duration overflow";
+
     /// Format error message for invalid date with components
     #[must_use]
     pub fn format_invalid_date(year: u16, month: u8, day: u8) -> String {
@@ -592,10 +2272,17 @@ pub mod time_utils {
     //! Time conversion and calculation utilities
 
     use crate::{
-        Meridiem, Timezone, WeekdayModifier,
-        constants::{SECONDS_PER_HOUR, SECONDS_PER_MINUTE},
+        AbsoluteTime, Frequency, Meridiem, MonthDay, PeriodUnit, Recurrence, RecurrenceEnd,
+        StandardDate, TimeUnit, Timezone, WeekStart, WeekdayModifier, WeekdaySet, YearPolicy,
+        constants::{
+            DAYS_PER_WEEK, MONTHS_PER_QUARTER, MONTHS_PER_YEAR, SECONDS_PER_DAY, SECONDS_PER_HOUR,
+            SECONDS_PER_MINUTE, SECONDS_PER_WEEK,
+        },
     };
 
+    /// Days in 400 years, after which the Gregorian calendar repeats
+    pub(crate) const DAYS_PER_CALENDAR_CYCLE: i64 = 146_097;
+
     /// Convert 12-hour time format to 24-hour format
     ///
     /// # Examples
@@ -647,15 +2334,19 @@ pub mod time_utils {
     /// Check whether the date components form a real calendar date.
     #[must_use]
     pub fn is_valid_calendar_date(year: u16, month: u8, day: u8) -> bool {
-        let days_in_month = match month {
+        (1..=days_in_month(year, month)).contains(&day)
+    }
+
+    /// Number of days in a month, 0 for an invalid month
+    #[must_use]
+    pub(crate) fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
             1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
             4 | 6 | 9 | 11 => 30,
             2 if is_leap_year(year) => 29,
             2 => 28,
-            _ => return false,
-        };
-
-        (1..=days_in_month).contains(&day)
+            _ => 0,
+        }
     }
 
     /// Check whether the time components form a valid 24-hour clock time.
@@ -673,70 +2364,487 @@ pub mod time_utils {
         }
     }
 
-    /// Check whether a timezone offset is in the supported UTC-12:00..=UTC+14:00 range.
+    /// Check whether a timezone offset is in the supported UTC-12:00..=UTC+14:00 range.
+    #[must_use]
+    pub fn is_valid_timezone_offset(offset: Timezone) -> bool {
+        match offset {
+            Timezone::Utc => true,
+            Timezone::Offset { hours, minutes } => {
+                minutes <= 59
+                    && match hours {
+                        -12 => minutes == 0,
+                        -11..=13 => true,
+                        14 => minutes == 0,
+                        _ => false,
+                    }
+            }
+        }
+    }
+
+    /// English ordinal suffix for a day of the month
+    ///
+    /// # Examples
+    /// ```
+    /// use temps_core::time_utils::english_ordinal_suffix;
+    ///
+    /// assert_eq!(english_ordinal_suffix(1), "st");
+    /// assert_eq!(english_ordinal_suffix(12), "th");
+    /// assert_eq!(english_ordinal_suffix(23), "rd");
+    /// ```
+    #[must_use]
+    pub fn english_ordinal_suffix(day: u8) -> &'static str {
+        match (day % 10, day % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        }
+    }
+
+    /// The average length of a time unit in seconds
+    ///
+    /// Months and years use the average Gregorian month and year, so this is
+    /// an estimate for calendar arithmetic rather than an exact length.
+    ///
+    /// # Examples
+    /// ```
+    /// use temps_core::{TimeUnit, time_utils::nominal_seconds};
+    ///
+    /// assert_eq!(nominal_seconds(TimeUnit::Hour), 3600);
+    /// assert_eq!(nominal_seconds(TimeUnit::Year), 365 * 86_400 + 20_952);
+    /// ```
+    #[must_use]
+    pub fn nominal_seconds(unit: TimeUnit) -> i64 {
+        // 146,097 days per 400 years
+        const SECONDS_PER_YEAR: i64 = DAYS_PER_CALENDAR_CYCLE * SECONDS_PER_DAY / 400;
+        match unit {
+            TimeUnit::Second => 1,
+            TimeUnit::Minute => i64::from(SECONDS_PER_MINUTE),
+            TimeUnit::Hour => i64::from(SECONDS_PER_HOUR),
+            TimeUnit::Day => SECONDS_PER_DAY,
+            TimeUnit::Week => SECONDS_PER_WEEK,
+            TimeUnit::Month => SECONDS_PER_YEAR / i64::from(MONTHS_PER_YEAR),
+            TimeUnit::Year => SECONDS_PER_YEAR,
+        }
+    }
+
+    /// Convert a weekday offset from Monday to an offset from the first day of the week
+    ///
+    /// # Examples
+    /// ```
+    /// use temps_core::{WeekStart, time_utils::days_from_week_start};
+    ///
+    /// // Tuesday
+    /// assert_eq!(days_from_week_start(1, WeekStart::Monday), 1);
+    /// assert_eq!(days_from_week_start(1, WeekStart::Sunday), 2);
+    /// assert_eq!(days_from_week_start(1, WeekStart::Saturday), 3);
+    /// ```
+    #[must_use]
+    pub fn days_from_week_start(monday_offset: i64, week_start: WeekStart) -> i64 {
+        let start = match week_start {
+            WeekStart::Monday => 0,
+            WeekStart::Saturday => 5,
+            WeekStart::Sunday => 6,
+        };
+        (monday_offset - start).rem_euclid(i64::from(DAYS_PER_WEEK))
+    }
+
+    /// Calculate the day offset for weekday calculations
+    ///
+    /// Returns the number of days to add/subtract to reach the target weekday.
+    /// The next or last occurrence of a weekday does not depend on the day
+    /// weeks start on, so offsets here stay Monday-based;
+    /// [`days_from_week_start`] is the entry point for week-start-aware
+    /// offsets, as used by [`calculate_week_weekday_offset`].
+    ///
+    /// # Arguments
+    /// * `current_day_offset` - Current weekday as offset from Monday (0-6)
+    /// * `target_day_offset` - Target weekday as offset from Monday (0-6)
+    /// * `modifier` - Whether to get next, last, or closest occurrence
+    #[must_use]
+    pub fn calculate_weekday_offset(
+        current_day_offset: i64,
+        target_day_offset: i64,
+        modifier: Option<WeekdayModifier>,
+    ) -> i64 {
+        let days_diff = target_day_offset - current_day_offset;
+
+        match modifier {
+            None => {
+                // Get the next occurrence (including today if it matches)
+                if days_diff >= 0 {
+                    days_diff
+                } else {
+                    7 + days_diff
+                }
+            }
+            Some(WeekdayModifier::Next) => {
+                // Next occurrence (not including today)
+                if days_diff > 0 {
+                    days_diff
+                } else {
+                    7 + days_diff
+                }
+            }
+            Some(WeekdayModifier::Last) => {
+                // Previous occurrence (not including today)
+                if days_diff < 0 {
+                    days_diff
+                } else {
+                    days_diff - 7
+                }
+            }
+        }
+    }
+
+    /// Calculate the day offset to a weekday in a week relative to the current one
+    ///
+    /// Weeks start on the day both offsets are counted from (see
+    /// [`days_from_week_start`]). Returns the number of days to add/subtract
+    /// to reach the target weekday.
+    ///
+    /// # Arguments
+    /// * `current_day_offset` - Current weekday as offset from the first day of the week (0-6)
+    /// * `target_day_offset` - Target weekday as offset from the first day of the week (0-6)
+    /// * `week_offset` - Weeks from the current week (e.g., 1 for "next week")
+    ///
+    /// # Examples
+    /// ```
+    /// use temps_core::{WeekStart, time_utils::{calculate_week_weekday_offset, days_from_week_start}};
+    ///
+    /// // From a Wednesday, Monday of next week is 5 days away
+    /// assert_eq!(calculate_week_weekday_offset(2, 0, 1), 5);
+    /// // and Friday of last week 5 days ago
+    /// assert_eq!(calculate_week_weekday_offset(2, 4, -1), -5);
+    ///
+    /// // With weeks starting on Sunday, Sunday of this week is 3 days ago
+    /// let wednesday = days_from_week_start(2, WeekStart::Sunday);
+    /// let sunday = days_from_week_start(6, WeekStart::Sunday);
+    /// assert_eq!(calculate_week_weekday_offset(wednesday, sunday, 0), -3);
+    /// ```
+    #[must_use]
+    pub fn calculate_week_weekday_offset(
+        current_day_offset: i64,
+        target_day_offset: i64,
+        week_offset: i64,
+    ) -> i64 {
+        week_offset * i64::from(DAYS_PER_WEEK) + target_day_offset - current_day_offset
+    }
+
+    /// Calculate the first months of a calendar period and of the period after it
+    ///
+    /// Returns `(start, end)` as `(year, month)` pairs for the month, quarter
+    /// or year `offset` periods away from the one containing `year`/`month`.
+    /// Returns `None` for days and weeks, which are not made of whole months,
+    /// and on overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use temps_core::{PeriodUnit, time_utils::period_month_bounds};
+    ///
+    /// // Last quarter, seen from May 2024
+    /// assert_eq!(
+    ///     period_month_bounds(2024, 5, PeriodUnit::Quarter, -1),
+    ///     Some(((2024, 1), (2024, 4)))
+    /// );
+    /// // Next month, seen from December 2024
+    /// assert_eq!(
+    ///     period_month_bounds(2024, 12, PeriodUnit::Month, 1),
+    ///     Some(((2025, 1), (2025, 2)))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn period_month_bounds(
+        year: i32,
+        month: u8,
+        unit: PeriodUnit,
+        offset: i64,
+    ) -> Option<((i32, u8), (i32, u8))> {
+        let months_per_period = i64::from(match unit {
+            PeriodUnit::Day | PeriodUnit::Week => return None,
+            PeriodUnit::Month => 1,
+            PeriodUnit::Quarter => MONTHS_PER_QUARTER,
+            PeriodUnit::Year => MONTHS_PER_YEAR,
+        });
+
+        let months_per_year = i64::from(MONTHS_PER_YEAR);
+        let current = i64::from(year) * months_per_year + i64::from(month) - 1;
+        let start = (current - current.rem_euclid(months_per_period))
+            .checked_add(offset.checked_mul(months_per_period)?)?;
+        let end = start.checked_add(months_per_period)?;
+
+        let to_year_month = |index: i64| {
+            let year = i32::try_from(index.div_euclid(months_per_year)).ok()?;
+            let month = u8::try_from(index.rem_euclid(months_per_year) + 1).ok()?;
+            Some((year, month))
+        };
+        Some((to_year_month(start)?, to_year_month(end)?))
+    }
+
+    /// Calculate the dates a daily or longer recurrence falls on
+    ///
+    /// Dates come in order, starting with the first day of the period that
+    /// contains `today`, so the first few may lie before `today`. Without
+    /// weekdays, the recurrence falls on `today`'s weekday, day of the month
+    /// or date; months and years without that day are skipped. Iteration
+    /// stops after the `Until` date of the recurrence, or the next such day of
+    /// the year on or after `today`, and once a period starts past the dates
+    /// that can be represented. A `Count` is left to the caller, who knows
+    /// which dates are still ahead.
+    /// Minutely and hourly recurrences yield no dates.
+    ///
+    /// # Examples
+    /// ```
+    /// use temps_core::{
+    ///     Frequency, Recurrence, StandardDate, WeekStart, WeekdaySet,
+    ///     time_utils::recurrence_dates,
+    /// };
+    ///
+    /// let today = StandardDate { day: 31, month: 1, year: 2024 };
+    /// let monthly = Recurrence {
+    ///     frequency: Frequency::Monthly,
+    ///     interval: 1,
+    ///     weekdays: WeekdaySet::empty(),
+    ///     time: None,
+    ///     end: None,
+    /// };
+    ///
+    /// // February and April have no 31st
+    /// let months: Vec<u8> = recurrence_dates(today, monthly, WeekStart::Monday)
+    ///     .take(3)
+    ///     .map(|date| date.month)
+    ///     .collect();
+    /// assert_eq!(months, [1, 3, 5]);
+    /// ```
+    pub fn recurrence_dates(
+        today: StandardDate,
+        recurrence: Recurrence,
+        week_start: WeekStart,
+    ) -> impl Iterator<Item = StandardDate> {
+        let interval = i64::from(recurrence.interval.max(1));
+        let weekdays = recurrence.weekdays;
+        let today_days = days_from_civil(today);
+        let today_offset = monday_offset(today_days);
+
+        let on_weekday = move |date: &StandardDate| {
+            let offset = monday_offset(days_from_civil(*date));
+            if weekdays.is_empty() {
+                offset == today_offset
+            } else {
+                weekdays.contains(WeekdaySet::DAYS[offset as usize])
+            }
+        };
+        let valid = |date: &StandardDate| is_valid_calendar_date(date.year, date.month, date.day);
+        // A period starting past the supported dates ends the iteration
+        let days = |first: i64, count: i64| {
+            civil_from_days(first)?;
+            Some((first..first + count).filter_map(civil_from_days))
+        };
+        let months = move |year: u16, months: std::ops::RangeInclusive<u8>| {
+            months.flat_map(move |month| (1..=31).map(move |day| StandardDate { day, month, year }))
+        };
+
+        let period = move |index: i64| -> Option<Vec<StandardDate>> {
+            let step = index.checked_mul(interval)?;
+            let dates = match recurrence.frequency {
+                Frequency::Minutely | Frequency::Hourly => return None,
+                Frequency::Daily => days(today_days.checked_add(step)?, 1)?
+                    .filter(|date| weekdays.is_empty() || on_weekday(date))
+                    .collect(),
+                Frequency::Weekly => {
+                    let week = today_days - days_from_week_start(today_offset, week_start);
+                    let first = week.checked_add(step.checked_mul(i64::from(DAYS_PER_WEEK))?)?;
+                    days(first, i64::from(DAYS_PER_WEEK))?
+                        .filter(on_weekday)
+                        .collect()
+                }
+                Frequency::Monthly => {
+                    let months_per_year = i64::from(MONTHS_PER_YEAR);
+                    let index = (i64::from(today.year) * months_per_year + i64::from(today.month)
+                        - 1)
+                    .checked_add(step)?;
+                    let year = u16::try_from(index.div_euclid(months_per_year)).ok()?;
+                    let month = (index.rem_euclid(months_per_year) + 1) as u8;
+                    if weekdays.is_empty() {
+                        let date = StandardDate {
+                            year,
+                            month,
+                            ..today
+                        };
+                        valid(&date).then_some(date).into_iter().collect()
+                    } else {
+                        months(year, month..=month)
+                            .filter(|date| valid(date) && on_weekday(date))
+                            .collect()
+                    }
+                }
+                Frequency::Yearly => {
+                    let year = u16::try_from(i64::from(today.year).checked_add(step)?).ok()?;
+                    if weekdays.is_empty() {
+                        let date = StandardDate { year, ..today };
+                        valid(&date).then_some(date).into_iter().collect()
+                    } else {
+                        months(year, 1..=12)
+                            .filter(|date| valid(date) && on_weekday(date))
+                            .collect()
+                    }
+                }
+            };
+            Some(dates)
+        };
+
+        let until = match recurrence.end {
+            Some(RecurrenceEnd::Until(until)) => Some((until.year, until.month, until.day)),
+            Some(RecurrenceEnd::UntilMonthDay(month_day)) => {
+                resolve_month_day(today, month_day, YearPolicy::Next)
+                    .map(|until| (until.year, until.month, until.day))
+            }
+            _ => None,
+        };
+        (0..).map_while(period).flatten().take_while(move |date| {
+            until.is_none_or(|until| (date.year, date.month, date.day) <= until)
+        })
+    }
+
+    /// Convert a time with a fixed offset to UTC, `None` if out of range
+    pub(crate) fn to_utc(time: AbsoluteTime) -> Option<AbsoluteTime> {
+        let Some(Timezone::Offset { hours, minutes }) = time.timezone else {
+            return Some(time);
+        };
+        let date = StandardDate {
+            day: time.day,
+            month: time.month,
+            year: time.year,
+        };
+        let seconds = days_from_civil(date) * SECONDS_PER_DAY
+            + i64::from(time.hour.unwrap_or(0)) * i64::from(SECONDS_PER_HOUR)
+            + i64::from(time.minute.unwrap_or(0)) * i64::from(SECONDS_PER_MINUTE)
+            + i64::from(time.second.unwrap_or(0))
+            - i64::from(calculate_timezone_offset_seconds(hours, minutes));
+        let date = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY))?;
+        let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+        Some(AbsoluteTime {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: Some((second_of_day / i64::from(SECONDS_PER_HOUR)) as u8),
+            minute: Some((second_of_day % i64::from(SECONDS_PER_HOUR) / 60) as u8),
+            second: Some((second_of_day % 60) as u8),
+            timezone: Some(Timezone::Utc),
+            ..time
+        })
+    }
+
     #[must_use]
-    pub fn is_valid_timezone_offset(offset: Timezone) -> bool {
-        match offset {
-            Timezone::Utc => true,
-            Timezone::Offset { hours, minutes } => {
-                minutes <= 59
-                    && match hours {
-                        -12 => minutes == 0,
-                        -11..=13 => true,
-                        14 => minutes == 0,
-                        _ => false,
-                    }
-            }
-        }
+    pub(crate) fn is_leap_year(year: u16) -> bool {
+        year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
     }
 
-    /// Calculate the day offset for weekday calculations
+    /// Pick the year of a date without one, relative to `today`
     ///
-    /// Returns the number of days to add/subtract to reach the target weekday
+    /// February 29 resolves to the closest leap year in the policy's direction.
+    /// Returns `None` if the day and month never form a valid date or the
+    /// resulting year is out of range.
     ///
-    /// # Arguments
-    /// * `current_day_offset` - Current weekday as offset from Monday (0-6)
-    /// * `target_day_offset` - Target weekday as offset from Monday (0-6)
-    /// * `modifier` - Whether to get next, last, or closest occurrence
+    /// # Examples
+    /// ```
+    /// use temps_core::{MonthDay, StandardDate, YearPolicy, time_utils::resolve_month_day};
+    ///
+    /// let today = StandardDate { day: 1, month: 3, year: 2025 };
+    /// let leap_day = MonthDay { day: 29, month: 2 };
+    ///
+    /// let next = resolve_month_day(today, leap_day, YearPolicy::Next).unwrap();
+    /// assert_eq!(next, StandardDate { day: 29, month: 2, year: 2028 });
+    ///
+    /// let nearest = resolve_month_day(today, leap_day, YearPolicy::Nearest).unwrap();
+    /// assert_eq!(nearest.year, 2024);
+    /// ```
     #[must_use]
-    pub fn calculate_weekday_offset(
-        current_day_offset: i64,
-        target_day_offset: i64,
-        modifier: Option<WeekdayModifier>,
-    ) -> i64 {
-        let days_diff = target_day_offset - current_day_offset;
+    pub fn resolve_month_day(
+        today: StandardDate,
+        date: MonthDay,
+        policy: YearPolicy,
+    ) -> Option<StandardDate> {
+        let with_year = |year: u16| StandardDate {
+            day: date.day,
+            month: date.month,
+            year,
+        };
+        let occurs_in = |year: u16| is_valid_calendar_date(year, date.month, date.day);
+        let this_year_offset = (date.month, date.day).cmp(&(today.month, today.day));
+
+        // A valid day and month occurs at least once every eight years
+        let next = || {
+            (0..=8)
+                .filter_map(|offset| today.year.checked_add(offset))
+                .filter(|&year| year > today.year || this_year_offset.is_ge())
+                .find(|&year| occurs_in(year))
+                .map(with_year)
+        };
+        let previous = || {
+            (0..=8)
+                .filter_map(|offset| today.year.checked_sub(offset))
+                .filter(|&year| year < today.year || this_year_offset.is_le())
+                .find(|&year| occurs_in(year))
+                .map(with_year)
+        };
 
-        match modifier {
-            None => {
-                // Get the next occurrence (including today if it matches)
-                if days_diff >= 0 {
-                    days_diff
-                } else {
-                    7 + days_diff
-                }
-            }
-            Some(WeekdayModifier::Next) => {
-                // Next occurrence (not including today)
-                if days_diff > 0 {
-                    days_diff
-                } else {
-                    7 + days_diff
-                }
-            }
-            Some(WeekdayModifier::Last) => {
-                // Previous occurrence (not including today)
-                if days_diff < 0 {
-                    days_diff
-                } else {
-                    days_diff - 7
+        match policy {
+            YearPolicy::Next => next(),
+            YearPolicy::Previous => previous(),
+            YearPolicy::Nearest => match (next(), previous()) {
+                (Some(next), Some(previous)) => {
+                    let today = days_from_civil(today);
+                    if days_from_civil(next) - today <= today - days_from_civil(previous) {
+                        Some(next)
+                    } else {
+                        Some(previous)
+                    }
                 }
-            }
+                (next, previous) => next.or(previous),
+            },
         }
     }
 
-    #[must_use]
-    fn is_leap_year(year: u16) -> bool {
-        year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+    /// Days since 1970-01-01 in the proleptic Gregorian calendar
+    pub(crate) fn days_from_civil(date: StandardDate) -> i64 {
+        let (month, day) = (i64::from(date.month), i64::from(date.day));
+        let year = i64::from(date.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Inverse of [`days_from_civil`], `None` outside the range of `StandardDate`
+    pub(crate) fn civil_from_days(days: i64) -> Option<StandardDate> {
+        let days = days.checked_add(719_468)?;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Some(StandardDate {
+            day: day as u8,
+            month: month as u8,
+            year: u16::try_from(year).ok()?,
+        })
+    }
+
+    /// Weekday of a day count from [`days_from_civil`] as offset from Monday
+    pub(crate) fn monday_offset(days: i64) -> i64 {
+        // 1970-01-01 was a Thursday
+        (days + 3).rem_euclid(i64::from(DAYS_PER_WEEK))
     }
 }
 
@@ -750,6 +2858,11 @@ pub mod time_utils {
 pub mod common {
 
     use super::*;
+    use crate::spanned::{alt, component, opt};
+    use winnow::{
+        ascii::multispace0,
+        combinator::{delimited, not, preceded},
+    };
 
     /// Parse a sequence of digits as an i64.
     ///
@@ -762,6 +2875,325 @@ pub mod common {
         digit1.try_map(|s: &str| s.parse::<i64>()).parse_next(input)
     }
 
+    /// Run each rule on the whole input and collect the interpretations.
+    ///
+    /// Rules are tried in order of preference; see [`expand_candidates`] for
+    /// the post-processing.
+    pub fn collect_candidates(
+        input: &str,
+        rules: impl IntoIterator<Item = Rule>,
+        mut parse_rule: impl FnMut(&mut &str, Rule) -> winnow::Result<TimeExpression>,
+    ) -> Vec<Candidate> {
+        let candidates = rules
+            .into_iter()
+            .filter_map(|rule| {
+                delimited(
+                    multispace0,
+                    |input: &mut &str| parse_rule(input, rule),
+                    multispace0,
+                )
+                .parse(input)
+                .ok()
+                .map(|expression| Candidate { expression, rule })
+            })
+            .collect();
+
+        expand_candidates(candidates)
+    }
+
+    /// Run each rule on the start of the input and keep the longest match.
+    ///
+    /// Leading whitespace is skipped. A match must end at a word boundary, so
+    /// "now" is not found in "nowhere"; on a tie the earlier rule wins.
+    /// Returns the expression and the unconsumed rest of the input.
+    pub fn parse_longest_prefix<'i>(
+        input: &'i str,
+        rules: impl IntoIterator<Item = Rule>,
+        mut parse_rule: impl FnMut(&mut &str, Rule) -> winnow::Result<TimeExpression>,
+    ) -> Result<(TimeExpression, &'i str)> {
+        let mut longest: Option<(TimeExpression, &'i str)> = None;
+        for rule in rules {
+            let mut rest = input;
+            let Ok(expression) = preceded(multispace0, |input: &mut &str| parse_rule(input, rule))
+                .parse_next(&mut rest)
+            else {
+                continue;
+            };
+            let at_boundary = !rest.starts_with(char::is_alphanumeric);
+            let is_longer = longest
+                .as_ref()
+                .is_none_or(|(_, longest_rest)| rest.len() < longest_rest.len());
+            if at_boundary && is_longer {
+                longest = Some((expression, rest));
+            }
+        }
+        longest.ok_or_else(|| {
+            TempsError::parse_error_with_position(errors::ERR_NO_TIME_PREFIX, input, 0)
+        })
+    }
+
+    /// Remove duplicate interpretations and add the alternative readings of weekdays.
+    ///
+    /// A bare weekday ("Monday") may also mean the one in the current or the
+    /// next week, and "next Monday" may mean Monday of next week.
+    #[must_use]
+    pub fn expand_candidates(candidates: Vec<Candidate>) -> Vec<Candidate> {
+        let mut expanded: Vec<Candidate> = Vec::with_capacity(candidates.len());
+        let mut push = |candidate: Candidate| {
+            if !expanded
+                .iter()
+                .any(|existing| existing.expression == candidate.expression)
+            {
+                expanded.push(candidate);
+            }
+        };
+
+        for candidate in candidates {
+            push(candidate);
+
+            let day = match candidate.expression {
+                TimeExpression::Day(day) => day,
+                TimeExpression::DayTime(day_time) => day_time.day,
+                _ => continue,
+            };
+            let with_day = |day| match candidate.expression {
+                TimeExpression::DayTime(day_time) => {
+                    TimeExpression::DayTime(DayTime { day, ..day_time })
+                }
+                _ => TimeExpression::Day(day),
+            };
+            let DayReference::Weekday {
+                day: weekday,
+                modifier,
+            } = day
+            else {
+                continue;
+            };
+            let in_week = |week_offset| DayReference::WeekdayInWeek {
+                day: weekday,
+                week_offset,
+            };
+
+            let alternatives: &[(i64, Rule)] = match modifier {
+                None => &[(0, Rule::WeekdayThisWeek), (1, Rule::WeekdayNextWeek)],
+                Some(WeekdayModifier::Next) => &[(1, Rule::WeekdayNextWeek)],
+                Some(WeekdayModifier::Last) => &[],
+            };
+            for &(week_offset, rule) in alternatives {
+                push(Candidate {
+                    expression: with_day(in_week(week_offset)),
+                    rule,
+                });
+            }
+        }
+
+        expanded
+    }
+
+    /// Succeed without consuming input if the next character is not a letter.
+    ///
+    /// Used after abbreviations that are also prefixes of other words.
+    pub fn word_boundary(input: &mut &str) -> winnow::Result<()> {
+        not(one_of(|c: char| c.is_alphabetic())).parse_next(input)
+    }
+
+    /// Build an interval expression from two point expressions.
+    ///
+    /// Returns `None` if either side is itself an interval.
+    pub fn interval_expression(
+        start: TimeExpression,
+        end: TimeExpression,
+    ) -> Option<TimeExpression> {
+        Some(TimeExpression::Interval(Interval {
+            start: Endpoint::try_from(start).ok()?,
+            end: Endpoint::try_from(end).ok()?,
+        }))
+    }
+
+    /// Build a date expression, with or without a year.
+    ///
+    /// Returns `None` if the components do not form a valid date. Without a
+    /// year, February 29 is accepted.
+    pub fn date_expression(day: u8, month: u8, year: Option<u16>) -> Option<TimeExpression> {
+        match year {
+            Some(year) => time_utils::is_valid_calendar_date(year, month, day)
+                .then_some(TimeExpression::Date(StandardDate { day, month, year })),
+            // 2000 is a leap year, so this accepts February 29
+            None => time_utils::is_valid_calendar_date(2000, month, day)
+                .then_some(TimeExpression::MonthDay(MonthDay { day, month })),
+        }
+    }
+
+    /// Build a relative expression from `(amount, unit)` pairs.
+    ///
+    /// A single pair yields a [`TimeExpression::Relative`], several pairs a
+    /// [`TimeExpression::RelativeDuration`]. Returns `None` if a unit is
+    /// repeated (e.g., "2 hours 3 hours").
+    pub fn relative_expression(
+        components: &[(i64, TimeUnit)],
+        direction: Direction,
+    ) -> Option<TimeExpression> {
+        match components {
+            [] => None,
+            &[(amount, unit)] => Some(TimeExpression::Relative(RelativeTime {
+                amount,
+                unit,
+                direction,
+            })),
+            _ => {
+                let mut seen = Vec::with_capacity(components.len());
+                let mut duration = Duration::default();
+                for &(amount, unit) in components {
+                    if seen.contains(&unit) {
+                        return None;
+                    }
+                    seen.push(unit);
+                    duration = duration.with(unit, amount);
+                }
+                Some(TimeExpression::RelativeDuration(RelativeDuration {
+                    duration,
+                    direction,
+                }))
+            }
+        }
+    }
+
+    /// Read a bare duration as that long from now, as if "in" came before it.
+    pub fn future_expression(duration: Duration) -> TimeExpression {
+        let components: Vec<_> = duration
+            .components()
+            .map(|(unit, amount)| (amount, unit))
+            .collect();
+        (duration.nanoseconds == 0)
+            .then(|| relative_expression(&components, Direction::Future))
+            .flatten()
+            .unwrap_or(TimeExpression::RelativeDuration(RelativeDuration {
+                duration,
+                direction: Direction::Future,
+            }))
+    }
+
+    /// The error for expressions a language has no words for.
+    pub fn unrepresentable() -> TempsError {
+        TempsError::unsupported_operation(errors::ERR_FORMAT_UNREPRESENTABLE)
+    }
+
+    /// Write weekdays as "Monday, Wednesday and Friday", with `name` giving
+    /// the word for each day and `and` the last separator.
+    pub fn format_weekday_list(
+        weekdays: WeekdaySet,
+        name: impl Fn(Weekday) -> &'static str,
+        and: &str,
+    ) -> String {
+        let names: Vec<_> = weekdays.iter().map(name).collect();
+        match names.split_last() {
+            Some((last, [])) => (*last).to_string(),
+            Some((last, rest)) => format!("{} {and} {last}", rest.join(", ")),
+            None => String::new(),
+        }
+    }
+
+    /// Write an absolute time in ISO 8601 form, as [`parse_iso_datetime`] reads it.
+    pub fn format_iso_datetime(time: &AbsoluteTime) -> String {
+        let mut text = format!("{:04}-{:02}-{:02}", time.year, time.month, time.day);
+        let Some(hour) = time.hour else {
+            return text;
+        };
+        text += &format!("T{hour:02}:{:02}", time.minute.unwrap_or(0));
+        if let Some(second) = time.second {
+            text += &format!(":{second:02}");
+            if let Some(nanosecond) = time.nanosecond {
+                let fraction = format!("{nanosecond:09}");
+                let fraction = fraction.trim_end_matches('0');
+                text += &format!(".{}", if fraction.is_empty() { "0" } else { fraction });
+            }
+        }
+        match time.timezone {
+            Some(Timezone::Utc) => text.push('Z'),
+            Some(Timezone::Offset { hours, minutes }) => {
+                let sign = if hours < 0 { '-' } else { '+' };
+                text += &format!("{sign}{:02}:{minutes:02}", hours.unsigned_abs());
+            }
+            None => {}
+        }
+        text
+    }
+
+    /// Write the units of a duration as "2 hours 30 minutes", largest first,
+    /// with `name` giving the word for each amount.
+    ///
+    /// Returns `None` if every unit is zero.
+    pub fn format_components(
+        duration: &Duration,
+        name: impl Fn(TimeUnit, i64) -> &'static str,
+    ) -> Option<String> {
+        let components: Vec<_> = duration
+            .components()
+            .map(|(unit, amount)| format!("{amount} {}", name(unit, amount)))
+            .collect();
+        (!components.is_empty()).then(|| components.join(" "))
+    }
+
+    /// Build a duration from `(amount, fraction digits, unit)` triples.
+    ///
+    /// Fractions are carried into the next smaller unit, so "1.5 days" becomes
+    /// 1 day and 12 hours and "1.5 seconds" 1 second and 500 ms. Returns `None`
+    /// if a unit is repeated, a fraction cannot be expressed exactly (e.g.,
+    /// "1.5 months") or the amounts overflow.
+    pub fn duration_from_components(
+        components: &[(i64, Option<&str>, TimeUnit)],
+    ) -> Option<Duration> {
+        // Smaller unit and how many of it make up one of the larger unit
+        let smaller = |unit| match unit {
+            TimeUnit::Year => Some((TimeUnit::Month, 12)),
+            TimeUnit::Month | TimeUnit::Second => None,
+            TimeUnit::Week => Some((TimeUnit::Day, 7)),
+            TimeUnit::Day => Some((TimeUnit::Hour, 24)),
+            TimeUnit::Hour => Some((TimeUnit::Minute, 60)),
+            TimeUnit::Minute => Some((TimeUnit::Second, 60)),
+        };
+
+        let mut seen = Vec::with_capacity(components.len());
+        let mut duration = Duration::default();
+        for &(amount, fraction, unit) in components {
+            if seen.contains(&unit) {
+                return None;
+            }
+            seen.push(unit);
+            duration = duration.with(unit, duration.get(unit).checked_add(amount)?);
+
+            // Only the first nine digits matter, like ISO fractional seconds
+            let digits = fraction.map_or("", |digits| &digits[..digits.len().min(9)]);
+            let denominator = 10_i64.pow(digits.len() as u32);
+            let mut numerator = if digits.is_empty() {
+                0
+            } else {
+                digits.parse::<i64>().ok()?
+            };
+            let mut unit = unit;
+
+            while numerator != 0 {
+                match smaller(unit) {
+                    Some((next, factor)) => {
+                        let scaled = numerator * factor;
+                        duration = duration
+                            .with(next, duration.get(next).checked_add(scaled / denominator)?);
+                        numerator = scaled % denominator;
+                        unit = next;
+                    }
+                    None if unit == TimeUnit::Second => {
+                        duration.nanoseconds +=
+                            numerator * constants::NANOS_PER_SECOND / denominator;
+                        numerator = 0;
+                    }
+                    None => return None,
+                }
+            }
+        }
+
+        Some(duration)
+    }
+
     /// Parse ISO 8601 datetime format.
     ///
     /// Supports various ISO datetime formats:
@@ -779,45 +3211,54 @@ pub mod common {
     /// ```
     pub fn parse_iso_datetime(input: &mut &str) -> winnow::Result<TimeExpression> {
         // Parse date components
-        let (year, month, day) = (
-            parse_four_digit_number,
-            '-',
-            parse_two_digit_number,
-            '-',
-            parse_two_digit_number,
+        let (year, month, day) = component(
+            Component::Date,
+            (
+                parse_four_digit_number,
+                '-',
+                parse_two_digit_number,
+                '-',
+                parse_two_digit_number,
+            )
+                .verify_map(|(year, _, month, _, day)| {
+                    time_utils::is_valid_calendar_date(year, month, day)
+                        .then_some((year, month, day))
+                }),
         )
-            .verify_map(|(year, _, month, _, day)| {
-                time_utils::is_valid_calendar_date(year, month, day).then_some((year, month, day))
-            })
-            .parse_next(input)?;
+        .parse_next(input)?;
 
         // Parse optional time components
         let time_part = opt((
             one_of(['T', ' ']),
-            parse_two_digit_number, // hour
-            ':',
-            parse_two_digit_number, // minute
-            opt((
-                ':',
-                parse_two_digit_number, // second
-                opt((
-                    '.',
-                    digit1.try_map(|s: &str| {
-                        // Convert fractional seconds to nanoseconds
-                        let fraction = if s.len() > 9 { &s[..9] } else { s };
-
-                        // Parse the fraction and multiply by appropriate power of 10
-                        let parsed = fraction.parse::<u32>()?;
-                        let fraction_len = u32::try_from(fraction.len())
-                            .expect("fraction length is capped at 9 digits");
-                        let multiplier = 10_u32.pow(9 - fraction_len);
-                        Ok::<u32, std::num::ParseIntError>(parsed * multiplier)
-                    }),
-                )),
-            )),
-            opt(parse_timezone),
+            component(
+                Component::Time,
+                (
+                    parse_two_digit_number, // hour
+                    ':',
+                    parse_two_digit_number, // minute
+                    opt((
+                        ':',
+                        parse_two_digit_number, // second
+                        opt((
+                            '.',
+                            digit1.try_map(|s: &str| {
+                                // Convert fractional seconds to nanoseconds
+                                let fraction = if s.len() > 9 { &s[..9] } else { s };
+
+                                // Parse the fraction and multiply by appropriate power of 10
+                                let parsed = fraction.parse::<u32>()?;
+                                let fraction_len = u32::try_from(fraction.len())
+                                    .expect("fraction length is capped at 9 digits");
+                                let multiplier = 10_u32.pow(9 - fraction_len);
+                                Ok::<u32, std::num::ParseIntError>(parsed * multiplier)
+                            }),
+                        )),
+                    )),
+                ),
+            ),
+            opt(component(Component::Timezone, parse_timezone)),
         )
-            .verify_map(|(_, h, _, m, sec_part, tz)| {
+            .verify_map(|(_, (h, _, m, sec_part), tz)| {
                 let second = sec_part.map_or(0, |(_, s, _)| s);
                 time_utils::is_valid_24_hour_time(h, m, second).then_some((h, m, sec_part, tz))
             }))
@@ -854,6 +3295,59 @@ pub mod common {
         }))
     }
 
+    /// Parse an ISO 8601 duration such as `P1Y2M10DT2H30M`, `P2W` or `PT1.5S`.
+    ///
+    /// Any component may have a fraction, which is carried into smaller
+    /// units like in [`duration_from_components`].
+    pub fn parse_iso_duration(input: &mut &str) -> winnow::Result<Duration> {
+        fn component<'i>(
+            designator: char,
+            unit: TimeUnit,
+        ) -> impl FnMut(&mut &'i str) -> winnow::Result<(i64, Option<&'i str>, TimeUnit)> {
+            move |input| {
+                (
+                    parse_digit_number,
+                    opt(preceded(one_of(['.', ',']), digit1)),
+                    designator,
+                )
+                    .map(|(amount, fraction, _)| (amount, fraction, unit))
+                    .parse_next(input)
+            }
+        }
+
+        (
+            'P',
+            opt(component('Y', TimeUnit::Year)),
+            opt(component('M', TimeUnit::Month)),
+            opt(component('W', TimeUnit::Week)),
+            opt(component('D', TimeUnit::Day)),
+            opt(preceded(
+                'T',
+                (
+                    opt(component('H', TimeUnit::Hour)),
+                    opt(component('M', TimeUnit::Minute)),
+                    opt(component('S', TimeUnit::Second)),
+                ),
+            )),
+        )
+            .verify_map(|(_, years, months, weeks, days, time)| {
+                // "T" must be followed by at least one time component
+                let (hours, minutes, seconds) = match time {
+                    Some((None, None, None)) => return None,
+                    time => time.unwrap_or_default(),
+                };
+                let components: Vec<_> = [years, months, weeks, days, hours, minutes, seconds]
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                if components.is_empty() {
+                    return None;
+                }
+                duration_from_components(&components)
+            })
+            .parse_next(input)
+    }
+
     /// Parse timezone specification.
     ///
     /// Supports:
@@ -920,7 +3414,7 @@ pub mod language {
     /// English language parser.
     ///
     /// Supports expressions like:
-    /// - "in 5 minutes", "3 days ago"
+    /// - "in 5 minutes", "3 days ago", "in 2 hours 30 minutes"
     /// - "tomorrow at 3:30 pm"
     /// - "next Monday", "last Friday"
     pub mod english;
@@ -928,7 +3422,7 @@ pub mod language {
     /// German language parser.
     ///
     /// Supports expressions like:
-    /// - "in 5 Minuten", "vor 3 Tagen"
+    /// - "in 5 Minuten", "vor 3 Tagen", "in 2 Stunden und 30 Minuten"
     /// - "morgen um 15:30"
     /// - "nächsten Montag", "letzten Freitag"
     pub mod german;
@@ -944,7 +3438,7 @@ pub mod language {
 /// # Arguments
 ///
 /// * `input` - The natural language time expression to parse
-/// * `language` - The language to use for parsing
+/// * `options` - The language to use for parsing, or [`ParseOptions`]
 ///
 /// # Returns
 ///
@@ -968,12 +3462,16 @@ pub mod language {
 ///
 /// // Parse ISO datetime (works in any language)
 /// let expr = parse("2024-01-15T14:30:00Z", Language::English).unwrap();
+///
+/// // Read numeric dates month-first
+/// let expr = parse("03/05/2024", Language::EnglishUs).unwrap();
 /// ```
 ///
 /// # Supported Formats
 ///
 /// ## Relative Time
 /// - "in 5 minutes", "5 minutes ago"
+/// - "in 2 hours 30 minutes", "1 day and 3 hours ago"
 /// - "in 2 hours", "an hour ago"
 /// - "in 3 days", "2 days ago"
 /// - "in a week", "2 weeks ago"
@@ -984,25 +3482,267 @@ pub mod language {
 /// - "today", "yesterday", "tomorrow"
 /// - "Monday", "Tuesday", etc.
 /// - "next Monday", "last Friday"
+/// - "next week Monday", "Friday last week"
 ///
 /// ## Times
 /// - "3:30 pm", "10:15 am"
 /// - "14:30", "09:00"
 ///
 /// ## Dates
-/// - "15/03/2024", "31-12-2025"
+/// - "15/03/2024", "31-12-2025" (in the [`DateOrder`] of the options)
+/// - "March 5th 2024", "5th of March 2024", "5. März 2024"
+/// - Without a year: "March 5", "05/03", "am 24.12." (the provider picks the year)
 ///
 /// ## Combined
 /// - "tomorrow at 3:30 pm"
 /// - "next Monday at 9:00 am"
+/// - "in 3 days at 5pm", "2 weeks ago at 10:30"
+/// - "next week Monday at 10:00"
+///
+/// ## Intervals
+/// - "from 3pm to 5pm", "between Monday and Friday", "3-5pm"
+/// - "von 9:00 bis 17:00", "zwischen Montag und Freitag", "15-17 Uhr"
+///
+/// ## Periods
+/// - "last week", "this month", "next quarter", "next year"
+/// - "letzte Woche", "diesen Monat", "nächstes Quartal", "nächstes Jahr"
+///
+/// ## Period Boundaries
+/// - "end of month", "start of next week", "beginning of the quarter", "EOD"
+/// - "Ende des Jahres", "Anfang nächster Woche", "Ende des Tages"
+///
+/// ## Recurrences
+/// - "every Monday at 9am", "every other Friday", "daily until 31/12/2024"
+/// - "jeden Montag um 9 Uhr", "alle 2 Wochen am Dienstag", "jeden Werktag"
 ///
 /// ## ISO Format
 /// - "2024-01-15T14:30:00Z"
 /// - "2024-01-15T14:30:00+02:00"
 /// - "2024-01-15T14:30:00.123Z"
-pub fn parse(input: &str, language: Language) -> Result<TimeExpression> {
+pub fn parse(input: &str, options: impl Into<ParseOptions>) -> Result<TimeExpression> {
+    let options = options.into();
+    match options.language() {
+        Language::English | Language::EnglishUs | Language::EnglishGb => {
+            language::english::EnglishParser.parse_with_options(input, &options)
+        }
+        Language::German => language::german::GermanParser.parse_with_options(input, &options),
+    }
+}
+
+/// List every plausible interpretation of a time expression.
+///
+/// Where [`parse`] silently picks one reading, this returns all of them,
+/// each with the [`Rule`] that produced it, so callers can ask the user to
+/// choose. The first candidate is always the one [`parse`] returns. Numeric
+/// dates are also read in the other [`DateOrder`]s, and weekdays as the day
+/// in the current or the next week.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Language, Rule, parse_candidates};
+///
+/// let candidates = parse_candidates("Monday", Language::English).unwrap();
+/// let rules: Vec<Rule> = candidates.iter().map(|candidate| candidate.rule).collect();
+/// assert_eq!(rules, [Rule::DayReference, Rule::WeekdayThisWeek, Rule::WeekdayNextWeek]);
+///
+/// // Unambiguous input has a single candidate
+/// let candidates = parse_candidates("in 5 minutes", Language::English).unwrap();
+/// assert_eq!(candidates.len(), 1);
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse`].
+pub fn parse_candidates(input: &str, options: impl Into<ParseOptions>) -> Result<Vec<Candidate>> {
+    let options = options.into();
+    match options.language() {
+        Language::English | Language::EnglishUs | Language::EnglishGb => {
+            language::english::EnglishParser.parse_candidates(input, &options)
+        }
+        Language::German => language::german::GermanParser.parse_candidates(input, &options),
+    }
+}
+
+/// Parse the time expression at the start of the input and return the rest.
+///
+/// For command-style input such as "remind me tomorrow at 9am to call Bob",
+/// where the caller's own grammar handles the words around the time. Leading
+/// whitespace is skipped and the longest expression that ends at a word
+/// boundary is taken; the rest of the input is returned untouched, including
+/// any whitespace after the expression.
+///
+/// Unlike [`parse`], a bare duration such as "2h" is read as that long from
+/// now, like "in 2h", because a command such as "snooze 2h" means that.
+/// [`parse`] rejects "2h" on its own, so an empty rest does not mean that
+/// [`parse`] would accept the whole input.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{
+///     DayReference, Direction, Language, RelativeTime, TimeExpression, TimeUnit, parse,
+///     parse_prefix,
+/// };
+///
+/// let input = "remind me tomorrow at 9am to call Bob";
+/// let rest = input.strip_prefix("remind me").unwrap();
+/// let (expression, rest) = parse_prefix(rest, Language::English).unwrap();
+/// assert!(matches!(expression, TimeExpression::DayTime(_)));
+/// assert_eq!(rest, " to call Bob");
+///
+/// let (expression, rest) = parse_prefix("tomorrow, please", Language::English).unwrap();
+/// assert_eq!(expression, TimeExpression::Day(DayReference::Tomorrow));
+/// assert_eq!(rest, ", please");
+///
+/// let (expression, rest) = parse_prefix("2h please", Language::English).unwrap();
+/// let in_two_hours = RelativeTime { amount: 2, unit: TimeUnit::Hour, direction: Direction::Future };
+/// assert_eq!(expression, TimeExpression::Relative(in_two_hours));
+/// assert_eq!(rest, " please");
+///
+/// // The whole input, but not a complete expression for `parse`
+/// assert_eq!(parse_prefix("2h", Language::English).unwrap().1, "");
+/// assert!(parse("2h", Language::English).is_err());
+///
+/// // "now" is not a word of "nowhere"
+/// assert!(parse_prefix("nowhere", Language::English).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `TempsError::ParseError` if the input does not start with a time
+/// expression.
+pub fn parse_prefix(
+    input: &str,
+    options: impl Into<ParseOptions>,
+) -> Result<(TimeExpression, &str)> {
+    let options = options.into();
+    match options.language() {
+        Language::English | Language::EnglishUs | Language::EnglishGb => {
+            language::english::EnglishParser.parse_prefix(input, &options)
+        }
+        Language::German => language::german::GermanParser.parse_prefix(input, &options),
+    }
+}
+
+/// Parse a standalone duration such as a timeout or retention period.
+///
+/// Unlike [`parse`], the input is an amount of time rather than a point in
+/// time: there is no "in" or "ago". Several units can be combined, units can
+/// be attached to the number, and amounts can have a fractional part that is
+/// carried into smaller units.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{parse_duration, Duration, Language, TimeUnit};
+///
+/// let duration = parse_duration("90 minutes", Language::English).unwrap();
+/// assert_eq!(duration.minutes, 90);
+///
+/// let duration = parse_duration("2h30m", Language::English).unwrap();
+/// assert_eq!(duration, Duration::default().with(TimeUnit::Hour, 2).with(TimeUnit::Minute, 30));
+///
+/// let duration = parse_duration("1.5 days", Language::English).unwrap();
+/// assert_eq!((duration.days, duration.hours), (1, 12));
+///
+/// let duration = parse_duration("1,5 Stunden", Language::German).unwrap();
+/// assert_eq!((duration.hours, duration.minutes), (1, 30));
+///
+/// // ISO 8601 durations are read in every language
+/// let duration = parse_duration("P1DT2H30M", Language::German).unwrap();
+/// assert_eq!((duration.days, duration.hours, duration.minutes), (1, 2, 30));
+/// ```
+///
+/// # Errors
+///
+/// Returns `TempsError::ParseError` if the input is not a duration, repeats
+/// a unit, or has a fractional amount of months that cannot be expressed
+/// exactly.
+pub fn parse_duration(input: &str, language: Language) -> Result<Duration> {
+    match language {
+        Language::English | Language::EnglishUs | Language::EnglishGb => {
+            language::english::EnglishParser.parse_duration(input)
+        }
+        Language::German => language::german::GermanParser.parse_duration(input),
+    }
+}
+
+/// Write a time expression as canonical text in the given language.
+///
+/// The text parses back with [`parse`] in the same language. Parsing and
+/// formatting again gives the same text, so this also translates input:
+/// parse German, format English.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Language, format_expression, parse};
+///
+/// let expr = parse("nächsten Montag um 15:00", Language::German).unwrap();
+/// assert_eq!(format_expression(&expr, Language::English).unwrap(), "next Monday at 15:00");
+///
+/// let expr = parse("every other Friday at 9am until 31/12/2024", Language::English).unwrap();
+/// assert_eq!(
+///     format_expression(&expr, Language::EnglishUs).unwrap(),
+///     "every 2 weeks on Friday at 9:00 am until December 31, 2024"
+/// );
+/// assert_eq!(
+///     format_expression(&expr, Language::German).unwrap(),
+///     "alle 2 Wochen am Freitag um 9:00 Uhr bis 31.12.2024"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns `TempsError::UnsupportedOperation` if the language has no words
+/// for the expression, such as "Monday in three weeks", or
+/// `TempsError::DateCalculationError` if a relative amount is negative.
+pub fn format_expression(expr: &TimeExpression, language: Language) -> Result<String> {
+    match language {
+        Language::English | Language::EnglishUs | Language::EnglishGb => {
+            language::english::EnglishParser.format(expr, language)
+        }
+        Language::German => language::german::GermanParser.format(expr, language),
+    }
+}
+
+/// Write a relative time as text in the given language.
+///
+/// The text parses back to the same amounts with [`parse`]. Providers build
+/// relative times from points in time with their `humanize` methods.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Direction, Duration, Language, RelativeDuration, TimeUnit, format_relative};
+///
+/// let relative = RelativeDuration {
+///     duration: Duration::default().with(TimeUnit::Hour, 2).with(TimeUnit::Minute, 30),
+///     direction: Direction::Future,
+/// };
+/// assert_eq!(format_relative(&relative, Language::English).unwrap(), "in 2 hours 30 minutes");
+///
+/// let relative = RelativeDuration {
+///     duration: Duration::default().with(TimeUnit::Day, 1),
+///     direction: Direction::Past,
+/// };
+/// assert_eq!(format_relative(&relative, Language::German).unwrap(), "vor 1 Tag");
+/// ```
+///
+/// # Errors
+///
+/// Returns `TempsError::DateCalculationError` if an amount is negative.
+pub fn format_relative(relative: &RelativeDuration, language: Language) -> Result<String> {
+    if relative.duration.components().any(|(_, amount)| amount < 0) {
+        return Err(TempsError::date_calculation(
+            errors::ERR_RELATIVE_AMOUNT_NON_NEGATIVE,
+        ));
+    }
     match language {
-        Language::English => language::english::EnglishParser.parse(input),
-        Language::German => language::german::GermanParser.parse(input),
+        Language::English | Language::EnglishUs | Language::EnglishGb => {
+            language::english::EnglishParser.format_relative(relative)
+        }
+        Language::German => language::german::GermanParser.format_relative(relative),
     }
 }
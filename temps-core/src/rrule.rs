@@ -0,0 +1,676 @@
+//! iCalendar (RFC 5545) recurrence rules.
+//!
+//! [`parse_rrule`] reads the `RRULE` of an event, optionally preceded by its
+//! `DTSTART`, into an [`RRule`]. Formatting an [`RRule`] writes the same lines
+//! back, so rules survive a round trip through temps. [`rrule_occurrences`]
+//! expands a rule into the local dates and times it occurs on.
+//!
+//! # Examples
+//!
+//! ```
+//! use temps_core::{Frequency, RRuleEnd, parse_rrule};
+//!
+//! let text = "DTSTART;TZID=Europe/Berlin:20240315T090000\nRRULE:FREQ=WEEKLY;COUNT=10;BYDAY=MO,WE";
+//! let rule = parse_rrule(text).unwrap();
+//! assert_eq!(rule.frequency, Frequency::Weekly);
+//! assert_eq!(rule.time_zone.as_deref(), Some("Europe/Berlin"));
+//! assert_eq!(rule.end, Some(RRuleEnd::Count(10)));
+//! assert_eq!(rule.to_string(), text);
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use winnow::{combinator::preceded, prelude::*, token::take_while};
+
+use crate::{
+    AbsoluteTime, ByDay, Frequency, RRule, RRuleEnd, Result, StandardDate, TempsError, Time,
+    Timezone, WeekStart, Weekday,
+    constants::{DAYS_PER_WEEK, MONTHS_PER_YEAR},
+    errors::*,
+    spanned::opt,
+    time_utils::{
+        self, DAYS_PER_CALENDAR_CYCLE, civil_from_days, convert_12_to_24_hour, days_from_civil,
+        days_from_week_start, days_in_month, is_leap_year, monday_offset,
+    },
+};
+
+/// Parse an iCalendar recurrence rule.
+///
+/// Accepts an `RRULE:` line or just its value, optionally preceded by a
+/// `DTSTART` line. Names and values are case-insensitive. Lines may end with
+/// `\n` or `\r\n`.
+///
+/// Supported parts are `FREQ` (except `SECONDLY`), `INTERVAL`, `COUNT`,
+/// `UNTIL`, `BYMONTH`, `BYMONTHDAY`, `BYDAY`, `BYHOUR`, `BYMINUTE`,
+/// `BYSECOND`, `BYSETPOS` and `WKST` (Monday, Sunday or Saturday).
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{AbsoluteTime, Frequency, RRuleEnd, Timezone, parse_rrule};
+///
+/// let rule = parse_rrule("FREQ=DAILY;UNTIL=20241231T235959Z;BYHOUR=9,17").unwrap();
+/// assert_eq!(rule.by_hour, [9, 17]);
+/// assert!(matches!(
+///     rule.end,
+///     Some(RRuleEnd::Until(AbsoluteTime { year: 2024, timezone: Some(Timezone::Utc), .. }))
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// Returns `TempsError::ParseError` with the position of the offending part
+/// if the rule is malformed, lacks `FREQ`, has both `COUNT` and `UNTIL`, or
+/// uses a part temps does not support.
+pub fn parse_rrule(input: &str) -> Result<RRule> {
+    let error = |message: &str, position: usize| {
+        TempsError::parse_error_with_position(message, input, position)
+    };
+
+    let mut start = None;
+    let mut time_zone = None;
+    let mut rule = None;
+    let mut position = 0;
+    for line in input.split('\n') {
+        let line_position = position;
+        position += line.len() + 1;
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (name, value) = line.split_once(':').unwrap_or(("RRULE", line));
+        let value_position = line_position + line.len() - value.len();
+        let mut params = name.split(';');
+        let name = params.next().unwrap_or_default();
+        if name.eq_ignore_ascii_case("DTSTART") && start.is_none() {
+            for param in params {
+                match param.split_once('=') {
+                    Some((key, tzid)) if key.eq_ignore_ascii_case("TZID") => {
+                        time_zone = Some(tzid.to_string());
+                    }
+                    Some((key, kind))
+                        if key.eq_ignore_ascii_case("VALUE")
+                            && (kind.eq_ignore_ascii_case("DATE")
+                                || kind.eq_ignore_ascii_case("DATE-TIME")) => {}
+                    _ => return Err(error(ERR_RRULE_INVALID_PART, line_position)),
+                }
+            }
+            let value = parse_date_time(value)
+                .ok_or_else(|| error(ERR_RRULE_INVALID_PART, value_position))?;
+            start = Some(value);
+        } else if name.eq_ignore_ascii_case("RRULE") && rule.is_none() {
+            if params.next().is_some() {
+                return Err(error(ERR_RRULE_INVALID_PART, line_position));
+            }
+            rule = Some(parse_rule_parts(value, value_position, &error)?);
+        } else {
+            return Err(error(ERR_RRULE_INVALID_PART, line_position));
+        }
+    }
+
+    let mut rule = rule.ok_or_else(|| error(ERR_RRULE_MISSING_FREQ, input.len()))?;
+    rule.start = start;
+    rule.time_zone = time_zone;
+    Ok(rule)
+}
+
+/// Parse the `;`-separated parts of an RRULE value starting at `position`
+fn parse_rule_parts(
+    value: &str,
+    mut position: usize,
+    error: &impl Fn(&str, usize) -> TempsError,
+) -> Result<RRule> {
+    let mut frequency = None;
+    let mut rule = RRule::new(Frequency::Daily);
+    let mut seen = Vec::new();
+
+    for part in value.split(';') {
+        let part_position = position;
+        position += part.len() + 1;
+        let invalid = || error(ERR_RRULE_INVALID_PART, part_position);
+        let unsupported = || error(ERR_RRULE_UNSUPPORTED_PART, part_position);
+
+        let (name, value) = part.split_once('=').ok_or_else(invalid)?;
+        let name = name.to_ascii_uppercase();
+        let value = value.to_ascii_uppercase();
+        if seen.contains(&name) {
+            return Err(invalid());
+        }
+
+        match name.as_str() {
+            "FREQ" => {
+                frequency = Some(match value.as_str() {
+                    "MINUTELY" => Frequency::Minutely,
+                    "HOURLY" => Frequency::Hourly,
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    "SECONDLY" => return Err(unsupported()),
+                    _ => return Err(invalid()),
+                });
+            }
+            "INTERVAL" => {
+                rule.interval = value.parse().ok().filter(|&n| n > 0).ok_or_else(invalid)?;
+            }
+            "COUNT" => rule.end = Some(RRuleEnd::Count(value.parse().map_err(|_| invalid())?)),
+            "UNTIL" => {
+                let until = parse_date_time(&value).ok_or_else(invalid)?;
+                rule.end = Some(RRuleEnd::Until(until));
+            }
+            "BYMONTH" => rule.by_month = parse_list(&value, 1..=12).ok_or_else(invalid)?,
+            "BYMONTHDAY" => {
+                rule.by_month_day = parse_list(&value, -31..=31).ok_or_else(invalid)?;
+            }
+            "BYDAY" => {
+                rule.by_day = value
+                    .split(',')
+                    .map(parse_by_day)
+                    .collect::<Option<_>>()
+                    .ok_or_else(invalid)?;
+            }
+            "BYHOUR" => rule.by_hour = parse_list(&value, 0..=23).ok_or_else(invalid)?,
+            "BYMINUTE" => rule.by_minute = parse_list(&value, 0..=59).ok_or_else(invalid)?,
+            "BYSECOND" => rule.by_second = parse_list(&value, 0..=59).ok_or_else(invalid)?,
+            "BYSETPOS" => rule.by_set_pos = parse_list(&value, -366..=366).ok_or_else(invalid)?,
+            "WKST" => {
+                rule.week_start = match parse_weekday(&value).ok_or_else(invalid)? {
+                    Weekday::Monday => WeekStart::Monday,
+                    Weekday::Sunday => WeekStart::Sunday,
+                    Weekday::Saturday => WeekStart::Saturday,
+                    _ => return Err(unsupported()),
+                };
+            }
+            "BYYEARDAY" | "BYWEEKNO" => return Err(unsupported()),
+            _ => return Err(invalid()),
+        }
+        if name == "COUNT" && seen.iter().any(|seen| seen == "UNTIL")
+            || name == "UNTIL" && seen.iter().any(|seen| seen == "COUNT")
+        {
+            return Err(error(ERR_RRULE_COUNT_AND_UNTIL, part_position));
+        }
+        seen.push(name);
+    }
+
+    rule.frequency = frequency.ok_or_else(|| error(ERR_RRULE_MISSING_FREQ, position - 1))?;
+    // Numbered weekdays and days of the month only make sense in some periods
+    let numbered = rule.by_day.iter().any(|day| day.ordinal.is_some());
+    let (numbered_ok, month_days_ok) = match rule.frequency {
+        Frequency::Monthly | Frequency::Yearly => (true, true),
+        Frequency::Weekly => (false, false),
+        _ => (false, true),
+    };
+    if numbered && !numbered_ok || !rule.by_month_day.is_empty() && !month_days_ok {
+        return Err(error(ERR_RRULE_INVALID_PART, position - 1));
+    }
+    Ok(rule)
+}
+
+/// Parse a comma-separated list of non-zero numbers within `range`
+fn parse_list<T: FromStr + PartialOrd + Default>(
+    value: &str,
+    range: std::ops::RangeInclusive<T>,
+) -> Option<Vec<T>> {
+    value
+        .split(',')
+        .map(|item| {
+            item.parse().ok().filter(|n| {
+                range.contains(n) && (*range.start() >= T::default() || *n != T::default())
+            })
+        })
+        .collect()
+}
+
+/// Parse a `BYDAY` entry such as `MO`, `2TU` or `-1FR`
+fn parse_by_day(value: &str) -> Option<ByDay> {
+    let split = value.len().checked_sub(2)?;
+    let (ordinal, weekday) = (value.get(..split)?, value.get(split..)?);
+    let ordinal = match ordinal {
+        "" => None,
+        ordinal => Some(
+            ordinal
+                .parse::<i8>()
+                .ok()
+                .filter(|n| (1..=53).contains(&n.abs()))?,
+        ),
+    };
+    Some(ByDay {
+        weekday: parse_weekday(weekday)?,
+        ordinal,
+    })
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    Some(match value {
+        "MO" => Weekday::Monday,
+        "TU" => Weekday::Tuesday,
+        "WE" => Weekday::Wednesday,
+        "TH" => Weekday::Thursday,
+        "FR" => Weekday::Friday,
+        "SA" => Weekday::Saturday,
+        "SU" => Weekday::Sunday,
+        _ => return None,
+    })
+}
+
+/// Parse a `DATE` (`20240315`) or `DATE-TIME` (`20240315T090000[Z]`) value
+fn parse_date_time(value: &str) -> Option<AbsoluteTime> {
+    fn digits<'a>(count: usize) -> impl Parser<&'a str, &'a str, winnow::error::ContextError> {
+        take_while(count, |c: char| c.is_ascii_digit())
+    }
+
+    let value = value.to_ascii_uppercase();
+    let ((year, month, day), time, utc) = (
+        (
+            digits(4).parse_to::<u16>(),
+            digits(2).parse_to::<u8>(),
+            digits(2).parse_to::<u8>(),
+        ),
+        opt(preceded(
+            'T',
+            (
+                digits(2).parse_to::<u8>(),
+                digits(2).parse_to::<u8>(),
+                digits(2).parse_to::<u8>(),
+            ),
+        )),
+        opt('Z'),
+    )
+        .parse(value.as_str())
+        .ok()?;
+
+    if !time_utils::is_valid_calendar_date(year, month, day)
+        || time.is_some_and(|(hour, minute, second)| {
+            !time_utils::is_valid_24_hour_time(hour, minute, second)
+        })
+        || time.is_none() && utc.is_some()
+    {
+        return None;
+    }
+    Some(AbsoluteTime {
+        year,
+        month,
+        day,
+        hour: time.map(|(hour, _, _)| hour),
+        minute: time.map(|(_, minute, _)| minute),
+        second: time.map(|(_, _, second)| second),
+        nanosecond: None,
+        timezone: utc.map(|_| Timezone::Utc),
+    })
+}
+
+impl FromStr for RRule {
+    type Err = TempsError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        parse_rrule(input)
+    }
+}
+
+/// Writes an optional `DTSTART` line and the `RRULE` line, separated by `\n`.
+///
+/// Parts with their default value (`INTERVAL=1`, `WKST=MO`) are left out.
+/// Times with a fixed offset are written in UTC.
+impl fmt::Display for RRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(start) = self.start {
+            f.write_str("DTSTART")?;
+            if let Some(time_zone) = &self.time_zone {
+                write!(f, ";TZID={time_zone}")?;
+            }
+            if start.hour.is_none() {
+                f.write_str(";VALUE=DATE")?;
+            }
+            writeln!(f, ":{}", DateTimeValue(start))?;
+        }
+
+        let frequency = match self.frequency {
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "RRULE:FREQ={frequency}")?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        match self.end {
+            Some(RRuleEnd::Count(count)) => write!(f, ";COUNT={count}")?,
+            Some(RRuleEnd::Until(until)) => write!(f, ";UNTIL={}", DateTimeValue(until))?,
+            None => {}
+        }
+
+        write_list(f, "BYMONTH", &self.by_month)?;
+        write_list(f, "BYMONTHDAY", &self.by_month_day)?;
+        write_list(f, "BYDAY", &self.by_day)?;
+        write_list(f, "BYHOUR", &self.by_hour)?;
+        write_list(f, "BYMINUTE", &self.by_minute)?;
+        write_list(f, "BYSECOND", &self.by_second)?;
+        write_list(f, "BYSETPOS", &self.by_set_pos)?;
+        match self.week_start {
+            WeekStart::Monday => Ok(()),
+            WeekStart::Sunday => f.write_str(";WKST=SU"),
+            WeekStart::Saturday => f.write_str(";WKST=SA"),
+        }
+    }
+}
+
+fn write_list<T: fmt::Display>(f: &mut fmt::Formatter<'_>, name: &str, items: &[T]) -> fmt::Result {
+    for (index, item) in items.iter().enumerate() {
+        if index == 0 {
+            write!(f, ";{name}={item}")?;
+        } else {
+            write!(f, ",{item}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the `BYDAY` form, such as `MO` or `-1FR`.
+impl fmt::Display for ByDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ordinal) = self.ordinal {
+            write!(f, "{ordinal}")?;
+        }
+        f.write_str(weekday_code(self.weekday))
+    }
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "MO",
+        Weekday::Tuesday => "TU",
+        Weekday::Wednesday => "WE",
+        Weekday::Thursday => "TH",
+        Weekday::Friday => "FR",
+        Weekday::Saturday => "SA",
+        Weekday::Sunday => "SU",
+    }
+}
+
+/// An [`AbsoluteTime`] in iCalendar `DATE` or `DATE-TIME` form
+struct DateTimeValue(AbsoluteTime);
+
+impl fmt::Display for DateTimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.0;
+        if let Some(Timezone::Offset { .. }) = value.timezone {
+            value = time_utils::to_utc(value).unwrap_or(value);
+        }
+        write!(f, "{:04}{:02}{:02}", value.year, value.month, value.day)?;
+        let Some(hour) = value.hour else {
+            return Ok(());
+        };
+        write!(
+            f,
+            "T{hour:02}{:02}{:02}",
+            value.minute.unwrap_or(0),
+            value.second.unwrap_or(0)
+        )?;
+        if value.timezone.is_some() {
+            f.write_str("Z")?;
+        }
+        Ok(())
+    }
+}
+
+/// Expand an iCalendar recurrence rule into local dates and times
+///
+/// Occurrences are yielded in order from `start` at `time`, the local time
+/// of the rule's first occurrence. Parts the rule leaves open come from
+/// the start: the weekday of weekly rules, the day of monthly and yearly
+/// rules, the month of yearly rules and the time of day. `COUNT` and
+/// `UNTIL` are left to the caller, which knows the time zone to compare
+/// them in. The iterator ends once no occurrence has been found for 400
+/// years, a full cycle of the calendar.
+///
+/// # Examples
+/// ```
+/// use temps_core::{StandardDate, Time, parse_rrule, rrule::rrule_occurrences};
+///
+/// // Friday
+/// let start = StandardDate { day: 15, month: 3, year: 2024 };
+/// let nine = Time { hour: 9, minute: 0, second: 0, meridiem: None };
+///
+/// // The last Friday of every month
+/// let rule = parse_rrule("FREQ=MONTHLY;BYDAY=-1FR").unwrap();
+/// let days: Vec<u8> = rrule_occurrences(&rule, start, nine)
+///     .take(3)
+///     .map(|(date, _)| date.day)
+///     .collect();
+/// assert_eq!(days, [29, 26, 31]);
+/// ```
+pub fn rrule_occurrences(
+    rule: &RRule,
+    start: StandardDate,
+    time: Time,
+) -> impl Iterator<Item = (StandardDate, Time)> + '_ {
+    // A day count from `days_from_civil` and a time of day
+    type Occurrence = (i64, (u8, u8, u8));
+
+    let interval = i64::from(rule.interval.max(1));
+    let start_day = days_from_civil(start);
+    let start_weekday = monday_offset(start_day);
+    let hour = convert_12_to_24_hour(time.hour, time.meridiem.as_ref());
+    let first = (start_day, (hour, time.minute, time.second));
+
+    let sorted_or = |values: &[u8], default: u8| {
+        let mut values = if values.is_empty() {
+            vec![default]
+        } else {
+            values.to_vec()
+        };
+        values.sort_unstable();
+        values.dedup();
+        values
+    };
+    let hours = sorted_or(&rule.by_hour, hour);
+    let minutes = sorted_or(&rule.by_minute, time.minute);
+    let seconds = sorted_or(&rule.by_second, time.second);
+    let times = move |hours: &[u8], minutes: &[u8]| -> Vec<(u8, u8, u8)> {
+        let mut times = Vec::new();
+        for &hour in hours {
+            for &minute in minutes {
+                times.extend(seconds.iter().map(|&second| (hour, minute, second)));
+            }
+        }
+        times
+    };
+
+    let matches_day = move |day: i64| -> bool {
+        let Some(date) = civil_from_days(day) else {
+            return false;
+        };
+        let weekday = monday_offset(day);
+        let month_length = days_in_month(date.year, date.month);
+        if !rule.by_month.is_empty() && !rule.by_month.contains(&date.month) {
+            return false;
+        }
+        let from_end = i16::from(date.day) - i16::from(month_length) - 1;
+        if !rule.by_month_day.is_empty()
+            && !rule.by_month_day.iter().any(|&month_day| {
+                i16::from(month_day)
+                    == if month_day < 0 {
+                        from_end
+                    } else {
+                        i16::from(date.day)
+                    }
+            })
+        {
+            return false;
+        }
+
+        if !rule.by_day.is_empty() {
+            // Numbered weekdays count within the month, or within the year
+            // for yearly rules without months
+            let (position, length) =
+                if rule.frequency == Frequency::Yearly && rule.by_month.is_empty() {
+                    let january = days_from_civil(StandardDate {
+                        day: 1,
+                        month: 1,
+                        year: date.year,
+                    });
+                    (day - january, 365 + i64::from(is_leap_year(date.year)))
+                } else {
+                    (i64::from(date.day) - 1, i64::from(month_length))
+                };
+            let numbered = matches!(rule.frequency, Frequency::Monthly | Frequency::Yearly);
+            return rule.by_day.iter().any(|by_day| {
+                by_day.weekday as i64 == weekday
+                    && match by_day.ordinal.map(i64::from) {
+                        Some(nth) if numbered && nth > 0 => position / 7 + 1 == nth,
+                        Some(nth) if numbered => (length - 1 - position) / 7 + 1 == -nth,
+                        _ => true,
+                    }
+            });
+        }
+        match rule.frequency {
+            Frequency::Weekly => weekday == start_weekday,
+            Frequency::Monthly if rule.by_month_day.is_empty() => date.day == start.day,
+            Frequency::Yearly if rule.by_month_day.is_empty() => {
+                date.day == start.day && (!rule.by_month.is_empty() || date.month == start.month)
+            }
+            _ => true,
+        }
+    };
+
+    // The first day, candidates and next index of the `index`th period
+    let units_per_day = match rule.frequency {
+        Frequency::Minutely => 24 * 60,
+        _ => 24,
+    };
+    let start_unit = match rule.frequency {
+        Frequency::Minutely => (start_day * 24 + i64::from(hour)) * 60 + i64::from(time.minute),
+        _ => start_day * 24 + i64::from(hour),
+    };
+    let period = move |index: i64| -> Option<(i64, Vec<Occurrence>, i64)> {
+        let step = index.checked_mul(interval)?;
+        let (first, count) = match rule.frequency {
+            Frequency::Minutely | Frequency::Hourly => {
+                let unit = start_unit.checked_add(step)?;
+                let day = unit.div_euclid(units_per_day);
+                let units_per_hour = units_per_day / 24;
+                let hour = (unit.rem_euclid(units_per_day) / units_per_hour) as u8;
+                let minute = (unit.rem_euclid(units_per_hour)) as u8;
+                // Skip straight to the next day or hour that can match
+                let skip_to = |boundary: i64| index + (boundary - unit + interval - 1) / interval;
+                if !matches_day(day) {
+                    return Some((day, Vec::new(), skip_to((day + 1) * units_per_day)));
+                }
+                if !rule.by_hour.is_empty() && !rule.by_hour.contains(&hour) {
+                    let next_hour = (unit / units_per_hour + 1) * units_per_hour;
+                    return Some((day, Vec::new(), skip_to(next_hour)));
+                }
+                let times = match rule.frequency {
+                    Frequency::Minutely
+                        if rule.by_minute.is_empty() || rule.by_minute.contains(&minute) =>
+                    {
+                        times(&[hour], &[minute])
+                    }
+                    Frequency::Minutely => Vec::new(),
+                    _ => times(&[hour], &minutes),
+                };
+                let candidates = times.into_iter().map(|time| (day, time)).collect();
+                return Some((day, candidates, index + 1));
+            }
+            Frequency::Daily => (start_day.checked_add(step)?, 1),
+            Frequency::Weekly => {
+                let week = start_day - days_from_week_start(start_weekday, rule.week_start);
+                (
+                    week.checked_add(step.checked_mul(i64::from(DAYS_PER_WEEK))?)?,
+                    7,
+                )
+            }
+            Frequency::Monthly => {
+                let months_per_year = i64::from(MONTHS_PER_YEAR);
+                let index = (i64::from(start.year) * months_per_year + i64::from(start.month) - 1)
+                    .checked_add(step)?;
+                let year = u16::try_from(index.div_euclid(months_per_year)).ok()?;
+                let month = (index.rem_euclid(months_per_year) + 1) as u8;
+                let first = days_from_civil(StandardDate {
+                    day: 1,
+                    month,
+                    year,
+                });
+                (first, i64::from(days_in_month(year, month)))
+            }
+            Frequency::Yearly => {
+                let year = u16::try_from(i64::from(start.year).checked_add(step)?).ok()?;
+                let first = days_from_civil(StandardDate {
+                    day: 1,
+                    month: 1,
+                    year,
+                });
+                (first, 365 + i64::from(is_leap_year(year)))
+            }
+        };
+        let times = times(&hours, &minutes);
+        let candidates = (first..first + count)
+            .filter(|&day| matches_day(day))
+            .flat_map(|day| times.iter().map(move |&time| (day, time)))
+            .collect();
+        Some((first, candidates, index + 1))
+    };
+
+    // BYSETPOS picks occurrences by their position within the period
+    let select = move |candidates: Vec<Occurrence>| {
+        if rule.by_set_pos.is_empty() {
+            return candidates;
+        }
+        let length = candidates.len() as i64;
+        let mut positions: Vec<usize> = rule
+            .by_set_pos
+            .iter()
+            .filter_map(|&position| {
+                let position = i64::from(position);
+                let index = if position > 0 {
+                    position - 1
+                } else {
+                    length + position
+                };
+                usize::try_from(index)
+                    .ok()
+                    .filter(|&index| index < candidates.len())
+            })
+            .collect();
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+            .into_iter()
+            .map(|index| candidates[index])
+            .collect()
+    };
+
+    let mut index = 0;
+    let mut last_found = start_day;
+    let mut pending = Vec::new().into_iter();
+    std::iter::from_fn(move || {
+        loop {
+            if let Some((day, (hour, minute, second))) = pending.next() {
+                let time = Time {
+                    hour,
+                    minute,
+                    second,
+                    meridiem: None,
+                };
+                return Some((civil_from_days(day)?, time));
+            }
+            let (first_day, candidates, next) = period(index)?;
+            if first_day - last_found > DAYS_PER_CALENDAR_CYCLE {
+                return None;
+            }
+            index = next;
+            let candidates: Vec<_> = select(candidates)
+                .into_iter()
+                .filter(|&candidate| candidate >= first)
+                .collect();
+            if let Some(&(day, _)) = candidates.last() {
+                last_found = day;
+            }
+            pending = candidates.into_iter();
+        }
+    })
+}
@@ -0,0 +1,248 @@
+//! Where each part of a time expression is in the input.
+//!
+//! [`parse_spanned`] parses like [`parse`] and also returns the byte range of
+//! every amount, unit, weekday, time of day, timezone and other part the
+//! grammar read, e.g. to highlight the input in an editor.
+
+use std::{
+    cell::RefCell,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use winnow::{
+    Parser,
+    combinator::Alt,
+    error::ParserError,
+    stream::{Accumulate, Range, Stream},
+};
+
+use crate::{Component, ParseOptions, Result, Span, Spanned, parse};
+
+/// A part read so far, with the lengths of the input left before and after it
+type Recorded = (Component, usize, usize);
+
+// The grammars report parts as they read them. Parts read by a branch that
+// is later abandoned are dropped again, which is why the language modules use
+// the `alt`, `opt`, `repeat` and `separated` from here instead of winnow's;
+// `clippy.toml` disallows winnow's own outside this module.
+thread_local! {
+    static RECORDED: RefCell<Option<Vec<Recorded>>> = const { RefCell::new(None) };
+}
+
+/// How many threads are recording, so that plain parsing can skip the
+/// thread local
+///
+/// Checking it costs one relaxed load, and the code that records is kept out
+/// of line, so parsing without spans stays as fast as before.
+static RECORDING: AtomicUsize = AtomicUsize::new(0);
+
+fn recording() -> bool {
+    RECORDING.load(Ordering::Relaxed) > 0
+}
+
+/// Parse a time expression and report where each of its parts is.
+///
+/// Spans are in bytes into `input` and ordered from left to right. Words
+/// that only join parts, such as "in", "at" or "um", have no span.
+///
+/// # Examples
+///
+/// ```
+/// use temps_core::{Component, Language, parse_spanned};
+///
+/// let input = "next Friday at 3:30 pm";
+/// let spanned = parse_spanned(input, Language::English).unwrap();
+/// let parts: Vec<(Component, &str)> = spanned
+///     .spans
+///     .iter()
+///     .map(|span| (span.component, &input[span.range.clone()]))
+///     .collect();
+/// assert_eq!(
+///     parts,
+///     [
+///         (Component::Modifier, "next"),
+///         (Component::Weekday, "Friday"),
+///         (Component::Time, "3:30 pm"),
+///     ]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse`].
+pub fn parse_spanned(input: &str, options: impl Into<ParseOptions>) -> Result<Spanned> {
+    RECORDING.fetch_add(1, Ordering::Relaxed);
+    let restore = Restore(RECORDED.replace(Some(Vec::new())));
+    let expression = parse(input, options);
+    let recorded = RECORDED.take().unwrap_or_default();
+    drop(restore);
+
+    let mut spans: Vec<Span> = recorded
+        .into_iter()
+        .map(|(component, before, after)| Span {
+            component,
+            range: input.len() - before..input.len() - after,
+        })
+        .collect();
+    spans.sort_by_key(|span| span.range.start);
+    Ok(Spanned {
+        expression: expression?,
+        spans,
+    })
+}
+
+/// Puts back the recording of an enclosing [`parse_spanned`], if any
+struct Restore(Option<Vec<Recorded>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        RECORDED.set(self.0.take());
+        RECORDING.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Report what `parser` reads as `component` while spans are recorded.
+pub(crate) fn component<'i, O, E>(
+    component: Component,
+    mut parser: impl Parser<&'i str, O, E>,
+) -> impl Parser<&'i str, O, E> {
+    move |input: &mut &'i str| {
+        let before = input.len();
+        let output = parser.parse_next(input)?;
+        if recording() {
+            record(component, before, input.len());
+        }
+        Ok(output)
+    }
+}
+
+/// Keep a part
+#[cold]
+#[inline(never)]
+fn record(component: Component, before: usize, after: usize) {
+    RECORDED.with_borrow_mut(|recorded| {
+        if let Some(recorded) = recorded {
+            recorded.push((component, before, after));
+        }
+    });
+}
+
+/// Drop the parts read from `start`, in input left, onwards
+///
+/// Parts kept so far end before `start`, so whatever starts at or after it
+/// was read by a parser that failed
+#[cold]
+#[inline(never)]
+fn drop_parts_from(start: usize) {
+    RECORDED.with_borrow_mut(|recorded| {
+        if let Some(recorded) = recorded {
+            recorded.retain(|&(_, before, _)| before > start);
+        }
+    });
+}
+
+/// Alternatives for [`alt`] that drop the parts of failed branches
+pub(crate) struct Alternatives<T>(T);
+
+// Like winnow's own implementations, with one check for a recording per call
+macro_rules! impl_alternatives {
+    ($first_index:tt $first:ident $($index:tt $parser:ident)+) => {
+        impl<I, O, E, $first, $($parser),+> Alt<I, O, E> for Alternatives<($first, $($parser),+)>
+        where
+            I: Stream,
+            E: ParserError<I>,
+            $first: Parser<I, O, E>,
+            $($parser: Parser<I, O, E>),+
+        {
+            fn choice(&mut self, input: &mut I) -> std::result::Result<O, E> {
+                let recording = recording();
+                let start = input.checkpoint();
+                let offset = input.eof_offset();
+                let mut error = match self.0.$first_index.parse_next(input) {
+                    Err(e) if e.is_backtrack() => e,
+                    result => return result,
+                };
+                $(
+                    if recording {
+                        drop_parts_from(offset);
+                    }
+                    input.reset(&start);
+                    match self.0.$index.parse_next(input) {
+                        Err(e) if e.is_backtrack() => error = error.or(e),
+                        result => return result,
+                    }
+                )+
+                if recording {
+                    drop_parts_from(offset);
+                }
+                Err(error.append(input, &start))
+            }
+        }
+    };
+}
+
+impl_alternatives!(0 P0 1 P1);
+impl_alternatives!(0 P0 1 P1 2 P2);
+impl_alternatives!(0 P0 1 P1 2 P2 3 P3);
+impl_alternatives!(0 P0 1 P1 2 P2 3 P3 4 P4);
+impl_alternatives!(0 P0 1 P1 2 P2 3 P3 4 P4 5 P5);
+impl_alternatives!(0 P0 1 P1 2 P2 3 P3 4 P4 5 P5 6 P6);
+impl_alternatives!(0 P0 1 P1 2 P2 3 P3 4 P4 5 P5 6 P6 7 P7);
+impl_alternatives!(0 P0 1 P1 2 P2 3 P3 4 P4 5 P5 6 P6 7 P7 8 P8);
+impl_alternatives!(0 P0 1 P1 2 P2 3 P3 4 P4 5 P5 6 P6 7 P7 8 P8 9 P9);
+
+/// [`winnow::combinator::alt`] that drops the parts of failed branches
+#[allow(clippy::disallowed_methods)]
+pub(crate) fn alt<I: Stream, O, E: ParserError<I>, T>(alternatives: T) -> impl Parser<I, O, E>
+where
+    Alternatives<T>: Alt<I, O, E>,
+{
+    winnow::combinator::alt(Alternatives(alternatives))
+}
+
+/// [`winnow::combinator::opt`] that drops the parts of a failed attempt
+#[allow(clippy::disallowed_methods)]
+pub(crate) fn opt<I: Stream, O, E: ParserError<I>>(
+    parser: impl Parser<I, O, E>,
+) -> impl Parser<I, Option<O>, E> {
+    let mut parser = winnow::combinator::opt(parser);
+    move |input: &mut I| {
+        let offset = input.eof_offset();
+        let output = parser.parse_next(input)?;
+        if output.is_none() && recording() {
+            drop_parts_from(offset);
+        }
+        Ok(output)
+    }
+}
+
+/// [`winnow::combinator::repeat`] that drops the parts of a failed last item
+#[allow(clippy::disallowed_methods)]
+pub(crate) fn repeat<I: Stream, O, C: Accumulate<O>, E: ParserError<I>>(
+    occurrences: impl Into<Range>,
+    parser: impl Parser<I, O, E>,
+) -> impl Parser<I, C, E> {
+    winnow::combinator::repeat(occurrences, item(parser))
+}
+
+/// [`winnow::combinator::separated`] that drops the parts of a failed last item
+#[allow(clippy::disallowed_methods)]
+pub(crate) fn separated<I: Stream, O, C: Accumulate<O>, S, E: ParserError<I>>(
+    occurrences: impl Into<Range>,
+    parser: impl Parser<I, O, E>,
+    separator: impl Parser<I, S, E>,
+) -> impl Parser<I, C, E> {
+    winnow::combinator::separated(occurrences, item(parser), separator)
+}
+
+/// An item of [`repeat`] or [`separated`] that drops its parts if it fails
+fn item<I: Stream, O, E>(mut parser: impl Parser<I, O, E>) -> impl Parser<I, O, E> {
+    move |input: &mut I| {
+        let offset = input.eof_offset();
+        let output = parser.parse_next(input);
+        if output.is_err() && recording() {
+            drop_parts_from(offset);
+        }
+        output
+    }
+}
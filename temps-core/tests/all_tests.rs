@@ -880,7 +880,13 @@ fn test_parser_rejects_invalid_time_components() {
         );
     }
 
-    let german_test_cases = vec!["24:00", "23:60", "23:59:60", "morgen um 24:00"];
+    let german_test_cases = vec![
+        "24:00",
+        "23:60",
+        "23:59:60",
+        "morgen um 24:00",
+        "heute um 24 Uhr",
+    ];
 
     for input in german_test_cases {
         assert!(
@@ -1024,6 +1030,7 @@ fn test_day_at_time_german() {
         "morgen UM 10:00 Uhr",
         "Montag um 15:45",
         "nächsten Freitag um 21:00",
+        "heute um 15 Uhr",
     ];
 
     for input in test_cases {
@@ -1446,3 +1453,3027 @@ fn test_weekday_modifiers_german() {
         assert_eq!(parsed, expected, "Mismatch for input: {input}");
     }
 }
+
+// ===== Compound Duration Tests =====
+
+fn relative_duration(components: &[(TimeUnit, i64)], direction: Direction) -> TimeExpression {
+    let duration = components
+        .iter()
+        .fold(Duration::default(), |duration, &(unit, amount)| {
+            duration.with(unit, amount)
+        });
+    TimeExpression::RelativeDuration(RelativeDuration {
+        duration,
+        direction,
+    })
+}
+
+#[test]
+fn test_compound_durations_english() {
+    let test_cases = vec![
+        (
+            "in 2 hours 30 minutes",
+            relative_duration(
+                &[(TimeUnit::Hour, 2), (TimeUnit::Minute, 30)],
+                Direction::Future,
+            ),
+        ),
+        (
+            "1 day and 3 hours ago",
+            relative_duration(&[(TimeUnit::Day, 1), (TimeUnit::Hour, 3)], Direction::Past),
+        ),
+        (
+            "in 1 year, 2 months and 3 days",
+            relative_duration(
+                &[
+                    (TimeUnit::Year, 1),
+                    (TimeUnit::Month, 2),
+                    (TimeUnit::Day, 3),
+                ],
+                Direction::Future,
+            ),
+        ),
+        (
+            "an hour and a minute ago",
+            relative_duration(
+                &[(TimeUnit::Hour, 1), (TimeUnit::Minute, 1)],
+                Direction::Past,
+            ),
+        ),
+        (
+            "in 30 minutes 2 hours",
+            relative_duration(
+                &[(TimeUnit::Hour, 2), (TimeUnit::Minute, 30)],
+                Direction::Future,
+            ),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::English).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_compound_durations_german() {
+    let test_cases = vec![
+        (
+            "in 2 Stunden und 30 Minuten",
+            relative_duration(
+                &[(TimeUnit::Hour, 2), (TimeUnit::Minute, 30)],
+                Direction::Future,
+            ),
+        ),
+        (
+            "vor 1 Tag 3 Stunden",
+            relative_duration(&[(TimeUnit::Day, 1), (TimeUnit::Hour, 3)], Direction::Past),
+        ),
+        (
+            "in einem Jahr, zwei Monaten und drei Tagen",
+            relative_duration(
+                &[
+                    (TimeUnit::Year, 1),
+                    (TimeUnit::Month, 2),
+                    (TimeUnit::Day, 3),
+                ],
+                Direction::Future,
+            ),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::German).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_single_unit_stays_relative() {
+    assert!(matches!(
+        parse("in 2 hours", Language::English).unwrap(),
+        TimeExpression::Relative(_)
+    ));
+    assert!(matches!(
+        parse("vor 2 Stunden", Language::German).unwrap(),
+        TimeExpression::Relative(_)
+    ));
+}
+
+#[test]
+fn test_compound_durations_reject_invalid_input() {
+    let english = [
+        "in 2 hours 3 hours",
+        "in 2 hours and",
+        "2 hours and ago",
+        "in 2 hours, , 30 minutes",
+    ];
+    for input in english {
+        assert!(
+            parse(input, Language::English).is_err(),
+            "Should fail: {input}"
+        );
+    }
+
+    let german = ["in 2 Stunden 3 Stunden", "vor 1 Tag und"];
+    for input in german {
+        assert!(
+            parse(input, Language::German).is_err(),
+            "Should fail: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_duration_components_largest_first() {
+    let duration = Duration::default()
+        .with(TimeUnit::Second, 5)
+        .with(TimeUnit::Year, 1)
+        .with(TimeUnit::Hour, 2);
+    assert_eq!(
+        duration.components().collect::<Vec<_>>(),
+        vec![
+            (TimeUnit::Year, 1),
+            (TimeUnit::Hour, 2),
+            (TimeUnit::Second, 5)
+        ]
+    );
+    assert!(!duration.is_zero());
+    assert!(Duration::default().is_zero());
+}
+
+// ===== Duration Parsing Tests =====
+
+#[test]
+fn test_parse_duration_english() {
+    let test_cases = vec![
+        ("90 minutes", Duration::default().with(TimeUnit::Minute, 90)),
+        (
+            "2h30m",
+            Duration::default()
+                .with(TimeUnit::Hour, 2)
+                .with(TimeUnit::Minute, 30),
+        ),
+        (
+            "2 hours and 30 minutes",
+            Duration::default()
+                .with(TimeUnit::Hour, 2)
+                .with(TimeUnit::Minute, 30),
+        ),
+        (
+            "1.5 days",
+            Duration::default()
+                .with(TimeUnit::Day, 1)
+                .with(TimeUnit::Hour, 12),
+        ),
+        (
+            "1.5 years",
+            Duration::default()
+                .with(TimeUnit::Year, 1)
+                .with(TimeUnit::Month, 6),
+        ),
+        ("0.25 hours", Duration::default().with(TimeUnit::Minute, 15)),
+        ("an hour", Duration::default().with(TimeUnit::Hour, 1)),
+        (
+            "1.5 seconds",
+            Duration {
+                seconds: 1,
+                nanoseconds: 500_000_000,
+                ..Duration::default()
+            },
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse_duration(input, Language::English).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_parse_duration_german() {
+    let test_cases = vec![
+        ("90 Minuten", Duration::default().with(TimeUnit::Minute, 90)),
+        (
+            "2 Stunden und 30 Minuten",
+            Duration::default()
+                .with(TimeUnit::Hour, 2)
+                .with(TimeUnit::Minute, 30),
+        ),
+        (
+            "1,5 Tage",
+            Duration::default()
+                .with(TimeUnit::Day, 1)
+                .with(TimeUnit::Hour, 12),
+        ),
+        ("eine Woche", Duration::default().with(TimeUnit::Week, 1)),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse_duration(input, Language::German).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_parse_duration_iso() {
+    let test_cases = vec![
+        (
+            "P1Y2M10DT2H30M",
+            Duration {
+                years: 1,
+                months: 2,
+                days: 10,
+                hours: 2,
+                minutes: 30,
+                ..Duration::default()
+            },
+        ),
+        ("P2W", Duration::default().with(TimeUnit::Week, 2)),
+        ("PT36H", Duration::default().with(TimeUnit::Hour, 36)),
+        (
+            "PT1.5S",
+            Duration {
+                seconds: 1,
+                nanoseconds: 500_000_000,
+                ..Duration::default()
+            },
+        ),
+        ("P0,5D", Duration::default().with(TimeUnit::Hour, 12)),
+    ];
+
+    for (input, expected) in test_cases {
+        for language in [Language::English, Language::German] {
+            assert_eq!(
+                parse_duration(input, language).unwrap(),
+                expected,
+                "Failed for input: {input}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_parse_duration_rejects_invalid_input() {
+    for input in [
+        "in 5 minutes",
+        "5 minutes ago",
+        "1.5 months",
+        "2 hours 3 hours",
+        "tomorrow",
+        "",
+        "P",
+        "PT",
+        "P1DT",
+        "P1H",
+        "P1.5M",
+    ] {
+        assert!(
+            parse_duration(input, Language::English).is_err(),
+            "Should fail: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_duration_to_std_duration() {
+    let duration = parse_duration("1 week 1.5 days", Language::English).unwrap();
+    assert_eq!(
+        std::time::Duration::try_from(duration).unwrap(),
+        std::time::Duration::from_secs((7 + 1) * 86_400 + 12 * 3600)
+    );
+
+    let duration = parse_duration("1.5 seconds", Language::English).unwrap();
+    assert_eq!(
+        std::time::Duration::try_from(duration).unwrap(),
+        std::time::Duration::from_millis(1500)
+    );
+
+    let duration = parse_duration("2 months", Language::English).unwrap();
+    assert!(matches!(
+        std::time::Duration::try_from(duration),
+        Err(TempsError::UnsupportedOperation { .. })
+    ));
+
+    let negative = Duration::default().with(TimeUnit::Minute, -1);
+    assert!(std::time::Duration::try_from(negative).is_err());
+}
+
+// ===== Relative Day With Time Tests =====
+
+fn day_time(day: DayReference, hour: u8, minute: u8, meridiem: Option<Meridiem>) -> TimeExpression {
+    TimeExpression::DayTime(DayTime {
+        day,
+        time: Time {
+            hour,
+            minute,
+            second: 0,
+            meridiem,
+        },
+    })
+}
+
+fn relative_day(amount: i64, unit: TimeUnit, direction: Direction) -> DayReference {
+    DayReference::Relative(RelativeTime {
+        amount,
+        unit,
+        direction,
+    })
+}
+
+#[test]
+fn test_relative_day_at_time_english() {
+    let test_cases = vec![
+        (
+            "in 3 days at 5pm",
+            day_time(
+                relative_day(3, TimeUnit::Day, Direction::Future),
+                5,
+                0,
+                Some(Meridiem::PM),
+            ),
+        ),
+        (
+            "2 weeks ago at 10:30",
+            day_time(
+                relative_day(2, TimeUnit::Week, Direction::Past),
+                10,
+                30,
+                None,
+            ),
+        ),
+        (
+            "in a month at 9:00 am",
+            day_time(
+                relative_day(1, TimeUnit::Month, Direction::Future),
+                9,
+                0,
+                Some(Meridiem::AM),
+            ),
+        ),
+        (
+            "next week Monday at 10:00",
+            day_time(
+                DayReference::WeekdayInWeek {
+                    day: Weekday::Monday,
+                    week_offset: 1,
+                },
+                10,
+                0,
+                None,
+            ),
+        ),
+        (
+            "friday last week at 4pm",
+            day_time(
+                DayReference::WeekdayInWeek {
+                    day: Weekday::Friday,
+                    week_offset: -1,
+                },
+                4,
+                0,
+                Some(Meridiem::PM),
+            ),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::English).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    assert_eq!(
+        parse("this week wednesday", Language::English).unwrap(),
+        TimeExpression::Day(DayReference::WeekdayInWeek {
+            day: Weekday::Wednesday,
+            week_offset: 0,
+        })
+    );
+    // Sub-day offsets cannot name a day
+    assert!(parse("in 3 hours at 5pm", Language::English).is_err());
+}
+
+#[test]
+fn test_relative_day_at_time_german() {
+    let test_cases = vec![
+        (
+            "vor 2 Tagen um 14:00",
+            day_time(relative_day(2, TimeUnit::Day, Direction::Past), 14, 0, None),
+        ),
+        (
+            "in 3 Tagen um 17:00 Uhr",
+            day_time(
+                relative_day(3, TimeUnit::Day, Direction::Future),
+                17,
+                0,
+                None,
+            ),
+        ),
+        (
+            "nächste Woche Montag um 10:00",
+            day_time(
+                DayReference::WeekdayInWeek {
+                    day: Weekday::Monday,
+                    week_offset: 1,
+                },
+                10,
+                0,
+                None,
+            ),
+        ),
+        (
+            "Freitag letzter Woche um 16:00",
+            day_time(
+                DayReference::WeekdayInWeek {
+                    day: Weekday::Friday,
+                    week_offset: -1,
+                },
+                16,
+                0,
+                None,
+            ),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::German).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    assert!(parse("in 3 Stunden um 17:00", Language::German).is_err());
+}
+
+// ===== Month Name Date Tests =====
+
+#[test]
+fn test_month_name_dates_english() {
+    let date = |day, month, year| TimeExpression::Date(StandardDate { day, month, year });
+    let test_cases = vec![
+        ("March 5th 2024", date(5, 3, 2024)),
+        ("March 5, 2024", date(5, 3, 2024)),
+        ("mar. 5 2024", date(5, 3, 2024)),
+        ("5 March 2024", date(5, 3, 2024)),
+        ("5th of March 2024", date(5, 3, 2024)),
+        ("1st January 2025", date(1, 1, 2025)),
+        ("December 22nd, 2024", date(22, 12, 2024)),
+        ("Sept 23rd 2024", date(23, 9, 2024)),
+        ("11th may 2024", date(11, 5, 2024)),
+        ("29 Feb 2024", date(29, 2, 2024)),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::English).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    for input in [
+        "March 5rd 2024",
+        "29 Feb 2023",
+        "March 32 2024",
+        "Marchh 5 2024",
+    ] {
+        assert!(
+            parse(input, Language::English).is_err(),
+            "Should fail: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_month_name_dates_german() {
+    let date = |day, month, year| TimeExpression::Date(StandardDate { day, month, year });
+    let test_cases = vec![
+        ("5. März 2024", date(5, 3, 2024)),
+        ("5.März 2024", date(5, 3, 2024)),
+        ("5 März 2024", date(5, 3, 2024)),
+        ("24. Dez. 2024", date(24, 12, 2024)),
+        ("1. Mai 2025", date(1, 5, 2025)),
+        ("3. Okt 2024", date(3, 10, 2024)),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::German).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    for input in ["31. Februar 2024", "5. märz 2024", "5. March 2024"] {
+        assert!(
+            parse(input, Language::German).is_err(),
+            "Should fail: {input}"
+        );
+    }
+}
+
+// ===== Dates Without Year Tests =====
+
+#[test]
+fn test_dates_without_year_english() {
+    let month_day = |day, month| TimeExpression::MonthDay(MonthDay { day, month });
+    let test_cases = vec![
+        ("March 5", month_day(5, 3)),
+        ("March 5th", month_day(5, 3)),
+        ("Dec 24", month_day(24, 12)),
+        ("5th of March", month_day(5, 3)),
+        ("5 March", month_day(5, 3)),
+        ("05/03", month_day(5, 3)),
+        ("29/02", month_day(29, 2)),
+        ("Feb 29th", month_day(29, 2)),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::English).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    for input in ["March 32", "30/02", "05-03", "Feb 30th"] {
+        assert!(
+            parse(input, Language::English).is_err(),
+            "Should fail: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_dates_without_year_german() {
+    let month_day = |day, month| TimeExpression::MonthDay(MonthDay { day, month });
+    let test_cases = vec![
+        ("5.3.", month_day(5, 3)),
+        ("24.12.", month_day(24, 12)),
+        ("am 24.12.", month_day(24, 12)),
+        ("Am 5. März", month_day(5, 3)),
+        ("5. März", month_day(5, 3)),
+        ("29. Feb.", month_day(29, 2)),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::German).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    assert_eq!(
+        parse("am 24.12.2024", Language::German).unwrap(),
+        TimeExpression::Date(StandardDate {
+            day: 24,
+            month: 12,
+            year: 2024
+        })
+    );
+
+    for input in ["30.02.", "24.12", "am24.12."] {
+        assert!(
+            parse(input, Language::German).is_err(),
+            "Should fail: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_resolve_month_day_year_policies() {
+    use temps_core::time_utils::resolve_month_day;
+
+    let today = StandardDate {
+        day: 15,
+        month: 6,
+        year: 2024,
+    };
+    let date = |day, month, year| StandardDate { day, month, year };
+    let test_cases = vec![
+        // (month day, policy, expected)
+        (
+            MonthDay { day: 5, month: 3 },
+            YearPolicy::Next,
+            date(5, 3, 2025),
+        ),
+        (
+            MonthDay { day: 5, month: 3 },
+            YearPolicy::Previous,
+            date(5, 3, 2024),
+        ),
+        (
+            MonthDay { day: 5, month: 3 },
+            YearPolicy::Nearest,
+            date(5, 3, 2024),
+        ),
+        (
+            MonthDay { day: 24, month: 12 },
+            YearPolicy::Next,
+            date(24, 12, 2024),
+        ),
+        (
+            MonthDay { day: 24, month: 12 },
+            YearPolicy::Previous,
+            date(24, 12, 2023),
+        ),
+        (
+            MonthDay { day: 24, month: 12 },
+            YearPolicy::Nearest,
+            date(24, 12, 2023),
+        ),
+        (
+            MonthDay { day: 1, month: 9 },
+            YearPolicy::Nearest,
+            date(1, 9, 2024),
+        ),
+        (
+            MonthDay { day: 1, month: 2 },
+            YearPolicy::Nearest,
+            date(1, 2, 2024),
+        ),
+        // Today counts as both the next and the previous occurrence
+        (
+            MonthDay { day: 15, month: 6 },
+            YearPolicy::Next,
+            date(15, 6, 2024),
+        ),
+        (
+            MonthDay { day: 15, month: 6 },
+            YearPolicy::Previous,
+            date(15, 6, 2024),
+        ),
+        // February 29 skips to the closest leap year
+        (
+            MonthDay { day: 29, month: 2 },
+            YearPolicy::Next,
+            date(29, 2, 2028),
+        ),
+        (
+            MonthDay { day: 29, month: 2 },
+            YearPolicy::Previous,
+            date(29, 2, 2024),
+        ),
+    ];
+
+    for (month_day, policy, expected) in test_cases {
+        assert_eq!(
+            resolve_month_day(today, month_day, policy),
+            Some(expected),
+            "Failed for {month_day:?} with {policy:?}"
+        );
+    }
+}
+
+// ===== Date Order Tests =====
+
+#[test]
+fn test_date_order_english() {
+    let date = |day, month, year| TimeExpression::Date(StandardDate { day, month, year });
+    let month_day = |day, month| TimeExpression::MonthDay(MonthDay { day, month });
+    let dmy = ParseOptions::new(Language::English).with_date_order(DateOrder::Dmy);
+    let mdy = ParseOptions::new(Language::English).with_date_order(DateOrder::Mdy);
+    let ymd = ParseOptions::new(Language::English).with_date_order(DateOrder::Ymd);
+
+    let test_cases = vec![
+        ("03/05/2024", dmy, date(3, 5, 2024)),
+        ("03/05/2024", mdy, date(5, 3, 2024)),
+        ("12-31-2024", mdy, date(31, 12, 2024)),
+        ("03/05", mdy, month_day(5, 3)),
+        ("2024/03/05", ymd, date(5, 3, 2024)),
+        ("03/05", ymd, month_day(5, 3)),
+        // Month names don't depend on the order
+        ("5th of March 2024", mdy, date(5, 3, 2024)),
+        ("March 5", ymd, month_day(5, 3)),
+    ];
+
+    for (input, options, expected) in test_cases {
+        assert_eq!(
+            parse(input, options).unwrap(),
+            expected,
+            "Failed for input: {input} with {options:?}"
+        );
+    }
+    assert_eq!(
+        parse("2024-03-05", mdy).unwrap(),
+        parse("2024-03-05", dmy).unwrap()
+    );
+
+    for (input, options) in [
+        ("31/12/2024", mdy),
+        ("02/29/2023", mdy),
+        ("03/05/2024", ymd),
+        ("2024/03/05", dmy),
+        ("2024/31/12", ymd),
+    ] {
+        assert!(
+            parse(input, options).is_err(),
+            "Should fail: {input} with {options:?}"
+        );
+    }
+}
+
+#[test]
+fn test_date_order_follows_locale() {
+    let date = |day, month, year| TimeExpression::Date(StandardDate { day, month, year });
+
+    assert_eq!(
+        parse("03/05/2024", Language::EnglishUs).unwrap(),
+        date(5, 3, 2024)
+    );
+    assert_eq!(
+        parse("03/05/2024", Language::EnglishGb).unwrap(),
+        date(3, 5, 2024)
+    );
+    assert_eq!(
+        parse("03/05/2024", Language::English).unwrap(),
+        date(3, 5, 2024)
+    );
+
+    // An explicit order wins over the locale
+    let options = ParseOptions::new(Language::EnglishUs).with_date_order(DateOrder::Dmy);
+    assert_eq!(parse("03/05/2024", options).unwrap(), date(3, 5, 2024));
+
+    // Everything else parses the same in both locales
+    for input in [
+        "in 3 hours",
+        "tomorrow at 3:30 pm",
+        "next Monday",
+        "March 5th, 2024",
+    ] {
+        assert_eq!(
+            parse(input, Language::EnglishUs).unwrap(),
+            parse(input, Language::EnglishGb).unwrap(),
+            "Mismatch for input: {input}"
+        );
+    }
+    assert_eq!(
+        parse_duration("2h30m", Language::EnglishUs).unwrap(),
+        parse_duration("2h30m", Language::English).unwrap()
+    );
+}
+
+// ===== Parse Candidate Tests =====
+
+#[test]
+fn test_parse_candidates_numeric_dates() {
+    let date = |day, month, year| TimeExpression::Date(StandardDate { day, month, year });
+    let month_day = |day, month| TimeExpression::MonthDay(MonthDay { day, month });
+
+    let candidates = parse_candidates("04/05/2024", Language::English).unwrap();
+    assert_eq!(
+        candidates,
+        vec![
+            Candidate {
+                expression: date(4, 5, 2024),
+                rule: Rule::NumericDate(DateOrder::Dmy),
+            },
+            Candidate {
+                expression: date(5, 4, 2024),
+                rule: Rule::NumericDate(DateOrder::Mdy),
+            },
+        ]
+    );
+
+    // The configured order comes first
+    let candidates = parse_candidates("04/05/2024", Language::EnglishUs).unwrap();
+    assert_eq!(candidates[0].expression, date(5, 4, 2024));
+    assert_eq!(candidates.len(), 2);
+
+    // Only one order forms a valid date
+    let candidates = parse_candidates("15/03/2024", Language::English).unwrap();
+    assert_eq!(candidates.len(), 1);
+
+    // Identical readings are reported once
+    let candidates = parse_candidates("05/05/2024", Language::English).unwrap();
+    assert_eq!(candidates.len(), 1);
+
+    let candidates = parse_candidates("04/05", Language::English).unwrap();
+    let expressions: Vec<_> = candidates.iter().map(|c| c.expression).collect();
+    assert_eq!(expressions, vec![month_day(4, 5), month_day(5, 4)]);
+
+    // German numeric dates are always day-first
+    let candidates = parse_candidates("04.05.2024", Language::German).unwrap();
+    assert_eq!(
+        candidates,
+        vec![Candidate {
+            expression: date(4, 5, 2024),
+            rule: Rule::NumericDate(DateOrder::Dmy),
+        }]
+    );
+}
+
+#[test]
+fn test_parse_candidates_weekdays() {
+    let weekday = |modifier| {
+        TimeExpression::Day(DayReference::Weekday {
+            day: Weekday::Monday,
+            modifier,
+        })
+    };
+    let in_week = |week_offset| {
+        TimeExpression::Day(DayReference::WeekdayInWeek {
+            day: Weekday::Monday,
+            week_offset,
+        })
+    };
+
+    for (input, language) in [("Monday", Language::English), ("Montag", Language::German)] {
+        assert_eq!(
+            parse_candidates(input, language).unwrap(),
+            vec![
+                Candidate {
+                    expression: weekday(None),
+                    rule: Rule::DayReference,
+                },
+                Candidate {
+                    expression: in_week(0),
+                    rule: Rule::WeekdayThisWeek,
+                },
+                Candidate {
+                    expression: in_week(1),
+                    rule: Rule::WeekdayNextWeek,
+                },
+            ],
+            "Failed for input: {input}"
+        );
+    }
+
+    let candidates = parse_candidates("next Monday", Language::English).unwrap();
+    let expressions: Vec<_> = candidates.iter().map(|c| c.expression).collect();
+    assert_eq!(
+        expressions,
+        vec![weekday(Some(WeekdayModifier::Next)), in_week(1)]
+    );
+
+    // Times are kept when a weekday is reinterpreted
+    let candidates = parse_candidates("Monday at 9:00 am", Language::English).unwrap();
+    assert_eq!(candidates.len(), 3);
+    assert!(candidates.iter().all(|c| matches!(
+        c.expression,
+        TimeExpression::DayTime(DayTime {
+            time: Time { hour: 9, .. },
+            ..
+        })
+    )));
+    assert_eq!(candidates[0].rule, Rule::DayAtTime);
+
+    // "last Monday" and "this week Monday" are not ambiguous
+    for input in ["last Monday", "this week Monday"] {
+        assert_eq!(
+            parse_candidates(input, Language::English).unwrap().len(),
+            1,
+            "Failed for input: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_parse_candidates_match_parse() {
+    for (input, language) in [
+        ("now", Language::English),
+        ("in 3 hours", Language::English),
+        ("2 days ago", Language::English),
+        ("tomorrow at 3:30 pm", Language::English),
+        ("2024-01-15T14:30:00Z", Language::English),
+        ("March 5th", Language::English),
+        ("14:30", Language::English),
+        ("vor 2 Stunden", Language::German),
+        ("am 24.12.", Language::German),
+        ("morgen um 15:30", Language::German),
+    ] {
+        let candidates = parse_candidates(input, language).unwrap();
+        assert_eq!(
+            candidates[0].expression,
+            parse(input, language).unwrap(),
+            "Mismatch for input: {input}"
+        );
+    }
+
+    assert!(parse_candidates("invalid input", Language::English).is_err());
+    assert!(parse_candidates("", Language::German).is_err());
+}
+
+// ===== Interval Tests =====
+
+fn interval(start: Endpoint, end: Endpoint) -> TimeExpression {
+    TimeExpression::Interval(Interval { start, end })
+}
+
+fn at(hour: u8, minute: u8, meridiem: Option<Meridiem>) -> Endpoint {
+    Endpoint::Time(Time {
+        hour,
+        minute,
+        second: 0,
+        meridiem,
+    })
+}
+
+#[test]
+fn test_interval_parsing_english() {
+    let weekday = |day| {
+        Endpoint::Day(DayReference::Weekday {
+            day,
+            modifier: None,
+        })
+    };
+    let pm = Some(Meridiem::PM);
+    let am = Some(Meridiem::AM);
+    let test_cases = vec![
+        ("from 3pm to 5pm", interval(at(3, 0, pm), at(5, 0, pm))),
+        (
+            "from 9:00 until 17:30",
+            interval(at(9, 0, None), at(17, 30, None)),
+        ),
+        (
+            "between Monday and Friday",
+            interval(weekday(Weekday::Monday), weekday(Weekday::Friday)),
+        ),
+        ("3-5pm", interval(at(3, 0, pm), at(5, 0, pm))),
+        ("11-1pm", interval(at(11, 0, am), at(1, 0, pm))),
+        ("9am - 5pm", interval(at(9, 0, am), at(5, 0, pm))),
+        ("9:00-17:00", interval(at(9, 0, None), at(17, 0, None))),
+        (
+            "from tomorrow at 3pm to 5pm",
+            interval(
+                Endpoint::DayTime(DayTime {
+                    day: DayReference::Tomorrow,
+                    time: Time {
+                        hour: 3,
+                        minute: 0,
+                        second: 0,
+                        meridiem: pm,
+                    },
+                }),
+                at(5, 0, pm),
+            ),
+        ),
+        (
+            "from March 5 to March 10",
+            interval(
+                Endpoint::MonthDay(MonthDay { day: 5, month: 3 }),
+                Endpoint::MonthDay(MonthDay { day: 10, month: 3 }),
+            ),
+        ),
+        (
+            "FROM now TO in 2 hours",
+            interval(
+                Endpoint::Now,
+                Endpoint::Relative(RelativeTime {
+                    amount: 2,
+                    unit: TimeUnit::Hour,
+                    direction: Direction::Future,
+                }),
+            ),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::English).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    for input in ["from 3pm", "between Monday", "from 3pm to", "13-5pm", "3-5"] {
+        assert!(
+            parse(input, Language::English).is_err(),
+            "Should fail: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_interval_parsing_german() {
+    let weekday = |day| {
+        Endpoint::Day(DayReference::Weekday {
+            day,
+            modifier: None,
+        })
+    };
+    let test_cases = vec![
+        (
+            "von 9:00 bis 17:00",
+            interval(at(9, 0, None), at(17, 0, None)),
+        ),
+        (
+            "von 9 bis 17 Uhr",
+            interval(at(9, 0, None), at(17, 0, None)),
+        ),
+        ("15-17 Uhr", interval(at(15, 0, None), at(17, 0, None))),
+        (
+            "von 15 Uhr bis 17 Uhr",
+            interval(at(15, 0, None), at(17, 0, None)),
+        ),
+        (
+            "15:00 - 17:30 Uhr",
+            interval(at(15, 0, None), at(17, 30, None)),
+        ),
+        (
+            "von Montag bis Freitag",
+            interval(weekday(Weekday::Monday), weekday(Weekday::Friday)),
+        ),
+        (
+            "zwischen Montag und Mittwoch",
+            interval(weekday(Weekday::Monday), weekday(Weekday::Wednesday)),
+        ),
+        (
+            "vom 24.12. bis zum 26.12.",
+            interval(
+                Endpoint::MonthDay(MonthDay { day: 24, month: 12 }),
+                Endpoint::MonthDay(MonthDay { day: 26, month: 12 }),
+            ),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        assert_eq!(
+            parse(input, Language::German).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    for input in ["von Montag", "zwischen Montag und", "15-25 Uhr", "15-17"] {
+        assert!(
+            parse(input, Language::German).is_err(),
+            "Should fail: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_endpoint_conversions() {
+    let expr = TimeExpression::Day(DayReference::Tomorrow);
+    let endpoint = Endpoint::try_from(expr).unwrap();
+    assert_eq!(endpoint, Endpoint::Day(DayReference::Tomorrow));
+    assert_eq!(TimeExpression::from(endpoint), expr);
+
+    let nested = interval(Endpoint::Now, Endpoint::Now);
+    assert!(Endpoint::try_from(nested).is_err());
+}
+
+// ===== Period Tests =====
+
+#[test]
+fn test_period_parsing() {
+    let period = |unit, offset| TimeExpression::Period(Period { unit, offset });
+    let test_cases = vec![
+        ("last week", Language::English, period(PeriodUnit::Week, -1)),
+        (
+            "this month",
+            Language::English,
+            period(PeriodUnit::Month, 0),
+        ),
+        (
+            "Next Quarter",
+            Language::English,
+            period(PeriodUnit::Quarter, 1),
+        ),
+        ("next year", Language::English, period(PeriodUnit::Year, 1)),
+        (
+            "letzte Woche",
+            Language::German,
+            period(PeriodUnit::Week, -1),
+        ),
+        (
+            "diesen Monat",
+            Language::German,
+            period(PeriodUnit::Month, 0),
+        ),
+        (
+            "letzten Monat",
+            Language::German,
+            period(PeriodUnit::Month, -1),
+        ),
+        (
+            "nächstes Quartal",
+            Language::German,
+            period(PeriodUnit::Quarter, 1),
+        ),
+        (
+            "Nächstes Jahr",
+            Language::German,
+            period(PeriodUnit::Year, 1),
+        ),
+        ("dieses Jahr", Language::German, period(PeriodUnit::Year, 0)),
+    ];
+
+    for (input, language, expected) in test_cases {
+        assert_eq!(
+            parse(input, language).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    // Weekdays in a week still take precedence
+    assert_eq!(
+        parse("next week Monday", Language::English).unwrap(),
+        TimeExpression::Day(DayReference::WeekdayInWeek {
+            day: Weekday::Monday,
+            week_offset: 1,
+        })
+    );
+
+    for (input, language) in [
+        ("last decade", Language::English),
+        ("next weeks", Language::English),
+        ("letzt Woche", Language::German),
+        ("nächstes jahr", Language::German),
+    ] {
+        assert!(parse(input, language).is_err(), "Should fail: {input}");
+    }
+}
+
+#[test]
+fn test_boundary_parsing() {
+    let boundary = |edge, unit, offset| {
+        TimeExpression::Boundary(Boundary {
+            edge,
+            period: Period { unit, offset },
+        })
+    };
+    let test_cases = vec![
+        (
+            "end of month",
+            Language::English,
+            boundary(Edge::End, PeriodUnit::Month, 0),
+        ),
+        (
+            "start of next week",
+            Language::English,
+            boundary(Edge::Start, PeriodUnit::Week, 1),
+        ),
+        (
+            "beginning of the quarter",
+            Language::English,
+            boundary(Edge::Start, PeriodUnit::Quarter, 0),
+        ),
+        (
+            "End of last year",
+            Language::English,
+            boundary(Edge::End, PeriodUnit::Year, -1),
+        ),
+        (
+            "end of the day",
+            Language::English,
+            boundary(Edge::End, PeriodUnit::Day, 0),
+        ),
+        (
+            "EOD",
+            Language::English,
+            boundary(Edge::End, PeriodUnit::Day, 0),
+        ),
+        (
+            "Ende des Jahres",
+            Language::German,
+            boundary(Edge::End, PeriodUnit::Year, 0),
+        ),
+        (
+            "Anfang nächster Woche",
+            Language::German,
+            boundary(Edge::Start, PeriodUnit::Week, 1),
+        ),
+        (
+            "Beginn des nächsten Quartals",
+            Language::German,
+            boundary(Edge::Start, PeriodUnit::Quarter, 1),
+        ),
+        (
+            "Ende letzten Monats",
+            Language::German,
+            boundary(Edge::End, PeriodUnit::Month, -1),
+        ),
+        (
+            "Ende des Tages",
+            Language::German,
+            boundary(Edge::End, PeriodUnit::Day, 0),
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        assert_eq!(
+            parse(input, language).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    // Boundaries can end an interval
+    assert_eq!(
+        parse("from today until end of month", Language::English).unwrap(),
+        TimeExpression::Interval(Interval {
+            start: Endpoint::Day(DayReference::Today),
+            end: Endpoint::Boundary(Boundary {
+                edge: Edge::End,
+                period: Period {
+                    unit: PeriodUnit::Month,
+                    offset: 0,
+                },
+            }),
+        })
+    );
+
+    for (input, language) in [
+        ("end of decade", Language::English),
+        ("middle of month", Language::English),
+        ("EODs", Language::English),
+        ("Ende des Monat", Language::German),
+    ] {
+        assert!(parse(input, language).is_err(), "Should fail: {input}");
+    }
+}
+
+#[test]
+fn test_days_from_week_start() {
+    use temps_core::time_utils::{calculate_week_weekday_offset, days_from_week_start};
+
+    for monday_offset in 0..7 {
+        assert_eq!(
+            days_from_week_start(monday_offset, WeekStart::Monday),
+            monday_offset
+        );
+    }
+    // Sunday opens US weeks and Saturday closes them
+    assert_eq!(days_from_week_start(6, WeekStart::Sunday), 0);
+    assert_eq!(days_from_week_start(5, WeekStart::Sunday), 6);
+    assert_eq!(days_from_week_start(5, WeekStart::Saturday), 0);
+    assert_eq!(days_from_week_start(4, WeekStart::Saturday), 6);
+
+    // From a Sunday, Monday of this week is tomorrow in a US week
+    // and six days ago in an ISO week
+    let sunday = 6;
+    let monday = 0;
+    let offset = |week_start| {
+        calculate_week_weekday_offset(
+            days_from_week_start(sunday, week_start),
+            days_from_week_start(monday, week_start),
+            0,
+        )
+    };
+    assert_eq!(offset(WeekStart::Sunday), 1);
+    assert_eq!(offset(WeekStart::Monday), -6);
+}
+
+#[test]
+fn test_recurrence_parsing() {
+    let time = |hour, minute, meridiem| Time {
+        hour,
+        minute,
+        second: 0,
+        meridiem,
+    };
+    let recurrence = |frequency, interval, weekdays: &[Weekday], time, end| {
+        TimeExpression::Recurrence(Recurrence {
+            frequency,
+            interval,
+            weekdays: weekdays.iter().copied().collect(),
+            time,
+            end,
+        })
+    };
+    let new_years_eve = StandardDate {
+        day: 31,
+        month: 12,
+        year: 2024,
+    };
+
+    let test_cases = vec![
+        (
+            "every Monday at 9am",
+            Language::English,
+            recurrence(
+                Frequency::Weekly,
+                1,
+                &[Weekday::Monday],
+                Some(time(9, 0, Some(Meridiem::AM))),
+                None,
+            ),
+        ),
+        (
+            "every other Friday",
+            Language::English,
+            recurrence(Frequency::Weekly, 2, &[Weekday::Friday], None, None),
+        ),
+        (
+            "every 2 weeks on Mon, Wed and Fri",
+            Language::English,
+            recurrence(
+                Frequency::Weekly,
+                2,
+                &[Weekday::Monday, Weekday::Wednesday, Weekday::Friday],
+                None,
+                None,
+            ),
+        ),
+        (
+            "each Tuesday and Thursday at 14:00",
+            Language::English,
+            recurrence(
+                Frequency::Weekly,
+                1,
+                &[Weekday::Tuesday, Weekday::Thursday],
+                Some(time(14, 0, None)),
+                None,
+            ),
+        ),
+        (
+            "every 15 minutes",
+            Language::English,
+            recurrence(Frequency::Minutely, 15, &[], None, None),
+        ),
+        (
+            "daily at 6pm until 31/12/2024",
+            Language::English,
+            recurrence(
+                Frequency::Daily,
+                1,
+                &[],
+                Some(time(6, 0, Some(Meridiem::PM))),
+                Some(RecurrenceEnd::Until(new_years_eve)),
+            ),
+        ),
+        (
+            "every day until March 5th",
+            Language::English,
+            recurrence(
+                Frequency::Daily,
+                1,
+                &[],
+                None,
+                Some(RecurrenceEnd::UntilMonthDay(MonthDay { day: 5, month: 3 })),
+            ),
+        ),
+        (
+            "every Monday, 3 times",
+            Language::English,
+            recurrence(
+                Frequency::Weekly,
+                1,
+                &[Weekday::Monday],
+                None,
+                Some(RecurrenceEnd::Count(3)),
+            ),
+        ),
+        (
+            "every month for 3 times",
+            Language::English,
+            recurrence(
+                Frequency::Monthly,
+                1,
+                &[],
+                None,
+                Some(RecurrenceEnd::Count(3)),
+            ),
+        ),
+        (
+            "Each year",
+            Language::English,
+            recurrence(Frequency::Yearly, 1, &[], None, None),
+        ),
+        (
+            "jeden Montag um 9 Uhr",
+            Language::German,
+            recurrence(
+                Frequency::Weekly,
+                1,
+                &[Weekday::Monday],
+                Some(time(9, 0, None)),
+                None,
+            ),
+        ),
+        (
+            "jeden zweiten Freitag",
+            Language::German,
+            recurrence(Frequency::Weekly, 2, &[Weekday::Friday], None, None),
+        ),
+        (
+            "alle 2 Wochen am Montag und Donnerstag",
+            Language::German,
+            recurrence(
+                Frequency::Weekly,
+                2,
+                &[Weekday::Monday, Weekday::Thursday],
+                None,
+                None,
+            ),
+        ),
+        (
+            "jede Stunde",
+            Language::German,
+            recurrence(Frequency::Hourly, 1, &[], None, None),
+        ),
+        (
+            "täglich um 18:00 bis 31.12.2024",
+            Language::German,
+            recurrence(
+                Frequency::Daily,
+                1,
+                &[],
+                Some(time(18, 0, None)),
+                Some(RecurrenceEnd::Until(new_years_eve)),
+            ),
+        ),
+        (
+            "jeden Montag bis 5. März",
+            Language::German,
+            recurrence(
+                Frequency::Weekly,
+                1,
+                &[Weekday::Monday],
+                None,
+                Some(RecurrenceEnd::UntilMonthDay(MonthDay { day: 5, month: 3 })),
+            ),
+        ),
+        (
+            "jeden Montag, 3 Mal",
+            Language::German,
+            recurrence(
+                Frequency::Weekly,
+                1,
+                &[Weekday::Monday],
+                None,
+                Some(RecurrenceEnd::Count(3)),
+            ),
+        ),
+        (
+            "jeden Monat 10 Mal",
+            Language::German,
+            recurrence(
+                Frequency::Monthly,
+                1,
+                &[],
+                None,
+                Some(RecurrenceEnd::Count(10)),
+            ),
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        assert_eq!(
+            parse(input, language).unwrap(),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    // Workdays
+    for (input, language) in [
+        ("every weekday", Language::English),
+        ("jeden Werktag", Language::German),
+    ] {
+        assert_eq!(
+            parse(input, language).unwrap(),
+            TimeExpression::Recurrence(Recurrence {
+                frequency: Frequency::Weekly,
+                interval: 1,
+                weekdays: WeekdaySet::WORKDAYS,
+                time: None,
+                end: None,
+            }),
+            "Failed for input: {input}"
+        );
+    }
+
+    for (input, language) in [
+        ("every", Language::English),
+        ("every 0 days", Language::English),
+        ("every Monday until", Language::English),
+        ("jeden", Language::German),
+        ("alle Wochen", Language::German),
+    ] {
+        assert!(parse(input, language).is_err(), "Should fail: {input}");
+    }
+}
+
+#[test]
+fn test_recurrence_dates() {
+    use temps_core::time_utils::recurrence_dates;
+
+    let date = |year, month, day| StandardDate { day, month, year };
+    let recurrence = |frequency, interval, weekdays: &[Weekday], end| Recurrence {
+        frequency,
+        interval,
+        weekdays: weekdays.iter().copied().collect(),
+        time: None,
+        end,
+    };
+    // Friday
+    let today = date(2024, 3, 15);
+    let dates = |recurrence, week_start, count| {
+        recurrence_dates(today, recurrence, week_start)
+            .take(count)
+            .collect::<Vec<_>>()
+    };
+
+    // The first period starts before today
+    assert_eq!(
+        dates(
+            recurrence(
+                Frequency::Weekly,
+                1,
+                &[Weekday::Monday, Weekday::Friday],
+                None
+            ),
+            WeekStart::Monday,
+            3
+        ),
+        [date(2024, 3, 11), date(2024, 3, 15), date(2024, 3, 18)]
+    );
+    // and depends on the week start
+    assert_eq!(
+        dates(
+            recurrence(Frequency::Weekly, 2, &[Weekday::Sunday], None),
+            WeekStart::Sunday,
+            2
+        ),
+        [date(2024, 3, 10), date(2024, 3, 24)]
+    );
+    assert_eq!(
+        dates(
+            recurrence(Frequency::Weekly, 2, &[Weekday::Sunday], None),
+            WeekStart::Monday,
+            2
+        ),
+        [date(2024, 3, 17), date(2024, 3, 31)]
+    );
+    // Without weekdays, weekly recurrences fall on today's weekday
+    assert_eq!(
+        dates(
+            recurrence(Frequency::Weekly, 1, &[], None),
+            WeekStart::Monday,
+            2
+        ),
+        [date(2024, 3, 15), date(2024, 3, 22)]
+    );
+    // Daily recurrences can be limited to weekdays
+    assert_eq!(
+        dates(
+            recurrence(Frequency::Daily, 1, &[Weekday::Saturday], None),
+            WeekStart::Monday,
+            2
+        ),
+        [date(2024, 3, 16), date(2024, 3, 23)]
+    );
+    // Monthly recurrences with weekdays fall on every matching day
+    assert_eq!(
+        dates(
+            recurrence(Frequency::Monthly, 1, &[Weekday::Friday], None),
+            WeekStart::Monday,
+            6
+        ),
+        [
+            date(2024, 3, 1),
+            date(2024, 3, 8),
+            date(2024, 3, 15),
+            date(2024, 3, 22),
+            date(2024, 3, 29),
+            date(2024, 4, 5),
+        ]
+    );
+    // Until is inclusive
+    assert_eq!(
+        dates(
+            recurrence(
+                Frequency::Daily,
+                1,
+                &[],
+                Some(RecurrenceEnd::Until(date(2024, 3, 17)))
+            ),
+            WeekStart::Monday,
+            10
+        ),
+        [date(2024, 3, 15), date(2024, 3, 16), date(2024, 3, 17)]
+    );
+    // A day of the year ends at its next occurrence
+    assert_eq!(
+        dates(
+            recurrence(
+                Frequency::Daily,
+                1,
+                &[],
+                Some(RecurrenceEnd::UntilMonthDay(MonthDay { day: 17, month: 3 }))
+            ),
+            WeekStart::Monday,
+            10
+        ),
+        [date(2024, 3, 15), date(2024, 3, 16), date(2024, 3, 17)]
+    );
+    // Leap days recur in leap years only
+    let leap_day = date(2024, 2, 29);
+    let yearly = recurrence(Frequency::Yearly, 1, &[], None);
+    assert_eq!(
+        recurrence_dates(leap_day, yearly, WeekStart::Monday)
+            .take(2)
+            .collect::<Vec<_>>(),
+        [leap_day, date(2028, 2, 29)]
+    );
+    // Sub-daily recurrences are not made of days
+    assert_eq!(
+        dates(
+            recurrence(Frequency::Hourly, 1, &[], None),
+            WeekStart::Monday,
+            1
+        ),
+        []
+    );
+}
+
+#[test]
+fn test_period_month_bounds() {
+    use temps_core::time_utils::period_month_bounds;
+
+    let test_cases = vec![
+        // (year, month, unit, offset, expected)
+        (2024, 3, PeriodUnit::Month, 0, ((2024, 3), (2024, 4))),
+        (2024, 1, PeriodUnit::Month, -1, ((2023, 12), (2024, 1))),
+        (2024, 12, PeriodUnit::Month, 1, ((2025, 1), (2025, 2))),
+        (2024, 3, PeriodUnit::Quarter, 0, ((2024, 1), (2024, 4))),
+        (2024, 3, PeriodUnit::Quarter, -1, ((2023, 10), (2024, 1))),
+        (2024, 11, PeriodUnit::Quarter, 1, ((2025, 1), (2025, 4))),
+        (2024, 7, PeriodUnit::Year, 1, ((2025, 1), (2026, 1))),
+        (2024, 7, PeriodUnit::Year, -2, ((2022, 1), (2023, 1))),
+    ];
+
+    for (year, month, unit, offset, expected) in test_cases {
+        assert_eq!(
+            period_month_bounds(year, month, unit, offset),
+            Some(expected),
+            "Failed for {year}-{month} {unit:?} {offset}"
+        );
+    }
+
+    assert_eq!(period_month_bounds(2024, 3, PeriodUnit::Week, 0), None);
+    assert_eq!(
+        period_month_bounds(2024, 3, PeriodUnit::Year, i64::MAX),
+        None
+    );
+}
+
+#[test]
+fn test_rrule_parsing() {
+    let rule = parse_rrule(
+        "DTSTART:19970902T090000Z\r\nRRULE:FREQ=WEEKLY;INTERVAL=2;WKST=SU;BYDAY=TU,TH;UNTIL=19971224",
+    )
+    .unwrap();
+    assert_eq!(
+        rule.start,
+        Some(AbsoluteTime {
+            year: 1997,
+            month: 9,
+            day: 2,
+            hour: Some(9),
+            minute: Some(0),
+            second: Some(0),
+            nanosecond: None,
+            timezone: Some(Timezone::Utc),
+        })
+    );
+    assert_eq!(rule.frequency, Frequency::Weekly);
+    assert_eq!(rule.interval, 2);
+    assert_eq!(rule.week_start, WeekStart::Sunday);
+    assert_eq!(
+        rule.by_day,
+        [
+            ByDay::from(Weekday::Tuesday),
+            ByDay::from(Weekday::Thursday)
+        ]
+    );
+    assert!(matches!(
+        rule.end,
+        Some(RRuleEnd::Until(AbsoluteTime {
+            day: 24,
+            hour: None,
+            ..
+        }))
+    ));
+
+    let rule: RRule = "freq=yearly;bymonth=1;byday=-2mo,1su;bymonthday=1,-1;bysetpos=1,-1"
+        .parse()
+        .unwrap();
+    assert_eq!(rule.by_month, [1]);
+    assert_eq!(rule.by_month_day, [1, -1]);
+    assert_eq!(rule.by_set_pos, [1, -1]);
+    assert_eq!(
+        rule.by_day,
+        [
+            ByDay {
+                weekday: Weekday::Monday,
+                ordinal: Some(-2)
+            },
+            ByDay {
+                weekday: Weekday::Sunday,
+                ordinal: Some(1)
+            },
+        ]
+    );
+
+    // Serialization writes parts in a fixed order and skips defaults
+    let round_trips = vec![
+        "RRULE:FREQ=DAILY",
+        "RRULE:FREQ=MINUTELY;INTERVAL=15;BYHOUR=9,10,11",
+        "DTSTART;VALUE=DATE:20240229\nRRULE:FREQ=YEARLY",
+        "DTSTART;TZID=America/New_York:20240315T090000\nRRULE:FREQ=MONTHLY;COUNT=5;BYDAY=2TU",
+        "RRULE:FREQ=YEARLY;UNTIL=20301231T235959Z;BYMONTH=3;BYMONTHDAY=-1;BYHOUR=0;BYMINUTE=30;BYSECOND=15;BYSETPOS=1;WKST=SA",
+    ];
+    for text in round_trips {
+        let rule = parse_rrule(text).unwrap();
+        assert_eq!(rule.to_string(), text);
+        assert_eq!(parse_rrule(&rule.to_string()).unwrap(), rule);
+    }
+    assert_eq!(
+        parse_rrule("FREQ=WEEKLY;BYDAY=MO;INTERVAL=1;WKST=MO")
+            .unwrap()
+            .to_string(),
+        "RRULE:FREQ=WEEKLY;BYDAY=MO"
+    );
+
+    // Fixed offsets are written in UTC
+    let mut rule = RRule::new(Frequency::Daily);
+    rule.end = Some(RRuleEnd::Until(AbsoluteTime {
+        year: 2024,
+        month: 12,
+        day: 31,
+        hour: Some(23),
+        minute: Some(30),
+        second: Some(0),
+        nanosecond: None,
+        timezone: Some(Timezone::Offset {
+            hours: -2,
+            minutes: 0,
+        }),
+    }));
+    assert_eq!(rule.to_string(), "RRULE:FREQ=DAILY;UNTIL=20250101T013000Z");
+
+    let errors = vec![
+        ("", errors::ERR_RRULE_MISSING_FREQ),
+        ("INTERVAL=2", errors::ERR_RRULE_MISSING_FREQ),
+        ("DTSTART:20240315T090000", errors::ERR_RRULE_MISSING_FREQ),
+        ("FREQ=FORTNIGHTLY", errors::ERR_RRULE_INVALID_PART),
+        ("FREQ=DAILY;INTERVAL=0", errors::ERR_RRULE_INVALID_PART),
+        ("FREQ=DAILY;FREQ=WEEKLY", errors::ERR_RRULE_INVALID_PART),
+        ("FREQ=DAILY;BYMONTH=13", errors::ERR_RRULE_INVALID_PART),
+        ("FREQ=MONTHLY;BYMONTHDAY=0", errors::ERR_RRULE_INVALID_PART),
+        ("FREQ=MONTHLY;BYDAY=0MO", errors::ERR_RRULE_INVALID_PART),
+        ("FREQ=WEEKLY;BYDAY=1MO", errors::ERR_RRULE_INVALID_PART),
+        ("FREQ=WEEKLY;BYMONTHDAY=1", errors::ERR_RRULE_INVALID_PART),
+        ("FREQ=DAILY;UNTIL=20240230", errors::ERR_RRULE_INVALID_PART),
+        ("FREQ=DAILY;BYHOUR=9;", errors::ERR_RRULE_INVALID_PART),
+        ("RRULE;X=1:FREQ=DAILY", errors::ERR_RRULE_INVALID_PART),
+        (
+            "EXDATE:20240315\nRRULE:FREQ=DAILY",
+            errors::ERR_RRULE_INVALID_PART,
+        ),
+        ("FREQ=SECONDLY", errors::ERR_RRULE_UNSUPPORTED_PART),
+        (
+            "FREQ=YEARLY;BYWEEKNO=20",
+            errors::ERR_RRULE_UNSUPPORTED_PART,
+        ),
+        ("FREQ=WEEKLY;WKST=WE", errors::ERR_RRULE_UNSUPPORTED_PART),
+        (
+            "FREQ=DAILY;COUNT=3;UNTIL=20241231",
+            errors::ERR_RRULE_COUNT_AND_UNTIL,
+        ),
+    ];
+    for (input, message) in errors {
+        match parse_rrule(input) {
+            Err(TempsError::ParseError {
+                message: actual, ..
+            }) => assert_eq!(actual, message, "Wrong error for input: {input}"),
+            other => panic!("Expected a parse error for {input}, got {other:?}"),
+        }
+    }
+
+    // Errors point at the offending part
+    assert!(matches!(
+        parse_rrule("RRULE:FREQ=DAILY;BYHOUR=24"),
+        Err(TempsError::ParseError {
+            position: Some(17),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_rrule_occurrences() {
+    use temps_core::rrule::rrule_occurrences;
+
+    let date = |year, month, day| StandardDate { day, month, year };
+    let time = |hour, minute| Time {
+        hour,
+        minute,
+        second: 0,
+        meridiem: None,
+    };
+    let occurrences = |rule: &str, start, at, count| {
+        rrule_occurrences(&parse_rrule(rule).unwrap(), start, at)
+            .take(count)
+            .collect::<Vec<_>>()
+    };
+    let dates = |rule: &str, start, count| {
+        occurrences(rule, start, time(9, 0), count)
+            .into_iter()
+            .map(|(date, _)| date)
+            .collect::<Vec<_>>()
+    };
+    // Tuesday
+    let start = date(1997, 9, 2);
+
+    // Examples from RFC 5545
+    assert_eq!(
+        dates("FREQ=WEEKLY;INTERVAL=2;WKST=SU;BYDAY=TU,TH", start, 8),
+        [
+            date(1997, 9, 2),
+            date(1997, 9, 4),
+            date(1997, 9, 16),
+            date(1997, 9, 18),
+            date(1997, 9, 30),
+            date(1997, 10, 2),
+            date(1997, 10, 14),
+            date(1997, 10, 16),
+        ]
+    );
+    assert_eq!(
+        dates("FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13", start, 3),
+        [date(1998, 2, 13), date(1998, 3, 13), date(1998, 11, 13)]
+    );
+    assert_eq!(
+        dates("FREQ=MONTHLY;BYDAY=1FR", start, 3),
+        [date(1997, 9, 5), date(1997, 10, 3), date(1997, 11, 7)]
+    );
+    assert_eq!(
+        dates("FREQ=YEARLY;BYDAY=20MO", date(1997, 5, 19), 3),
+        [date(1997, 5, 19), date(1998, 5, 18), date(1999, 5, 17)]
+    );
+    assert_eq!(
+        dates("FREQ=YEARLY;INTERVAL=2;BYMONTH=1;BYDAY=SU", start, 3),
+        [date(1999, 1, 3), date(1999, 1, 10), date(1999, 1, 17)]
+    );
+    // The last workday of the month
+    assert_eq!(
+        dates("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", start, 3),
+        [date(1997, 9, 30), date(1997, 10, 31), date(1997, 11, 28)]
+    );
+
+    // Open parts come from the start, skipping days a month or year lacks
+    assert_eq!(
+        dates("FREQ=MONTHLY", date(2024, 1, 31), 3),
+        [date(2024, 1, 31), date(2024, 3, 31), date(2024, 5, 31)]
+    );
+    assert_eq!(
+        dates("FREQ=MONTHLY;BYMONTHDAY=-1", date(2024, 1, 15), 3),
+        [date(2024, 1, 31), date(2024, 2, 29), date(2024, 3, 31)]
+    );
+    assert_eq!(
+        dates("FREQ=YEARLY", date(2024, 2, 29), 2),
+        [date(2024, 2, 29), date(2028, 2, 29)]
+    );
+    assert_eq!(
+        dates("FREQ=YEARLY;BYMONTH=3,6", date(2024, 1, 10), 3),
+        [date(2024, 3, 10), date(2024, 6, 10), date(2025, 3, 10)]
+    );
+    assert_eq!(
+        dates("FREQ=DAILY;INTERVAL=10;BYMONTH=1", date(2024, 1, 1), 5),
+        [
+            date(2024, 1, 1),
+            date(2024, 1, 11),
+            date(2024, 1, 21),
+            date(2024, 1, 31),
+            date(2025, 1, 5),
+        ]
+    );
+
+    // Times of day
+    assert_eq!(
+        occurrences("FREQ=DAILY;BYHOUR=17,9", start, time(9, 30), 3),
+        [
+            (start, time(9, 30)),
+            (start, time(17, 30)),
+            (date(1997, 9, 3), time(9, 30)),
+        ]
+    );
+    assert_eq!(
+        occurrences("FREQ=HOURLY;INTERVAL=5", start, time(15, 0), 3),
+        [
+            (start, time(15, 0)),
+            (start, time(20, 0)),
+            (date(1997, 9, 3), time(1, 0)),
+        ]
+    );
+    assert_eq!(
+        occurrences(
+            "FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10",
+            start,
+            time(9, 0),
+            7
+        ),
+        [
+            (start, time(9, 0)),
+            (start, time(9, 20)),
+            (start, time(9, 40)),
+            (start, time(10, 0)),
+            (start, time(10, 20)),
+            (start, time(10, 40)),
+            (date(1997, 9, 3), time(9, 0)),
+        ]
+    );
+    // Sub-daily rules skip days that do not match
+    assert_eq!(
+        occurrences("FREQ=HOURLY;INTERVAL=7;BYDAY=SA", start, time(0, 0), 2),
+        [
+            (date(1997, 9, 6), time(2, 0)),
+            (date(1997, 9, 6), time(9, 0)),
+        ]
+    );
+
+    // Rules that never match end the iteration
+    assert_eq!(dates("FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30", start, 1), []);
+    assert_eq!(
+        dates(
+            "FREQ=YEARLY;INTERVAL=4;BYMONTH=2;BYMONTHDAY=29",
+            date(2021, 1, 1),
+            1
+        ),
+        []
+    );
+}
+
+#[test]
+fn test_cron_parsing() {
+    let values = |field: CronField| field.iter().collect::<Vec<_>>();
+
+    let schedule = parse_cron("*/15 9-17 * * MON-FRI").unwrap();
+    assert_eq!(values(schedule.seconds), [0]);
+    assert_eq!(values(schedule.minutes), [0, 15, 30, 45]);
+    assert_eq!(values(schedule.hours), (9..=17).collect::<Vec<_>>());
+    assert_eq!(values(schedule.days), (1..=31).collect::<Vec<_>>());
+    assert_eq!(values(schedule.months), (1..=12).collect::<Vec<_>>());
+    assert!(schedule.weekdays.contains(Weekday::Monday));
+    assert!(schedule.weekdays.contains(Weekday::Friday));
+    assert!(!schedule.weekdays.contains(Weekday::Saturday));
+    assert!(!schedule.either_day);
+
+    // Six fields start with seconds
+    let schedule = parse_cron("30 5/20 0,12 1-10/3 jan,Jul ?").unwrap();
+    assert_eq!(values(schedule.seconds), [30]);
+    assert_eq!(values(schedule.minutes), [5, 25, 45]);
+    assert_eq!(values(schedule.hours), [0, 12]);
+    assert_eq!(values(schedule.days), [1, 4, 7, 10]);
+    assert_eq!(values(schedule.months), [1, 7]);
+    assert_eq!(schedule.weekdays.iter().count(), 7);
+    assert!(!schedule.either_day);
+
+    // Sunday is 0 or 7, and ranges wrap to it
+    let weekend = parse_cron("0 0 * * FRI-SUN").unwrap().weekdays;
+    assert_eq!(weekend, parse_cron("0 0 * * 5-7").unwrap().weekdays);
+    assert_eq!(weekend, parse_cron("0 0 * * 0,5,6").unwrap().weekdays);
+    assert!(weekend.contains(Weekday::Sunday));
+
+    // Either day field matches when both are restricted
+    assert!(parse_cron("0 0 1 * MON").unwrap().either_day);
+    assert!(!parse_cron("0 0 ? * MON").unwrap().either_day);
+
+    // Macros
+    assert_eq!(parse_cron("@daily"), parse_cron("0 0 * * *"));
+    assert_eq!(parse_cron("@Yearly"), parse_cron("0 0 1 1 *"));
+    assert_eq!(parse_cron("@hourly"), parse_cron("0 * * * *"));
+
+    let errors = [
+        ("* * * *", errors::ERR_CRON_FIELD_COUNT),
+        ("* * * * * * *", errors::ERR_CRON_FIELD_COUNT),
+        ("60 * * * *", errors::ERR_CRON_INVALID_FIELD),
+        ("* 24 * * *", errors::ERR_CRON_INVALID_FIELD),
+        ("* * 0 * *", errors::ERR_CRON_INVALID_FIELD),
+        ("* * * 13 *", errors::ERR_CRON_INVALID_FIELD),
+        ("* * * * 8", errors::ERR_CRON_INVALID_FIELD),
+        ("*/0 * * * *", errors::ERR_CRON_INVALID_FIELD),
+        ("30-10 * * * *", errors::ERR_CRON_INVALID_FIELD),
+        ("* * * * MON-", errors::ERR_CRON_INVALID_FIELD),
+        ("@often", errors::ERR_CRON_FIELD_COUNT),
+        ("0 0 L * *", errors::ERR_CRON_UNSUPPORTED),
+        ("0 0 15W * *", errors::ERR_CRON_UNSUPPORTED),
+        ("0 0 * * 5#3", errors::ERR_CRON_UNSUPPORTED),
+    ];
+    for (input, message) in errors {
+        match parse_cron(input) {
+            Err(TempsError::ParseError {
+                message: actual, ..
+            }) => assert_eq!(actual, message, "Wrong error for input: {input}"),
+            other => panic!("Expected a parse error for {input}, got {other:?}"),
+        }
+    }
+
+    // Errors point at the offending field
+    assert!(matches!(
+        parse_cron("0  9 * 13 *"),
+        Err(TempsError::ParseError {
+            position: Some(7),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_cron_times() {
+    use temps_core::cron::cron_times;
+
+    let date = |year, month, day| StandardDate { day, month, year };
+    let time = |hour, minute, second| Time {
+        hour,
+        minute,
+        second,
+        meridiem: None,
+    };
+    let times = |cron: &str, start, at, direction, count| {
+        cron_times(parse_cron(cron).unwrap(), start, at, direction)
+            .take(count)
+            .collect::<Vec<_>>()
+    };
+    // Friday
+    let friday = date(2024, 3, 15);
+
+    // The start time itself fires
+    assert_eq!(
+        times(
+            "*/15 9-17 * * MON-FRI",
+            friday,
+            time(9, 0, 0),
+            Direction::Future,
+            2
+        ),
+        [(friday, time(9, 0, 0)), (friday, time(9, 15, 0))]
+    );
+    assert_eq!(
+        times(
+            "*/15 9-17 * * MON-FRI",
+            friday,
+            time(17, 50, 0),
+            Direction::Future,
+            2
+        ),
+        [
+            (date(2024, 3, 18), time(9, 0, 0)),
+            (date(2024, 3, 18), time(9, 15, 0))
+        ]
+    );
+    assert_eq!(
+        times(
+            "*/15 9-17 * * MON-FRI",
+            date(2024, 3, 18),
+            time(8, 0, 0),
+            Direction::Past,
+            2
+        ),
+        [(friday, time(17, 45, 0)), (friday, time(17, 30, 0))]
+    );
+
+    // Seconds
+    assert_eq!(
+        times(
+            "*/20 59 23 * * *",
+            friday,
+            time(23, 59, 30),
+            Direction::Future,
+            3
+        ),
+        [
+            (friday, time(23, 59, 40)),
+            (date(2024, 3, 16), time(23, 59, 0)),
+            (date(2024, 3, 16), time(23, 59, 20)),
+        ]
+    );
+    assert_eq!(
+        times(
+            "*/20 59 23 * * *",
+            friday,
+            time(23, 59, 30),
+            Direction::Past,
+            2
+        ),
+        [(friday, time(23, 59, 20)), (friday, time(23, 59, 0))]
+    );
+
+    // Either the day of the month or the weekday
+    let dates: Vec<_> = times(
+        "0 0 13 * FRI",
+        date(2024, 9, 1),
+        time(0, 0, 0),
+        Direction::Future,
+        4,
+    )
+    .into_iter()
+    .map(|(date, _)| date)
+    .collect();
+    assert_eq!(
+        dates,
+        [
+            date(2024, 9, 6),
+            date(2024, 9, 13),
+            date(2024, 9, 20),
+            date(2024, 9, 27)
+        ]
+    );
+
+    // Leap days
+    assert_eq!(
+        times(
+            "0 12 29 2 *",
+            date(2024, 3, 1),
+            time(0, 0, 0),
+            Direction::Future,
+            1
+        ),
+        [(date(2028, 2, 29), time(12, 0, 0))]
+    );
+    assert_eq!(
+        times(
+            "0 12 29 2 *",
+            date(2024, 3, 1),
+            time(0, 0, 0),
+            Direction::Past,
+            1
+        ),
+        [(date(2024, 2, 29), time(12, 0, 0))]
+    );
+
+    // Schedules that never fire end
+    assert_eq!(
+        times("0 0 30 2 *", friday, time(0, 0, 0), Direction::Future, 1),
+        []
+    );
+
+    // 12-hour start times
+    let evening = Time {
+        hour: 5,
+        minute: 0,
+        second: 0,
+        meridiem: Some(Meridiem::PM),
+    };
+    assert_eq!(
+        times("0 * * * *", friday, evening, Direction::Future, 1),
+        [(friday, time(17, 0, 0))]
+    );
+}
+
+#[test]
+fn test_format_relative() {
+    let relative = |direction, units: &[(TimeUnit, i64)]| RelativeDuration {
+        duration: units
+            .iter()
+            .fold(Duration::default(), |duration, &(unit, amount)| {
+                duration.with(unit, amount)
+            }),
+        direction,
+    };
+    let test_cases = [
+        (
+            relative(Direction::Future, &[(TimeUnit::Minute, 5)]),
+            "in 5 minutes",
+            "in 5 Minuten",
+        ),
+        (
+            relative(Direction::Past, &[(TimeUnit::Day, 2)]),
+            "2 days ago",
+            "vor 2 Tagen",
+        ),
+        (
+            relative(Direction::Past, &[(TimeUnit::Year, 1)]),
+            "1 year ago",
+            "vor 1 Jahr",
+        ),
+        (
+            relative(
+                Direction::Future,
+                &[(TimeUnit::Hour, 1), (TimeUnit::Minute, 30)],
+            ),
+            "in 1 hour 30 minutes",
+            "in 1 Stunde 30 Minuten",
+        ),
+        (
+            relative(
+                Direction::Past,
+                &[
+                    (TimeUnit::Year, 2),
+                    (TimeUnit::Month, 1),
+                    (TimeUnit::Week, 3),
+                    (TimeUnit::Second, 1),
+                ],
+            ),
+            "2 years 1 month 3 weeks 1 second ago",
+            "vor 2 Jahren 1 Monat 3 Wochen 1 Sekunde",
+        ),
+        (relative(Direction::Future, &[]), "now", "jetzt"),
+    ];
+
+    for (relative, english, german) in test_cases {
+        for (language, text) in [(Language::English, english), (Language::German, german)] {
+            assert_eq!(format_relative(&relative, language).unwrap(), text);
+
+            // The text parses back to the same amounts
+            let parsed = match parse(text, language).unwrap() {
+                TimeExpression::Now => Duration::default(),
+                TimeExpression::Relative(RelativeTime {
+                    amount,
+                    unit,
+                    direction,
+                }) => {
+                    assert_eq!(direction, relative.direction);
+                    Duration::default().with(unit, amount)
+                }
+                TimeExpression::RelativeDuration(parsed) => {
+                    assert_eq!(parsed.direction, relative.direction);
+                    parsed.duration
+                }
+                other => panic!("Unexpected expression for {text}: {other:?}"),
+            };
+            assert_eq!(parsed, relative.duration, "Wrong amounts for {text}");
+        }
+    }
+
+    // Amounts must not be negative
+    let negative = relative(Direction::Future, &[(TimeUnit::Hour, -1)]);
+    assert!(matches!(
+        format_relative(&negative, Language::English),
+        Err(TempsError::DateCalculationError { .. })
+    ));
+
+    // Options
+    let options = HumanizeOptions::from(Language::German);
+    assert_eq!(options.max_units(), 1);
+    assert_eq!(options.smallest_unit(), TimeUnit::Second);
+    assert_eq!(options.rounding(), Rounding::Nearest);
+    assert_eq!(options.units().count(), 7);
+    let options = options.with_max_units(0).with_smallest_unit(TimeUnit::Day);
+    assert_eq!(options.max_units(), 1);
+    assert_eq!(options.units().last(), Some(TimeUnit::Day));
+}
+
+#[test]
+fn test_format_expression() {
+    use temps_core::language::{english::EnglishParser, german::GermanParser};
+
+    // Input, canonical English, canonical American English
+    let english = [
+        ("now", "now", "now"),
+        ("in 5 minutes", "in 5 minutes", "in 5 minutes"),
+        ("3 days ago", "3 days ago", "3 days ago"),
+        ("tomorrow", "tomorrow", "tomorrow"),
+        ("monday", "Monday", "Monday"),
+        ("next friday", "next Friday", "next Friday"),
+        ("last sunday", "last Sunday", "last Sunday"),
+        (
+            "wednesday last week",
+            "Wednesday last week",
+            "Wednesday last week",
+        ),
+        ("3:30 pm", "3:30 pm", "3:30 pm"),
+        ("15:45:10", "15:45:10", "15:45:10"),
+        ("5 march 2024", "5 March 2024", "March 5, 2024"),
+        ("march 5", "5 March", "March 5"),
+        (
+            "tomorrow at 9am",
+            "tomorrow at 9:00 am",
+            "tomorrow at 9:00 am",
+        ),
+        (
+            "2024-03-05T14:30:15.250+05:30",
+            "2024-03-05T14:30:15.25+05:30",
+            "2024-03-05T14:30:15.25+05:30",
+        ),
+        (
+            "from monday to friday",
+            "from Monday to Friday",
+            "from Monday to Friday",
+        ),
+        ("this month", "this month", "this month"),
+        ("next quarter", "next quarter", "next quarter"),
+        ("end of the month", "end of the month", "end of the month"),
+        (
+            "start of next week",
+            "start of next week",
+            "start of next week",
+        ),
+        ("every day", "every day", "every day"),
+        (
+            "every monday and thursday at 9am",
+            "every Monday and Thursday at 9:00 am",
+            "every Monday and Thursday at 9:00 am",
+        ),
+        (
+            "every weekday at 8:30",
+            "every weekday at 08:30",
+            "every weekday at 08:30",
+        ),
+        (
+            "every other friday at 9am until 12/12/2024",
+            "every 2 weeks on Friday at 9:00 am until 12 December 2024",
+            "every 2 weeks on Friday at 9:00 am until December 12, 2024",
+        ),
+        (
+            "every day until march 5th",
+            "every day until 5 March",
+            "every day until March 5",
+        ),
+        (
+            "every 3 months on monday 5 times",
+            "every 3 months on Monday 5 times",
+            "every 3 months on Monday 5 times",
+        ),
+        (
+            "every year 1 time",
+            "every year 1 time",
+            "every year 1 time",
+        ),
+    ];
+    for (input, gb, us) in english {
+        for (language, text) in [(Language::English, gb), (Language::EnglishUs, us)] {
+            let expr = parse(input, language).unwrap();
+            assert_eq!(format_expression(&expr, language).unwrap(), text);
+            // The canonical text parses back to the same expression
+            assert_eq!(parse(text, language).unwrap(), expr, "Round trip of {text}");
+        }
+    }
+
+    // German text is already canonical
+    let german = [
+        "jetzt",
+        "vor 3 Tagen",
+        "gestern",
+        "nächsten Freitag",
+        "Montag nächster Woche",
+        "15:45:10 Uhr",
+        "05.03.2024",
+        "05.03.",
+        "nächsten Montag um 15:00 Uhr",
+        "2024-03-05T14:30:00Z",
+        "von Montag bis Freitag",
+        "nächste Woche",
+        "diesen Monat",
+        "letztes Jahr",
+        "Ende des Monats",
+        "Anfang nächster Woche",
+        "Ende des letzten Jahres",
+        "jede Stunde",
+        "jeden Montag und Donnerstag um 9:00 Uhr",
+        "jeden Werktag um 8:30 Uhr",
+        "alle 2 Wochen am Freitag um 9:00 Uhr bis 31.12.2024",
+        "jeden Tag bis 05.03.",
+        "alle 3 Monate am Montag 5 Mal",
+        "jedes Jahr 1 Mal",
+    ];
+    for text in german {
+        let expr = parse(text, Language::German).unwrap();
+        assert_eq!(format_expression(&expr, Language::German).unwrap(), text);
+    }
+
+    // Translating keeps the meaning, apart from the German 24-hour clock
+    for (input, _, _) in english {
+        let expr = parse(input, Language::English).unwrap();
+        let text = format_expression(&expr, Language::German).unwrap();
+        let translated = parse(&text, Language::German).unwrap();
+        assert_eq!(
+            format_expression(&translated, Language::German).unwrap(),
+            text
+        );
+        let back = format_expression(&translated, Language::English).unwrap();
+        assert_eq!(
+            parse(&back, Language::English).unwrap(),
+            translated,
+            "Translation of {input}"
+        );
+    }
+    for text in german {
+        let expr = parse(text, Language::German).unwrap();
+        let english = format_expression(&expr, Language::English).unwrap();
+        assert_eq!(parse(&english, Language::English).unwrap(), expr);
+    }
+    assert_eq!(
+        format_expression(
+            &parse("3:30 pm", Language::English).unwrap(),
+            Language::German
+        )
+        .unwrap(),
+        "15:30 Uhr"
+    );
+
+    // Each parser writes other languages through their own parser
+    let expr = parse("nächsten Montag um 15:00", Language::German).unwrap();
+    assert_eq!(
+        GermanParser.format(&expr, Language::English).unwrap(),
+        "next Monday at 15:00"
+    );
+    assert_eq!(
+        EnglishParser.format(&expr, Language::German).unwrap(),
+        "nächsten Montag um 15:00 Uhr"
+    );
+
+    // Expressions without a canonical text
+    let unrepresentable = [
+        TimeExpression::Period(Period {
+            unit: PeriodUnit::Week,
+            offset: 2,
+        }),
+        TimeExpression::Period(Period {
+            unit: PeriodUnit::Day,
+            offset: 0,
+        }),
+        TimeExpression::Day(DayReference::WeekdayInWeek {
+            day: Weekday::Monday,
+            week_offset: -3,
+        }),
+    ];
+    for expr in unrepresentable {
+        for language in [Language::English, Language::German] {
+            assert!(matches!(
+                format_expression(&expr, language),
+                Err(TempsError::UnsupportedOperation { .. })
+            ));
+        }
+    }
+    let negative = TimeExpression::Relative(RelativeTime {
+        amount: -5,
+        unit: TimeUnit::Minute,
+        direction: Direction::Future,
+    });
+    assert!(matches!(
+        format_expression(&negative, Language::German),
+        Err(TempsError::DateCalculationError { .. })
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_json::json;
+
+    // The documented representation
+    let expr = parse("in 5 minutes", Language::English).unwrap();
+    assert_eq!(
+        serde_json::to_value(expr).unwrap(),
+        json!({
+            "type": "relative",
+            "value": {"amount": 5, "unit": "minute", "direction": "future"}
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(TimeExpression::Now).unwrap(),
+        json!({"type": "now"})
+    );
+    let expr = parse("every monday and friday at 9am 3 times", Language::English).unwrap();
+    assert_eq!(
+        serde_json::to_value(expr).unwrap(),
+        json!({
+            "type": "recurrence",
+            "value": {
+                "frequency": "weekly",
+                "interval": 1,
+                "weekdays": ["monday", "friday"],
+                "time": {"hour": 9, "minute": 0, "second": 0, "meridiem": "am"},
+                "end": {"type": "count", "value": 3}
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(Language::EnglishUs).unwrap(),
+        json!("english_us")
+    );
+
+    // Expressions round-trip
+    let inputs = [
+        "now",
+        "in 2 hours 30 minutes",
+        "2024-03-05T14:30:15.250+05:30",
+        "next friday",
+        "monday next week",
+        "tomorrow at 3:30 pm",
+        "5 march 2024",
+        "march 5",
+        "from monday to 2024-03-05",
+        "next quarter",
+        "end of last year",
+        "every other friday at 9am until 12/12/2024",
+    ];
+    for input in inputs {
+        let expr = parse(input, Language::English).unwrap();
+        let json = serde_json::to_string(&expr).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TimeExpression>(&json).unwrap(),
+            expr,
+            "Round trip of {input}: {json}"
+        );
+    }
+    let schedule = parse_cron("*/15 9-17 * * MON-FRI").unwrap();
+    let json = serde_json::to_value(schedule).unwrap();
+    assert_eq!(json["minutes"], json!([0, 15, 30, 45]));
+    assert_eq!(
+        serde_json::from_value::<CronSchedule>(json).unwrap(),
+        schedule
+    );
+    let rule = parse_rrule(
+        "DTSTART;TZID=Europe/Berlin:20240301T090000\nRRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=3",
+    )
+    .unwrap();
+    let json = serde_json::to_string(&rule).unwrap();
+    assert_eq!(serde_json::from_str::<RRule>(&json).unwrap(), rule);
+
+    // Errors
+    let error = parse("in 5 blargs", Language::English).unwrap_err();
+    let json = serde_json::to_value(&error).unwrap();
+    assert_eq!(json["type"], "parse_error");
+    assert_eq!(json["value"]["input"], "in 5 blargs");
+    assert_eq!(serde_json::from_value::<TempsError>(json).unwrap(), error);
+    let error = TempsError::backend_error("out of range", "jiff");
+    let json = serde_json::to_string(&error).unwrap();
+    assert_eq!(serde_json::from_str::<TempsError>(&json).unwrap(), error);
+
+    // Values that do not fit a cron field are rejected
+    assert!(serde_json::from_value::<CronField>(json!([5, 64])).is_err());
+    assert!(serde_json::from_value::<Weekday>(json!("funday")).is_err());
+}
+
+// ===== Extraction Tests =====
+
+#[test]
+fn test_extract() {
+    let found = |text: &str, language| -> Vec<String> {
+        find_iter(text, language)
+            .map(|found| text[found.range].to_string())
+            .collect()
+    };
+
+    let test_cases = [
+        (
+            "Can we meet next Friday at 3pm? If not, Monday works.",
+            Language::English,
+            vec!["next Friday at 3pm", "Monday"],
+        ),
+        (
+            "The report is due 2024-12-25T15:30:00Z, reminders go out every weekday at 9am.",
+            Language::English,
+            vec!["2024-12-25T15:30:00Z", "every weekday at 9am"],
+        ),
+        (
+            "I'm off from 3pm to 5pm and again in 2 hours 30 minutes",
+            Language::English,
+            vec!["from 3pm to 5pm", "in 2 hours 30 minutes"],
+        ),
+        ("Nowhere to go and nothing to do", Language::English, vec![]),
+        (
+            "Können wir uns nächsten Montag um 15:00 treffen? Sonst am 24.12.",
+            Language::German,
+            vec!["nächsten Montag um 15:00", "am 24.12."],
+        ),
+        (
+            "Die Rechnung kam vor 3 Tagen, bezahlt wird Ende des Monats.",
+            Language::German,
+            vec!["vor 3 Tagen", "Ende des Monats"],
+        ),
+    ];
+
+    for (text, language, expected) in test_cases {
+        assert_eq!(found(text, language), expected, "Failed for: {text}");
+    }
+
+    // Byte ranges count multi-byte characters
+    let text = "Grüße, bis übermorgen? Nein: morgen früh.";
+    let matches = extract(text, Language::German);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].range, 32..38);
+    assert_eq!(
+        matches[0].expression,
+        TimeExpression::Day(DayReference::Tomorrow)
+    );
+
+    // Matches are the longest from their start and never overlap
+    let text = "tomorrow at 9:00 am";
+    let matches = extract(text, Language::English);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].range, 0..text.len());
+    assert_eq!(
+        matches[0].expression,
+        parse(text, Language::English).unwrap()
+    );
+    for window in extract(
+        "now, today, tomorrow at noon, next week and 5 minutes ago",
+        Language::English,
+    )
+    .windows(2)
+    {
+        assert!(window[0].range.end <= window[1].range.start);
+    }
+
+    // Options apply to each match
+    let text = "Due 04/05/2024.";
+    let options = ParseOptions::new(Language::English).with_date_order(DateOrder::Mdy);
+    assert_eq!(
+        extract(text, options)[0].expression,
+        TimeExpression::Date(StandardDate {
+            day: 5,
+            month: 4,
+            year: 2024
+        })
+    );
+    assert!(extract("", Language::English).is_empty());
+}
+
+// ===== Prefix Parsing Tests =====
+
+#[test]
+fn test_parse_prefix() {
+    let test_cases = [
+        (
+            "tomorrow at 9am to call Bob",
+            Language::English,
+            "tomorrow at 9am",
+            " to call Bob",
+        ),
+        (
+            "in 2 hours please",
+            Language::English,
+            "in 2 hours",
+            " please",
+        ),
+        (
+            "  every Monday at 9:00 am, standup",
+            Language::English,
+            "every Monday at 9:00 am",
+            ", standup",
+        ),
+        (
+            "from 3pm to 5pm: focus time",
+            Language::English,
+            "from 3pm to 5pm",
+            ": focus time",
+        ),
+        (
+            "2024-12-25T15:30:00Z deploy",
+            Language::English,
+            "2024-12-25T15:30:00Z",
+            " deploy",
+        ),
+        (
+            "morgen um 9:00 Bob anrufen",
+            Language::German,
+            "morgen um 9:00",
+            " Bob anrufen",
+        ),
+        (
+            "in 2 Stunden bitte",
+            Language::German,
+            "in 2 Stunden",
+            " bitte",
+        ),
+        // Bare durations are read as that long from now
+        ("2h please", Language::English, "in 2 hours", " please"),
+        (
+            "2h30m, then lunch",
+            Language::English,
+            "in 2 hours 30 minutes",
+            ", then lunch",
+        ),
+        (
+            "90 Minuten bitte",
+            Language::German,
+            "in 90 Minuten",
+            " bitte",
+        ),
+    ];
+
+    for (input, language, expression, rest) in test_cases {
+        let (parsed, parsed_rest) = parse_prefix(input, language).unwrap();
+        assert_eq!(
+            parsed,
+            parse(expression, language).unwrap(),
+            "Failed for: {input}"
+        );
+        assert_eq!(parsed_rest, rest, "Failed for: {input}");
+    }
+
+    let command = "snooze 2h please";
+    let rest = command.strip_prefix("snooze").unwrap();
+    let (expression, rest) = parse_prefix(rest, Language::English).unwrap();
+    assert_eq!(expression, parse("in 2 hours", Language::English).unwrap());
+    assert_eq!(rest, " please");
+
+    // Whole expressions leave nothing and agree with `parse`
+    for input in ["next Friday at 3pm", "5 minutes ago", "end of the month"] {
+        assert_eq!(
+            parse_prefix(input, Language::English).unwrap(),
+            (parse(input, Language::English).unwrap(), "")
+        );
+    }
+
+    // Bare durations are read in full, but `parse` rejects them
+    for (input, language, expression) in [
+        ("2h", Language::English, "in 2 hours"),
+        ("2 Stunden", Language::German, "in 2 Stunden"),
+    ] {
+        assert_eq!(
+            parse_prefix(input, language).unwrap(),
+            (parse(expression, language).unwrap(), ""),
+            "Failed for: {input}"
+        );
+        assert!(parse(input, language).is_err(), "Failed for: {input}");
+    }
+
+    // Expressions end at a word boundary
+    for input in ["nowhere", "tomorrowland", "call Bob tomorrow", ""] {
+        let err = parse_prefix(input, Language::English).unwrap_err();
+        assert!(matches!(
+            err,
+            TempsError::ParseError {
+                position: Some(0),
+                ..
+            }
+        ));
+    }
+
+    // Options apply to the prefix
+    let options = ParseOptions::new(Language::English).with_date_order(DateOrder::Mdy);
+    assert_eq!(
+        parse_prefix("04/05/2024 release", options).unwrap(),
+        (
+            TimeExpression::Date(StandardDate {
+                day: 5,
+                month: 4,
+                year: 2024
+            }),
+            " release"
+        )
+    );
+}
+
+// ===== Combinator Tests =====
+
+#[test]
+fn test_combinators() {
+    use temps_core::combinators::winnow::Parser;
+    use temps_core::combinators::winnow::ascii::{Caseless, multispace1};
+    use temps_core::combinators::winnow::combinator::{preceded, separated_pair};
+    use temps_core::combinators::winnow::token::rest;
+    use temps_core::combinators::{self, english, german};
+
+    // The expression parsers agree with `parse`
+    for input in [
+        "in 5 minutes",
+        "tomorrow at 3:30 pm",
+        "from 3pm to 5pm",
+        "every Monday at 9am",
+        "04/05/2024",
+    ] {
+        for order in [DateOrder::Dmy, DateOrder::Mdy] {
+            let options = ParseOptions::new(Language::English).with_date_order(order);
+            assert_eq!(
+                (|input: &mut &str| english::parse_expression(input, order))
+                    .parse(input)
+                    .unwrap(),
+                parse(input, options).unwrap(),
+                "Failed for: {input}"
+            );
+        }
+    }
+    for input in [
+        "in 5 Minuten",
+        "morgen um 15:00",
+        "jeden Montag",
+        "am 24.12.",
+    ] {
+        assert_eq!(
+            german::parse_expression.parse(input).unwrap(),
+            parse(input, Language::German).unwrap(),
+            "Failed for: {input}"
+        );
+    }
+
+    // Embedded in a larger grammar
+    let mut snooze = preceded(
+        (Caseless("snooze for"), multispace1),
+        separated_pair(english::parse_duration, multispace1, rest),
+    );
+    let (duration, rest) = snooze.parse("snooze for 2h30m please").unwrap();
+    assert_eq!(
+        duration,
+        parse_duration("2h30m", Language::English).unwrap()
+    );
+    assert_eq!(rest, "please");
+
+    let mut erinnerung = separated_pair(german::parse_day, multispace1, german::parse_time);
+    assert_eq!(
+        erinnerung.parse("nächsten Montag 14:30").unwrap(),
+        (
+            DayReference::Weekday {
+                day: Weekday::Monday,
+                modifier: Some(WeekdayModifier::Next),
+            },
+            Time {
+                hour: 14,
+                minute: 30,
+                second: 0,
+                meridiem: None,
+            }
+        )
+    );
+
+    // Component parsers
+    assert_eq!(
+        english::parse_weekday.parse("Mon").unwrap(),
+        Weekday::Monday
+    );
+    assert_eq!(
+        english::parse_time_unit.parse("minutes").unwrap(),
+        TimeUnit::Minute
+    );
+    assert_eq!(english::parse_number.parse("five").unwrap(), 5);
+    assert_eq!(english::parse_meridiem.parse("p.m.").unwrap(), Meridiem::PM);
+    assert_eq!(english::parse_month_name.parse("March").unwrap(), 3);
+    assert_eq!(
+        english::parse_time.parse("9am").unwrap(),
+        Time {
+            hour: 9,
+            minute: 0,
+            second: 0,
+            meridiem: Some(Meridiem::AM),
+        }
+    );
+    assert_eq!(
+        german::parse_weekday.parse("Dienstag").unwrap(),
+        Weekday::Tuesday
+    );
+    assert_eq!(
+        german::parse_time_unit.parse("Stunden").unwrap(),
+        TimeUnit::Hour
+    );
+    assert_eq!(german::parse_number.parse("drei").unwrap(), 3);
+    assert_eq!(german::parse_month_name.parse("März").unwrap(), 3);
+    assert_eq!(
+        english::parse_relative.parse("3 days ago").unwrap(),
+        parse("3 days ago", Language::English).unwrap()
+    );
+    assert_eq!(
+        german::parse_relative.parse("vor 3 Tagen").unwrap(),
+        parse("vor 3 Tagen", Language::German).unwrap()
+    );
+
+    // Parsers do not check what follows; `word_boundary` does
+    let mut input = "nowhere";
+    assert!(english::parse_expression(&mut input, DateOrder::Dmy).is_ok());
+    assert_eq!(input, "here");
+    assert!(
+        (
+            |input: &mut &str| english::parse_expression(input, DateOrder::Dmy),
+            combinators::word_boundary
+        )
+            .parse("nowhere")
+            .is_err()
+    );
+
+    assert_eq!(
+        combinators::parse_iso_datetime
+            .parse("2024-01-15T14:30:00Z")
+            .unwrap(),
+        parse("2024-01-15T14:30:00Z", Language::English).unwrap()
+    );
+}
+
+// ===== Spanned Parsing Tests =====
+
+#[test]
+fn test_parse_spanned() {
+    use Component::*;
+
+    let test_cases = [
+        (
+            "in 2 hours 30 minutes",
+            Language::English,
+            vec![
+                (Amount, "2"),
+                (Unit, "hours"),
+                (Amount, "30"),
+                (Unit, "minutes"),
+            ],
+        ),
+        (
+            "next Friday at 3:30 pm",
+            Language::English,
+            vec![(Modifier, "next"), (Weekday, "Friday"), (Time, "3:30 pm")],
+        ),
+        (
+            "Monday next week",
+            Language::English,
+            vec![(Weekday, "Monday"), (Modifier, "next"), (Unit, "week")],
+        ),
+        ("Monday", Language::English, vec![(Weekday, "Monday")]),
+        (
+            "next week",
+            Language::English,
+            vec![(Modifier, "next"), (Unit, "week")],
+        ),
+        ("tomorrow", Language::English, vec![(Day, "tomorrow")]),
+        (
+            "2024-01-15T14:30:00+02:00",
+            Language::English,
+            vec![
+                (Date, "2024-01-15"),
+                (Time, "14:30:00"),
+                (Timezone, "+02:00"),
+            ],
+        ),
+        (
+            "end of next month",
+            Language::English,
+            vec![(Edge, "end"), (Modifier, "next"), (Unit, "month")],
+        ),
+        (
+            "every other Friday at 9am until 31/12/2024",
+            Language::English,
+            vec![
+                (Amount, "other"),
+                (Weekday, "Friday"),
+                (Time, "9am"),
+                (Date, "31/12/2024"),
+            ],
+        ),
+        ("3-5pm", Language::English, vec![(Time, "3"), (Time, "5pm")]),
+        (
+            "from March 5th 2024 to tomorrow",
+            Language::English,
+            vec![(Date, "March 5th 2024"), (Day, "tomorrow")],
+        ),
+        (
+            "vor 3 Tagen",
+            Language::German,
+            vec![(Amount, "3"), (Unit, "Tagen")],
+        ),
+        (
+            "nächsten Montag um 15:00",
+            Language::German,
+            vec![(Modifier, "nächsten"), (Weekday, "Montag"), (Time, "15:00")],
+        ),
+        (
+            "jeden zweiten Dienstag um 17 Uhr",
+            Language::German,
+            vec![(Amount, "zweiten"), (Weekday, "Dienstag"), (Time, "17")],
+        ),
+        (
+            "Ende des Monats",
+            Language::German,
+            vec![(Edge, "Ende"), (Unit, "Monats")],
+        ),
+        ("am 24.12.", Language::German, vec![(Date, "24.12.")]),
+        (
+            "von 9 bis 17 Uhr",
+            Language::German,
+            vec![(Time, "9"), (Time, "17")],
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        let spanned = parse_spanned(input, language).unwrap();
+        assert_eq!(
+            spanned.expression,
+            parse(input, language).unwrap(),
+            "Failed for: {input}"
+        );
+        let parts: Vec<(Component, &str)> = spanned
+            .spans
+            .iter()
+            .map(|span| (span.component, &input[span.range.clone()]))
+            .collect();
+        assert_eq!(parts, expected, "Failed for: {input}");
+    }
+
+    // Ranges are into the input, including surrounding whitespace
+    let spanned = parse_spanned("  täglich ", Language::German).unwrap();
+    assert_eq!(
+        spanned.spans,
+        [Span {
+            component: Frequency,
+            range: 2..10,
+        }]
+    );
+
+    // Errors are those of `parse`
+    assert_eq!(
+        parse_spanned("in 5 minuts", Language::English).unwrap_err(),
+        parse("in 5 minuts", Language::English).unwrap_err()
+    );
+}
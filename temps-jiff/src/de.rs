@@ -0,0 +1,219 @@
+//! Serde adapters that read human time strings, e.g. in config files.
+//!
+//! Each module has a `deserialize` function for
+//! `#[serde(deserialize_with = "...")]` that reads a natural-language phrase
+//! or an ISO 8601 string:
+//!
+//! - [`zoned`] and [`timestamp`] read times (`"in 30 days"`,
+//!   `"tomorrow at 9am"`, `"2024-12-25T15:30:00Z"`) into a
+//!   [`Zoned`] and a [`Timestamp`](jiff::Timestamp)
+//! - [`span`] reads durations (`"2 hours"`, `"1.5 days"`, `"PT2H"`) into a
+//!   [`Span`](jiff::Span)
+//!
+//! Phrases are read in English and resolved by the default [`JiffProvider`],
+//! unless [`with_settings`] picks other [`Settings`] while deserializing.
+//!
+//! # Examples
+//!
+//! ```
+//! use jiff::{Span, Zoned, civil::date, tz::TimeZone};
+//! use serde::Deserialize;
+//! use temps_core::Language;
+//! use temps_jiff::{
+//!     JiffProvider,
+//!     de::{Settings, with_settings},
+//! };
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(deserialize_with = "temps_jiff::de::zoned::deserialize")]
+//!     expires_at: Zoned,
+//!     #[serde(deserialize_with = "temps_jiff::de::span::deserialize")]
+//!     timeout: Span,
+//! }
+//!
+//! let json = r#"{"expires_at": "in 30 Tagen", "timeout": "2 Stunden"}"#;
+//! let reference = date(2024, 3, 15).at(10, 30, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+//! let provider = JiffProvider::new(TimeZone::UTC).with_reference(reference);
+//! let settings = Settings::new(Language::German).with_provider(provider);
+//! let config: Config = with_settings(settings, || serde_json::from_str(json)).unwrap();
+//!
+//! assert_eq!(config.expires_at.date(), date(2024, 4, 14));
+//! assert_eq!(config.timeout.get_hours(), 2);
+//! ```
+
+use std::cell::RefCell;
+
+use jiff::Zoned;
+use temps_core::{Language, ParseOptions, Result, TimeParser};
+
+use crate::JiffProvider;
+
+thread_local! {
+    static SETTINGS: RefCell<Option<Settings>> = const { RefCell::new(None) };
+}
+
+/// How the adapters read phrases: the language and the provider that
+/// resolves them, with its time zone and reference clock.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::tz::TimeZone;
+/// use temps_core::{DateOrder, Language, ParseOptions};
+/// use temps_jiff::{JiffProvider, de::Settings};
+///
+/// let settings = Settings::new(ParseOptions::new(Language::English).with_date_order(DateOrder::Mdy))
+///     .with_provider(JiffProvider::new(TimeZone::UTC));
+/// ```
+#[derive(Clone)]
+pub struct Settings {
+    options: ParseOptions,
+    provider: JiffProvider,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::new(Language::English)
+    }
+}
+
+impl Settings {
+    /// Read phrases with the given language or parse options, resolved by
+    /// the default provider.
+    #[must_use]
+    pub fn new(options: impl Into<ParseOptions>) -> Self {
+        Self {
+            options: options.into(),
+            provider: JiffProvider::default(),
+        }
+    }
+
+    /// Resolve phrases with the given provider, e.g. in another time zone or
+    /// against a fixed reference time.
+    #[must_use]
+    pub fn with_provider(mut self, provider: JiffProvider) -> Self {
+        self.provider = provider;
+        self
+    }
+
+    /// The parse options phrases are read with.
+    #[must_use]
+    pub fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    /// The provider that resolves phrases.
+    #[must_use]
+    pub fn provider(&self) -> &JiffProvider {
+        &self.provider
+    }
+}
+
+/// Run `f` with the adapters reading phrases according to `settings`.
+///
+/// The settings apply to deserialization on the current thread until `f`
+/// returns, after which the previous settings are restored.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use serde::Deserialize;
+/// use temps_core::Language;
+/// use temps_jiff::de::{Settings, with_settings};
+///
+/// #[derive(Deserialize)]
+/// struct Job {
+///     #[serde(deserialize_with = "temps_jiff::de::zoned::deserialize")]
+///     start: Zoned,
+/// }
+///
+/// let settings = Settings::new(Language::German);
+/// let job: Job = with_settings(settings, || serde_json::from_str(r#"{"start": "morgen um 9:00"}"#))
+///     .unwrap();
+/// ```
+pub fn with_settings<R>(settings: Settings, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous settings, also when `f` panics
+    struct Restore(Option<Settings>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SETTINGS.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(SETTINGS.replace(Some(settings)));
+    f()
+}
+
+/// The settings of the innermost [`with_settings`], or the default
+fn current() -> Settings {
+    SETTINGS.with_borrow(|settings| settings.clone().unwrap_or_default())
+}
+
+/// Resolve a phrase with the current settings
+fn resolve(input: &str) -> Result<Zoned> {
+    let settings = current();
+    let expr = temps_core::parse(input, settings.options)?;
+    settings.provider.parse_expression(expr)
+}
+
+/// Read a time as a [`Zoned`] in the provider's time zone.
+pub mod zoned {
+    use jiff::Zoned;
+    use serde::{Deserialize, Deserializer, de::Error};
+
+    /// Deserialize a phrase or ISO 8601 string into a `Zoned`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the [`TempsError`](temps_core::TempsError) message if the
+    /// string cannot be parsed or resolved.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Zoned, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        super::resolve(&input).map_err(D::Error::custom)
+    }
+}
+
+/// Read a time as a [`Timestamp`](jiff::Timestamp).
+///
+/// Phrases such as "tomorrow at 9am" are resolved in the provider's time zone.
+pub mod timestamp {
+    use jiff::Timestamp;
+    use serde::{Deserialize, Deserializer, de::Error};
+
+    /// Deserialize a phrase or ISO 8601 string into a `Timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the [`TempsError`](temps_core::TempsError) message if the
+    /// string cannot be parsed or resolved.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        super::resolve(&input)
+            .map(|zoned| zoned.timestamp())
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Read a duration as a [`Span`](jiff::Span).
+///
+/// Durations are read like [`parse_to_span`](crate::parse_to_span), in the
+/// language of the current settings.
+pub mod span {
+    use jiff::Span;
+    use serde::{Deserialize, Deserializer, de::Error};
+
+    /// Deserialize a duration such as "2 hours" or "PT2H" into a `Span`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the [`TempsError`](temps_core::TempsError) message if the
+    /// string is not a duration.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Span, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        let language = super::current().options.language();
+        crate::parse_to_span(&input, language).map_err(D::Error::custom)
+    }
+}
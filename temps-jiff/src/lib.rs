@@ -12,6 +12,7 @@
 //! - Support for all time expression types
 //! - Proper handling of month/year arithmetic using jiff's `Span`
 //! - Timezone support (UTC and fixed offsets)
+//! - Configurable target time zone (system zone by default)
 //! - Precise time calculations with nanosecond precision
 //!
 //! ## Example
@@ -25,11 +26,66 @@
 //! println!("In 5 minutes: {}", datetime);
 //!
 //! // Or use the provider directly
-//! let provider = JiffProvider;
+//! let provider = JiffProvider::default();
 //! let expr = temps_core::parse("tomorrow at 3:30 pm", Language::English).unwrap();
 //! let datetime = provider.parse_expression(expr).unwrap();
 //! ```
 //!
+//! ## Reference Time
+//!
+//! By default all expressions are resolved against `Zoned::now()`. A fixed
+//! reference time or a custom [`Clock`] makes resolution deterministic:
+//!
+//! ```
+//! use jiff::{civil::date, tz::TimeZone};
+//! use temps_jiff::{JiffProvider, parse_to_zoned_at};
+//! use temps_core::{Language, TimeParser};
+//!
+//! let reference = date(2024, 3, 15).at(10, 30, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+//!
+//! let provider = JiffProvider::new(TimeZone::UTC).with_reference(reference.clone());
+//! let expr = temps_core::parse("tomorrow", Language::English).unwrap();
+//! let tomorrow = provider.parse_expression(expr).unwrap();
+//!
+//! let later = parse_to_zoned_at("in 2 hours", Language::English, &reference).unwrap();
+//! ```
+//!
+//! ## Time Zones
+//!
+//! Expressions are resolved in the provider's time zone, so "tomorrow at 9am"
+//! means 9am in that zone regardless of the host's time zone:
+//!
+//! ```
+//! use jiff::tz::TimeZone;
+//! use temps_jiff::JiffProvider;
+//! use temps_core::{Language, TimeParser};
+//!
+//! let provider = JiffProvider::new(TimeZone::get("Europe/Berlin").unwrap());
+//! let expr = temps_core::parse("tomorrow at 9:00 am", Language::English).unwrap();
+//! let datetime = provider.parse_expression(expr).unwrap();
+//! ```
+//!
+//! ## Daylight Saving Time
+//!
+//! Local times that fall into a DST gap (skipped) or fold (repeated) are
+//! resolved according to the provider's [`Disambiguation`] policy. The
+//! default, [`Disambiguation::Compatible`], moves skipped times forward and
+//! picks the earlier of two repeated times. Use
+//! [`JiffProvider::parse_expression_with_fold`] to learn which choice was made:
+//!
+//! ```
+//! use jiff::tz::TimeZone;
+//! use temps_jiff::JiffProvider;
+//! use temps_core::{Disambiguation, Fold, FoldChoice, Language};
+//!
+//! let provider = JiffProvider::new(TimeZone::get("Europe/Berlin").unwrap())
+//!     .with_disambiguation(Disambiguation::Later);
+//! let expr = temps_core::parse("2024-10-27T02:30:00", Language::English).unwrap();
+//! let (datetime, fold) = provider.parse_expression_with_fold(expr).unwrap();
+//! assert_eq!(fold, Fold::Overlap(FoldChoice::Later));
+//! assert_eq!(datetime.offset().seconds(), 3600);
+//! ```
+//!
 //! ## Month and Year Arithmetic
 //!
 //! This implementation uses jiff's `Span` type for date arithmetic, which
@@ -44,17 +100,35 @@
 //!
 //! - `ParseError`: Invalid input that cannot be parsed
 //! - `DateCalculationError`: Date arithmetic that results in invalid dates
+//! - `AmbiguousTime`: Local times skipped or repeated by DST transitions when
+//!   the provider uses [`Disambiguation::Reject`]
 //! - `InvalidDate`/`InvalidTime`: Components that are out of valid ranges
 //! - `BackendError`: Errors from the jiff library
 
-use jiff::{Span, Zoned};
+#[cfg(feature = "serde")]
+pub mod de;
+
+use std::sync::Arc;
+
+use jiff::{
+    Span, Zoned, civil,
+    tz::{self, AmbiguousOffset, TimeZone},
+};
 use temps_core::{
-    DayReference, Direction, Language, Result, TempsError, TimeExpression, TimeParser, TimeUnit,
-    Weekday,
+    AbsoluteTime, Boundary, Clock, CronSchedule, DayReference, DayTime, Direction, Disambiguation,
+    Edge, Endpoint, FixedClock, Fold, FoldChoice, Frequency, HumanizeOptions, Interval, Language,
+    MonthDay, ParseOptions, Period, PeriodEnd, PeriodUnit, RRule, RRuleEnd, Recurrence,
+    RecurrenceEnd, RelativeDuration, RelativeTime, Result, Rounding, StandardDate, TempsError,
+    Time, TimeExpression, TimeParser, TimeUnit, WeekStart, Weekday, YearPolicy,
+    constants::DAYS_PER_WEEK,
+    cron::cron_times,
     errors::*,
+    format_relative,
+    rrule::rrule_occurrences,
     time_utils::{
-        calculate_timezone_offset_seconds, calculate_weekday_offset, convert_12_to_24_hour,
-        is_valid_time, is_valid_timezone_offset,
+        calculate_timezone_offset_seconds, calculate_week_weekday_offset, calculate_weekday_offset,
+        convert_12_to_24_hour, days_from_week_start, is_valid_time, is_valid_timezone_offset,
+        nominal_seconds, period_month_bounds, recurrence_dates, resolve_month_day,
     },
 };
 
@@ -62,18 +136,239 @@ use temps_core::{
 ///
 /// This provider uses jiff's `Zoned` as its datetime type, providing
 /// high-precision time calculations and comprehensive timezone support.
+/// Expressions are resolved in the provider's time zone, which defaults to
+/// the system time zone.
+///
+/// Relative expressions are resolved against the system clock unless a
+/// reference time or custom [`Clock`] is configured.
 ///
 /// ## Example
 ///
 /// ```
+/// use jiff::tz::TimeZone;
 /// use temps_jiff::JiffProvider;
 /// use temps_core::{TimeParser, parse, Language};
 ///
-/// let provider = JiffProvider;
+/// let provider = JiffProvider::default();
 /// let expr = parse("next Monday", Language::English).unwrap();
 /// let datetime = provider.parse_expression(expr).unwrap();
+///
+/// // Resolve "tomorrow at 9am" in Berlin instead of the host's time zone
+/// let provider = JiffProvider::new(TimeZone::get("Europe/Berlin").unwrap());
+/// let expr = parse("tomorrow at 9:00 am", Language::English).unwrap();
+/// let datetime = provider.parse_expression(expr).unwrap();
+/// assert_eq!(datetime.hour(), 9);
 /// ```
-pub struct JiffProvider;
+#[derive(Clone)]
+pub struct JiffProvider {
+    time_zone: TimeZone,
+    clock: Option<Arc<dyn Clock<Zoned>>>,
+    disambiguation: Disambiguation,
+    year_policy: YearPolicy,
+    period_end: PeriodEnd,
+    week_start: WeekStart,
+}
+
+impl Default for JiffProvider {
+    fn default() -> Self {
+        Self::new(TimeZone::system())
+    }
+}
+
+impl JiffProvider {
+    /// Create a provider that resolves expressions in the given time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::tz::TimeZone;
+    /// use temps_jiff::JiffProvider;
+    ///
+    /// let provider = JiffProvider::new(TimeZone::UTC);
+    /// ```
+    #[must_use]
+    pub fn new(time_zone: TimeZone) -> Self {
+        Self {
+            time_zone,
+            clock: None,
+            disambiguation: Disambiguation::default(),
+            year_policy: YearPolicy::default(),
+            period_end: PeriodEnd::default(),
+            week_start: WeekStart::default(),
+        }
+    }
+
+    /// The time zone expressions are resolved in.
+    #[must_use]
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.time_zone
+    }
+
+    /// Resolve expressions against the given clock instead of the system clock.
+    ///
+    /// The clock's time is converted into the provider's time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use temps_jiff::JiffProvider;
+    /// use temps_core::TimeParser;
+    ///
+    /// let provider = JiffProvider::new(TimeZone::UTC).with_clock(|| {
+    ///     date(2024, 3, 15).at(10, 30, 0, 0).to_zoned(TimeZone::UTC).unwrap()
+    /// });
+    /// assert_eq!(provider.now().hour(), 10);
+    /// ```
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl Clock<Zoned> + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Resolve expressions against a fixed reference time.
+    ///
+    /// The reference time is converted into the provider's time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use temps_jiff::JiffProvider;
+    /// use temps_core::TimeParser;
+    ///
+    /// let reference = date(2024, 3, 15).at(10, 30, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+    /// let provider = JiffProvider::new(TimeZone::UTC).with_reference(reference.clone());
+    /// assert_eq!(provider.now(), reference);
+    /// ```
+    #[must_use]
+    pub fn with_reference(self, reference: Zoned) -> Self {
+        self.with_clock(FixedClock(reference))
+    }
+
+    /// Use the given policy for local times skipped or repeated by DST transitions.
+    ///
+    /// The default is [`Disambiguation::Compatible`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use temps_jiff::JiffProvider;
+    /// use temps_core::Disambiguation;
+    ///
+    /// let provider = JiffProvider::default().with_disambiguation(Disambiguation::Reject);
+    /// ```
+    #[must_use]
+    pub fn with_disambiguation(mut self, disambiguation: Disambiguation) -> Self {
+        self.disambiguation = disambiguation;
+        self
+    }
+
+    /// Use the given policy to pick the year of dates written without one.
+    ///
+    /// The default is [`YearPolicy::Next`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use temps_jiff::JiffProvider;
+    /// use temps_core::YearPolicy;
+    ///
+    /// // "March 5" in a list of past events
+    /// let provider = JiffProvider::default().with_year_policy(YearPolicy::Previous);
+    /// ```
+    #[must_use]
+    pub fn with_year_policy(mut self, year_policy: YearPolicy) -> Self {
+        self.year_policy = year_policy;
+        self
+    }
+
+    /// Use the given instant for the end of a period ("end of month", "EOD").
+    ///
+    /// The default is [`PeriodEnd::LastNanosecond`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use temps_jiff::JiffProvider;
+    /// use temps_core::{Language, PeriodEnd, TimeParser, parse};
+    ///
+    /// let reference = date(2024, 3, 15).at(10, 0, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+    /// let provider = JiffProvider::new(TimeZone::UTC)
+    ///     .with_reference(reference)
+    ///     .with_period_end(PeriodEnd::NextStart);
+    ///
+    /// let expr = parse("end of month", Language::English).unwrap();
+    /// let end = provider.parse_expression(expr).unwrap();
+    /// assert_eq!(end.datetime(), date(2024, 4, 1).at(0, 0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn with_period_end(mut self, period_end: PeriodEnd) -> Self {
+        self.period_end = period_end;
+        self
+    }
+
+    /// Use the given first day of the week for "this week", "next week Monday"
+    /// and the like.
+    ///
+    /// The default is [`WeekStart::Monday`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use temps_jiff::JiffProvider;
+    /// use temps_core::WeekStart;
+    ///
+    /// // "this week" runs from Sunday to Saturday
+    /// let provider = JiffProvider::default().with_week_start(WeekStart::Sunday);
+    /// ```
+    #[must_use]
+    pub fn with_week_start(mut self, week_start: WeekStart) -> Self {
+        self.week_start = week_start;
+        self
+    }
+}
+
+fn to_jiff_weekday(day: Weekday) -> civil::Weekday {
+    match day {
+        Weekday::Monday => civil::Weekday::Monday,
+        Weekday::Tuesday => civil::Weekday::Tuesday,
+        Weekday::Wednesday => civil::Weekday::Wednesday,
+        Weekday::Thursday => civil::Weekday::Thursday,
+        Weekday::Friday => civil::Weekday::Friday,
+        Weekday::Saturday => civil::Weekday::Saturday,
+        Weekday::Sunday => civil::Weekday::Sunday,
+    }
+}
+
+/// End an iteration of occurrences after its first error
+fn end_at_error<T>(
+    occurrences: impl Iterator<Item = Result<T>>,
+) -> impl Iterator<Item = Result<T>> {
+    occurrences.scan(false, |failed, occurrence| {
+        if *failed {
+            return None;
+        }
+        *failed = occurrence.is_err();
+        Some(occurrence)
+    })
+}
+
+fn to_civil_date(date: StandardDate) -> Result<civil::Date> {
+    let (year, month, day) = jiff_date_components(date.year, date.month, date.day)?;
+    civil::Date::new(year, month, day)
+        .map_err(|_| TempsError::invalid_date(date.year, date.month, date.day))
+}
+
+fn from_civil_date(date: civil::Date) -> Result<StandardDate> {
+    Ok(StandardDate {
+        day: date.day() as u8,
+        month: date.month() as u8,
+        year: u16::try_from(date.year())
+            .map_err(|_| TempsError::date_calculation(ERR_DATE_CALC_INVALID))?,
+    })
+}
 
 fn jiff_date_components(year: u16, month: u8, day: u8) -> Result<(i16, i8, i8)> {
     Ok((
@@ -98,293 +393,980 @@ fn jiff_time_components(
     ))
 }
 
-impl TimeParser for JiffProvider {
-    type DateTime = Zoned;
-
-    fn now(&self) -> Self::DateTime {
-        Zoned::now()
+impl JiffProvider {
+    /// Parse a time expression and report how its local time was disambiguated.
+    ///
+    /// Works like [`TimeParser::parse_expression`], but also returns the [`Fold`]
+    /// that was chosen when the expression named a local time that is skipped or
+    /// repeated by a DST transition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::tz::TimeZone;
+    /// use temps_jiff::JiffProvider;
+    /// use temps_core::{Fold, FoldChoice, Language, parse};
+    ///
+    /// // 02:30 does not exist in Berlin on 2024-03-31
+    /// let provider = JiffProvider::new(TimeZone::get("Europe/Berlin").unwrap());
+    /// let expr = parse("2024-03-31T02:30:00", Language::English).unwrap();
+    /// let (datetime, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    /// assert_eq!(fold, Fold::Gap(FoldChoice::Later));
+    /// assert_eq!(datetime.hour(), 3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`TimeParser::parse_expression`], including
+    /// `TempsError::AmbiguousTime` when the policy is [`Disambiguation::Reject`]
+    /// and the local time is skipped or repeated.
+    pub fn parse_expression_with_fold(&self, expr: TimeExpression) -> Result<(Zoned, Fold)> {
+        match expr {
+            TimeExpression::Now => Ok((self.now(), Fold::Unambiguous)),
+            TimeExpression::Relative(rel) => Ok((self.resolve_relative(rel)?, Fold::Unambiguous)),
+            TimeExpression::RelativeDuration(rel) => {
+                Ok((self.resolve_relative_duration(rel)?, Fold::Unambiguous))
+            }
+            TimeExpression::Absolute(abs) => self.resolve_absolute(abs),
+            TimeExpression::Day(day_ref) => self.start_of_day(self.resolve_day(day_ref)?),
+            TimeExpression::Time(time) => {
+                let today = self.now().date();
+                self.localize(Self::at_time(today, time)?)
+            }
+            TimeExpression::DayTime(day_time) => {
+                let date = self.resolve_day(day_time.day)?;
+                self.localize(Self::at_time(date, day_time.time)?)
+            }
+            TimeExpression::Date(date) => self.start_of_day(to_civil_date(date)?),
+            TimeExpression::MonthDay(month_day) => {
+                let today = self.now().date();
+                self.start_of_day(Self::resolve_month_day(today, month_day, self.year_policy)?)
+            }
+            // Intervals and periods on their own resolve to their start
+            TimeExpression::Interval(interval) => {
+                self.parse_expression_with_fold(interval.start.into())
+            }
+            TimeExpression::Period(period) => self.start_of_day(self.resolve_period(period)?.0),
+            TimeExpression::Boundary(boundary) => self.resolve_boundary(boundary),
+            // Recurrences resolve to their next occurrence
+            TimeExpression::Recurrence(recurrence) => self
+                .resolve_occurrences(recurrence)?
+                .next()
+                .unwrap_or_else(|| Err(TempsError::date_calculation(ERR_NO_OCCURRENCES))),
+        }
     }
 
-    fn parse_expression(&self, expr: TimeExpression) -> Result<Self::DateTime> {
+    /// Resolve an interval or period expression to its start and end.
+    ///
+    /// Intervals are half-open: an end that names a whole day includes that
+    /// day, so "from Monday to Friday" ends at midnight after Friday. A bare
+    /// time, an unmodified weekday or a date without a year at the end is
+    /// resolved from the start, so "from 10pm to 2am" ends the next day.
+    /// Periods ("last week", "this month") cover the whole calendar period,
+    /// from midnight on its first day to midnight on the first day after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use temps_jiff::JiffProvider;
+    /// use temps_core::{Language, parse};
+    ///
+    /// let reference = date(2024, 3, 15).at(10, 0, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+    /// let provider = JiffProvider::new(TimeZone::UTC).with_reference(reference);
+    ///
+    /// let expr = parse("from tomorrow at 3pm to 5pm", Language::English).unwrap();
+    /// let (start, end) = provider.parse_interval(expr).unwrap();
+    /// assert_eq!(start.datetime(), date(2024, 3, 16).at(15, 0, 0, 0));
+    /// assert_eq!(end.datetime(), date(2024, 3, 16).at(17, 0, 0, 0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::UnsupportedOperation` if the expression is neither
+    /// an interval nor a period, `TempsError::DateCalculationError` if it ends before it
+    /// starts, and the errors of [`TimeParser::parse_expression`] otherwise.
+    pub fn parse_interval(&self, expr: TimeExpression) -> Result<(Zoned, Zoned)> {
         match expr {
-            TimeExpression::Now => Ok(self.now()),
-            TimeExpression::Relative(rel) => {
-                if rel.amount < 0 {
-                    return Err(TempsError::date_calculation(
-                        ERR_RELATIVE_AMOUNT_NON_NEGATIVE,
-                    ));
-                }
+            TimeExpression::Interval(interval) => self.resolve_interval(interval),
+            TimeExpression::Period(period) => {
+                let (start, end) = self.resolve_period(period)?;
+                Ok((self.start_of_day(start)?.0, self.start_of_day(end)?.0))
+            }
+            _ => Err(TempsError::unsupported_operation(ERR_NOT_AN_INTERVAL)),
+        }
+    }
 
-                let now = self.now();
-
-                // Create a span based on the time unit
-                let span = match rel.unit {
-                    TimeUnit::Second => Span::new().seconds(rel.amount),
-                    TimeUnit::Minute => Span::new().minutes(rel.amount),
-                    TimeUnit::Hour => Span::new().hours(rel.amount),
-                    TimeUnit::Day => Span::new().days(rel.amount),
-                    TimeUnit::Week => Span::new().weeks(rel.amount),
-                    TimeUnit::Month => Span::new().months(rel.amount),
-                    TimeUnit::Year => Span::new().years(rel.amount),
-                };
+    /// Resolve the occurrences of a recurrence at or after the reference time.
+    ///
+    /// Daily and longer recurrences fall on midnight unless they name a time
+    /// of day. Minutely and hourly recurrences step in absolute time from
+    /// midnight on the reference date, so "every 15 minutes" falls on the
+    /// quarter hours. The iterator ends with the count or until date of the
+    /// recurrence; take as many occurrences as needed otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use temps_jiff::JiffProvider;
+    /// use temps_core::{Language, Result, parse};
+    ///
+    /// // Friday
+    /// let reference = date(2024, 3, 15).at(10, 0, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+    /// let provider = JiffProvider::new(TimeZone::UTC).with_reference(reference);
+    ///
+    /// let expr = parse("jeden zweiten Freitag um 17:00", Language::German).unwrap();
+    /// let next = provider.occurrences(expr).unwrap().take(2).collect::<Result<Vec<_>>>();
+    /// let next: Vec<_> = next.unwrap().iter().map(|zoned| zoned.datetime()).collect();
+    /// assert_eq!(
+    ///     next,
+    ///     [date(2024, 3, 15).at(17, 0, 0, 0), date(2024, 3, 29).at(17, 0, 0, 0)]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `TempsError::UnsupportedOperation` if the expression is not a
+    /// recurrence. An occurrence that cannot be resolved, such as a local time
+    /// skipped by DST under [`Disambiguation::Reject`], is yielded as an error
+    /// and ends the iteration.
+    pub fn occurrences(
+        &self,
+        expr: TimeExpression,
+    ) -> Result<impl Iterator<Item = Result<Zoned>> + '_> {
+        let TimeExpression::Recurrence(recurrence) = expr else {
+            return Err(TempsError::unsupported_operation(ERR_NOT_A_RECURRENCE));
+        };
+        Ok(self
+            .resolve_occurrences(recurrence)?
+            .map(|occurrence| occurrence.map(|(zoned, _)| zoned)))
+    }
 
-                // Apply the span in the correct direction
-                match rel.direction {
-                    Direction::Past => now.checked_sub(span).map_err(|e| {
-                        TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string())
-                    }),
-                    Direction::Future => now.checked_add(span).map_err(|e| {
-                        TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string())
-                    }),
-                }
+    /// Resolve the occurrences of an iCalendar recurrence rule at or after
+    /// the reference time.
+    ///
+    /// The rule is expanded in the provider's time zone from its `DTSTART`,
+    /// or from the reference time if it has none, so occurrences keep their
+    /// local time of day across DST changes. UTC start and until times are
+    /// converted to the provider's time zone; local ones, including those
+    /// with a `TZID`, are read in it. `COUNT` counts occurrences from the
+    /// start, including those before the reference time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use temps_core::{Result, parse_rrule};
+    /// use temps_jiff::JiffProvider;
+    ///
+    /// let berlin = TimeZone::get("Europe/Berlin").unwrap();
+    /// let reference = date(2024, 3, 29).at(12, 0, 0, 0).to_zoned(berlin.clone()).unwrap();
+    /// let provider = JiffProvider::new(berlin).with_reference(reference);
+    ///
+    /// // Berlin switches to summer time on March 31
+    /// let rule = parse_rrule("DTSTART:20240301T090000\nRRULE:FREQ=DAILY;COUNT=31").unwrap();
+    /// let next = provider.rrule_occurrences(&rule).unwrap().collect::<Result<Vec<_>>>();
+    /// let times: Vec<_> = next.unwrap().iter().map(|zoned| zoned.datetime()).collect();
+    /// assert_eq!(times, [date(2024, 3, 30).at(9, 0, 0, 0), date(2024, 3, 31).at(9, 0, 0, 0)]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the start or until time cannot be resolved. A
+    /// local time skipped by DST moves according to the provider's
+    /// [`Disambiguation`], and is left out if that lands on the previous
+    /// occurrence; under [`Disambiguation::Reject`] it is yielded as an error
+    /// and ends the iteration.
+    pub fn rrule_occurrences<'a>(
+        &'a self,
+        rule: &'a RRule,
+    ) -> Result<impl Iterator<Item = Result<Zoned>> + 'a> {
+        let now = self.now();
+        let start = match rule.start {
+            // Local start times keep their time of day even if DST skips it
+            Some(start) if start.timezone.is_none() => {
+                let date = StandardDate {
+                    day: start.day,
+                    month: start.month,
+                    year: start.year,
+                };
+                let time = Time {
+                    hour: start.hour.unwrap_or(0),
+                    minute: start.minute.unwrap_or(0),
+                    second: start.second.unwrap_or(0),
+                    meridiem: None,
+                };
+                (date, time)
             }
-            TimeExpression::Absolute(abs) => {
-                use jiff::civil::{Date, DateTime, Time};
-                use jiff::tz::{Offset, TimeZone};
-
-                let (year, month, day) = jiff_date_components(abs.year, abs.month, abs.day)?;
-                let date = Date::new(year, month, day)
-                    .map_err(|e| TempsError::backend_error(e.to_string(), "jiff"))?;
-
-                if let (Some(hour), Some(minute)) = (abs.hour, abs.minute) {
-                    // Validate hour is in valid range (0-23)
-                    if hour > 23 {
-                        return Err(TempsError::invalid_time(
-                            hour,
-                            minute,
-                            abs.second.unwrap_or(0),
-                        ));
-                    }
-                    // Validate minute is in valid range (0-59)
-                    if minute > 59 {
-                        return Err(TempsError::invalid_time(
-                            hour,
-                            minute,
-                            abs.second.unwrap_or(0),
-                        ));
-                    }
-                    // Validate second is in valid range (0-59)
-                    if let Some(second) = abs.second
-                        && second > 59
-                    {
-                        return Err(TempsError::invalid_time(hour, minute, second));
-                    }
+            start => {
+                let start = match start {
+                    Some(start) => self.resolve_absolute(start)?.0,
+                    None => now.clone(),
+                };
+                let time = Time {
+                    hour: start.hour() as u8,
+                    minute: start.minute() as u8,
+                    second: start.second() as u8,
+                    meridiem: None,
+                };
+                (from_civil_date(start.date())?, time)
+            }
+        };
+        let until = match rule.end {
+            // Until a date includes the whole day
+            Some(RRuleEnd::Until(until)) if until.hour.is_none() => {
+                let date = self.resolve_absolute(until)?.0.date();
+                let next_day = self.start_of_next_day(date)?;
+                Some(
+                    next_day
+                        .checked_sub(Span::new().nanoseconds(1))
+                        .map_err(|_| TempsError::date_calculation(ERR_DATE_CALC_INVALID))?,
+                )
+            }
+            Some(RRuleEnd::Until(until)) => Some(self.resolve_absolute(until)?.0),
+            _ => None,
+        };
+        let count = match rule.end {
+            Some(RRuleEnd::Count(count)) => count as usize,
+            _ => usize::MAX,
+        };
 
-                    let second = abs.second.unwrap_or(0);
-                    let nanosecond = abs.nanosecond.unwrap_or(0);
-                    let (hour, minute, second, nanosecond) =
-                        jiff_time_components(hour, minute, second, nanosecond)?;
-
-                    let time = Time::new(hour, minute, second, nanosecond)
-                        .map_err(|e| TempsError::backend_error(e.to_string(), "jiff"))?;
-
-                    let datetime = DateTime::from_parts(date, time);
-
-                    match &abs.timezone {
-                        Some(temps_core::Timezone::Utc) => datetime
-                            .to_zoned(TimeZone::UTC)
-                            .map(|z| z.with_time_zone(TimeZone::system()))
-                            .map_err(|e| {
-                                TempsError::backend_error(
-                                    format!("{ERR_TIMEZONE_CONVERSION}: {e}"),
-                                    "jiff",
-                                )
-                            }),
-                        Some(temps_core::Timezone::Offset { hours, minutes }) => {
-                            if !is_valid_timezone_offset(temps_core::Timezone::Offset {
-                                hours: *hours,
-                                minutes: *minutes,
-                            }) {
-                                return Err(TempsError::invalid_timezone_offset(*hours, *minutes));
-                            }
-
-                            let total_seconds = calculate_timezone_offset_seconds(*hours, *minutes);
-                            let offset = Offset::from_seconds(total_seconds).map_err(|_| {
-                                TempsError::invalid_timezone_offset(*hours, *minutes)
-                            })?;
-
-                            datetime
-                                .to_zoned(TimeZone::fixed(offset))
-                                .map(|z| z.with_time_zone(TimeZone::system()))
-                                .map_err(|e| {
-                                    TempsError::backend_error(
-                                        format!("{ERR_TIMEZONE_CONVERSION}: {e}"),
-                                        "jiff",
-                                    )
-                                })
-                        }
-                        None => {
-                            // No timezone specified, treat as system timezone
-                            datetime.to_zoned(TimeZone::system()).map_err(|e| {
-                                TempsError::backend_error(
-                                    format!("{ERR_TIMEZONE_CONVERSION}: {e}"),
-                                    "jiff",
-                                )
-                            })
-                        }
+        let mut previous: Option<Zoned> = None;
+        let occurrences = rrule_occurrences(rule, start.0, start.1)
+            .map(move |(date, time)| self.localize(Self::at_time(to_civil_date(date)?, time)?))
+            // A local time skipped by DST can land on the previous occurrence
+            .filter(move |occurrence| match occurrence {
+                Ok((zoned, _)) => {
+                    let later = previous.as_ref().is_none_or(|previous| zoned > previous);
+                    if later {
+                        previous = Some(zoned.clone());
                     }
-                } else {
-                    // Date only, set time to midnight
-                    let datetime = date.at(0, 0, 0, 0);
-                    datetime.to_zoned(TimeZone::system()).map_err(|e| {
-                        TempsError::backend_error(format!("{ERR_TIMEZONE_CONVERSION}: {e}"), "jiff")
-                    })
+                    later
                 }
-            }
-            TimeExpression::Day(day_ref) => {
-                let now = self.now();
-                match day_ref {
-                    DayReference::Today => {
-                        let date = now.date();
-                        date.at(0, 0, 0, 0)
-                            .to_zoned(now.time_zone().clone())
-                            .map_err(|e| {
-                                TempsError::date_calculation_with_source(
-                                    "Failed to create today's date",
-                                    e.to_string(),
-                                )
-                            })
-                    }
-                    DayReference::Yesterday => {
-                        let yesterday = now.checked_sub(Span::new().days(1)).map_err(|e| {
-                            TempsError::date_calculation_with_source(
-                                "Failed to calculate yesterday",
-                                e.to_string(),
-                            )
-                        })?;
-                        let date = yesterday.date();
-                        date.at(0, 0, 0, 0)
-                            .to_zoned(now.time_zone().clone())
-                            .map_err(|e| {
-                                TempsError::date_calculation_with_source(
-                                    "Failed to create yesterday's date",
-                                    e.to_string(),
-                                )
-                            })
-                    }
-                    DayReference::Tomorrow => {
-                        let tomorrow = now.checked_add(Span::new().days(1)).map_err(|e| {
-                            TempsError::date_calculation_with_source(
-                                "Failed to calculate tomorrow",
-                                e.to_string(),
-                            )
-                        })?;
-                        let date = tomorrow.date();
-                        date.at(0, 0, 0, 0)
-                            .to_zoned(now.time_zone().clone())
-                            .map_err(|e| {
-                                TempsError::date_calculation_with_source(
-                                    "Failed to create tomorrow's date",
-                                    e.to_string(),
-                                )
-                            })
-                    }
-                    DayReference::Weekday { day, modifier } => {
-                        let target_weekday = match day {
-                            Weekday::Monday => jiff::civil::Weekday::Monday,
-                            Weekday::Tuesday => jiff::civil::Weekday::Tuesday,
-                            Weekday::Wednesday => jiff::civil::Weekday::Wednesday,
-                            Weekday::Thursday => jiff::civil::Weekday::Thursday,
-                            Weekday::Friday => jiff::civil::Weekday::Friday,
-                            Weekday::Saturday => jiff::civil::Weekday::Saturday,
-                            Weekday::Sunday => jiff::civil::Weekday::Sunday,
-                        };
-
-                        let current_weekday = now.weekday();
-                        let current_offset = current_weekday.to_monday_zero_offset() as i64;
-                        let target_offset = target_weekday.to_monday_zero_offset() as i64;
-
-                        let days_to_add =
-                            calculate_weekday_offset(current_offset, target_offset, modifier);
-                        let target_date = now.checked_add(Span::new().days(days_to_add));
-
-                        let target = target_date.map_err(|e| {
-                            TempsError::date_calculation_with_source(
-                                "Failed to calculate weekday",
-                                e.to_string(),
-                            )
-                        })?;
-                        let date = target.date();
-                        date.at(0, 0, 0, 0)
-                            .to_zoned(now.time_zone().clone())
-                            .map_err(|e| {
-                                TempsError::date_calculation_with_source(
-                                    "Failed to create weekday date",
-                                    e.to_string(),
-                                )
-                            })
+                Err(_) => true,
+            })
+            .take_while(move |occurrence| match (occurrence, &until) {
+                (Ok((zoned, _)), Some(until)) => zoned <= until,
+                _ => true,
+            })
+            .take(count)
+            .filter(move |occurrence| !matches!(occurrence, Ok((zoned, _)) if *zoned < now))
+            .map(|occurrence| occurrence.map(|(zoned, _)| zoned));
+        Ok(end_at_error(occurrences))
+    }
+
+    /// Resolve the fire times of a cron schedule strictly after `after`, in
+    /// ascending order.
+    ///
+    /// The schedule is matched against local wall time in the provider's time
+    /// zone, so `0 9 * * *` fires at 9:00 local time on both sides of a DST
+    /// change. A local time repeated by DST fires once, at the instant chosen
+    /// by the provider's [`Disambiguation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use temps_core::parse_cron;
+    /// use temps_jiff::JiffProvider;
+    ///
+    /// let berlin = TimeZone::get("Europe/Berlin").unwrap();
+    /// let provider = JiffProvider::new(berlin.clone());
+    /// let schedule = parse_cron("*/15 9-17 * * MON-FRI").unwrap();
+    ///
+    /// // Friday evening
+    /// let after = date(2024, 3, 15).at(17, 50, 0, 0).to_zoned(berlin).unwrap();
+    /// let mut next = provider.next_after(schedule, &after).unwrap();
+    /// assert_eq!(next.next().unwrap().unwrap().datetime(), date(2024, 3, 18).at(9, 0, 0, 0));
+    /// assert_eq!(next.next().unwrap().unwrap().datetime(), date(2024, 3, 18).at(9, 15, 0, 0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `after` is outside the supported date range. A
+    /// local time skipped by DST moves according to the provider's
+    /// [`Disambiguation`], and is left out if that lands on a time already
+    /// yielded; under [`Disambiguation::Reject`] it is yielded as an error and
+    /// ends the iteration.
+    pub fn next_after(
+        &self,
+        schedule: CronSchedule,
+        after: &Zoned,
+    ) -> Result<impl Iterator<Item = Result<Zoned>> + '_> {
+        self.resolve_cron(schedule, after, Direction::Future)
+    }
+
+    /// Resolve the fire times of a cron schedule strictly before `before`, in
+    /// descending order.
+    ///
+    /// This is the reverse of [`next_after`](Self::next_after), with the same
+    /// handling of time zones and DST.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    /// use temps_core::parse_cron;
+    /// use temps_jiff::JiffProvider;
+    ///
+    /// let provider = JiffProvider::new(TimeZone::UTC);
+    /// let schedule = parse_cron("@daily").unwrap();
+    ///
+    /// let before = date(2024, 3, 15).at(0, 0, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+    /// let mut previous = provider.prev_before(schedule, &before).unwrap();
+    /// assert_eq!(previous.next().unwrap().unwrap().datetime(), date(2024, 3, 14).at(0, 0, 0, 0));
+    /// assert_eq!(previous.next().unwrap().unwrap().datetime(), date(2024, 3, 13).at(0, 0, 0, 0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// As for [`next_after`](Self::next_after).
+    pub fn prev_before(
+        &self,
+        schedule: CronSchedule,
+        before: &Zoned,
+    ) -> Result<impl Iterator<Item = Result<Zoned>> + '_> {
+        self.resolve_cron(schedule, before, Direction::Past)
+    }
+
+    /// Describe a point in time relative to the reference time, such as
+    /// "in 5 minutes" or "vor 2 Tagen".
+    ///
+    /// The text uses the largest units first and is rounded as set in the
+    /// [`HumanizeOptions`]; a time closer than the smallest unit is "now".
+    /// [`parse`](temps_core::parse) reads the text back, and this provider
+    /// resolves it to the rounded time: months and years are calendar months
+    /// and years, as when parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::{Span, civil::date, tz::TimeZone};
+    /// use temps_core::{HumanizeOptions, Language, Rounding, TimeParser, TimeUnit, parse};
+    /// use temps_jiff::JiffProvider;
+    ///
+    /// let reference = date(2024, 3, 15).at(12, 0, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+    /// let provider = JiffProvider::new(TimeZone::UTC).with_reference(reference.clone());
+    ///
+    /// let later = reference.checked_add(Span::new().minutes(150)).unwrap();
+    /// assert_eq!(provider.humanize(&later, Language::English).unwrap(), "in 3 hours");
+    ///
+    /// let options = HumanizeOptions::new(Language::German)
+    ///     .with_max_units(2)
+    ///     .with_smallest_unit(TimeUnit::Minute)
+    ///     .with_rounding(Rounding::Down);
+    /// let text = provider.humanize(&later, options).unwrap();
+    /// assert_eq!(text, "in 2 Stunden 30 Minuten");
+    ///
+    /// // The text resolves back to the same time
+    /// let expr = parse(&text, Language::German).unwrap();
+    /// assert_eq!(provider.parse_expression(expr).unwrap(), later);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rounded time is outside the supported date
+    /// range.
+    pub fn humanize(&self, zoned: &Zoned, options: impl Into<HumanizeOptions>) -> Result<String> {
+        let options = options.into();
+        let relative =
+            self.relative_to_now(&zoned.with_time_zone(self.time_zone.clone()), &options)?;
+        format_relative(&relative, options.language())
+    }
+
+    fn resolve_interval(&self, interval: Interval) -> Result<(Zoned, Zoned)> {
+        let (start, _) = self.parse_expression_with_fold(interval.start.into())?;
+        let start_date = start.date();
+
+        let end =
+            match interval.end {
+                // "from tomorrow at 3pm to 5pm", "from 10pm to 2am"
+                Endpoint::Time(time) => {
+                    let (end, _) = self.localize(Self::at_time(start_date, time)?)?;
+                    if end > start {
+                        end
+                    } else {
+                        let next_day = Self::next_day(start_date)?;
+                        self.localize(Self::at_time(next_day, time)?)?.0
                     }
                 }
-            }
-            TimeExpression::Time(time) => {
-                let now = self.now();
-                let date = now.date();
-
-                if !is_valid_time(time.hour, time.minute, time.second, time.meridiem) {
-                    return Err(TempsError::invalid_time(
-                        time.hour,
-                        time.minute,
-                        time.second,
-                    ));
+                // "from Friday at 9am to Monday at 5pm"
+                Endpoint::DayTime(DayTime {
+                    day:
+                        DayReference::Weekday {
+                            day,
+                            modifier: None,
+                        },
+                    time,
+                }) => {
+                    let date = Self::weekday_from(start_date, day)?;
+                    self.localize(Self::at_time(date, time)?)?.0
                 }
+                // Whole days include the last day
+                Endpoint::Day(DayReference::Weekday {
+                    day,
+                    modifier: None,
+                }) => self.start_of_next_day(Self::weekday_from(start_date, day)?)?,
+                Endpoint::Day(day_ref) => self.start_of_next_day(self.resolve_day(day_ref)?)?,
+                Endpoint::Date(date) => self.start_of_next_day(to_civil_date(date)?)?,
+                Endpoint::MonthDay(month_day) => self.start_of_next_day(
+                    Self::resolve_month_day(start_date, month_day, YearPolicy::Next)?,
+                )?,
+                end => self.parse_expression_with_fold(end.into())?.0,
+            };
 
-                let hour = convert_12_to_24_hour(time.hour, time.meridiem.as_ref());
+        if end < start {
+            return Err(TempsError::date_calculation(ERR_INTERVAL_END_BEFORE_START));
+        }
+        Ok((start, end))
+    }
 
-                let (hour, minute, second, nanosecond) =
-                    jiff_time_components(hour, time.minute, time.second, 0)?;
+    fn resolve_occurrences(
+        &self,
+        recurrence: Recurrence,
+    ) -> Result<impl Iterator<Item = Result<(Zoned, Fold)>> + '_> {
+        let now = self.now();
+        let today = now.date();
+        let time = recurrence.time.unwrap_or(Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            meridiem: None,
+        });
+        let (until, count) = match recurrence.end {
+            Some(RecurrenceEnd::Until(date)) => (Some(to_civil_date(date)?), usize::MAX),
+            Some(RecurrenceEnd::UntilMonthDay(month_day)) => (
+                Some(Self::resolve_month_day(today, month_day, YearPolicy::Next)?),
+                usize::MAX,
+            ),
+            Some(RecurrenceEnd::Count(count)) => (None, count as usize),
+            None => (None, usize::MAX),
+        };
 
-                date.at(hour, minute, second, nanosecond)
-                    .to_zoned(now.time_zone().clone())
-                    .map_err(|e| {
-                        TempsError::backend_error(format!("Failed to create time: {e}"), "jiff")
+        let occurrences: Box<dyn Iterator<Item = Result<(Zoned, Fold)>> + '_> = match recurrence
+            .frequency
+        {
+            Frequency::Minutely | Frequency::Hourly => {
+                let interval = i64::from(recurrence.interval.max(1));
+                let step = match recurrence.frequency {
+                    Frequency::Minutely => Span::new().try_minutes(interval),
+                    _ => Span::new().try_hours(interval),
+                }
+                .map_err(|_| TempsError::arithmetic_overflow(ERR_DURATION_OVERFLOW))?;
+                let weekdays = recurrence.weekdays;
+                let first = self.localize(Self::at_time(today, time)?)?;
+                Box::new(
+                    std::iter::successors(Some(first), move |(zoned, _)| {
+                        Some((zoned.checked_add(step).ok()?, Fold::Unambiguous))
                     })
+                    .take_while(move |(zoned, _)| until.is_none_or(|until| zoned.date() <= until))
+                    .filter(move |(zoned, _)| {
+                        weekdays.is_empty()
+                            || weekdays
+                                .iter()
+                                .any(|day| to_jiff_weekday(day) == zoned.weekday())
+                    })
+                    .map(Ok),
+                )
             }
-            TimeExpression::DayTime(day_time) => {
-                // First get the day
-                let day_result = self.parse_expression(TimeExpression::Day(day_time.day))?;
-                let date = day_result.date();
-
-                if !is_valid_time(
-                    day_time.time.hour,
-                    day_time.time.minute,
-                    day_time.time.second,
-                    day_time.time.meridiem,
-                ) {
-                    return Err(TempsError::invalid_time(
-                        day_time.time.hour,
-                        day_time.time.minute,
-                        day_time.time.second,
-                    ));
+            _ => {
+                let dates = recurrence_dates(from_civil_date(today)?, recurrence, self.week_start);
+                Box::new(
+                    dates
+                        .map(move |date| self.localize(Self::at_time(to_civil_date(date)?, time)?)),
+                )
+            }
+        };
+
+        Ok(end_at_error(
+            occurrences
+                .filter(move |occurrence| !matches!(occurrence, Ok((zoned, _)) if *zoned < now))
+                .take(count),
+        ))
+    }
+
+    fn resolve_cron(
+        &self,
+        schedule: CronSchedule,
+        from: &Zoned,
+        direction: Direction,
+    ) -> Result<impl Iterator<Item = Result<Zoned>> + '_> {
+        let from = from.with_time_zone(self.time_zone.clone());
+        let time = Time {
+            hour: from.hour() as u8,
+            minute: from.minute() as u8,
+            second: from.second() as u8,
+            meridiem: None,
+        };
+        let date = from_civil_date(from.date())?;
+
+        // Each time must pass the last, which also drops the local time at
+        // `from` and skipped local times moved onto a time already yielded
+        let mut bound = from;
+        let times = cron_times(schedule, date, time, direction)
+            .map(move |(date, time)| self.localize(Self::at_time(to_civil_date(date)?, time)?))
+            .filter(move |time| match time {
+                Ok((zoned, _)) => {
+                    let passes = match direction {
+                        Direction::Future => *zoned > bound,
+                        Direction::Past => *zoned < bound,
+                    };
+                    if passes {
+                        bound = zoned.clone();
+                    }
+                    passes
                 }
+                Err(_) => true,
+            })
+            .map(|time| time.map(|(zoned, _)| zoned));
+        Ok(end_at_error(times))
+    }
 
-                let hour =
-                    convert_12_to_24_hour(day_time.time.hour, day_time.time.meridiem.as_ref());
+    /// Split the time from now to `target` into the units of `options`,
+    /// rounding the last one
+    fn relative_to_now(
+        &self,
+        target: &Zoned,
+        options: &HumanizeOptions,
+    ) -> Result<RelativeDuration> {
+        let now = self.now();
+        let direction = if *target < now {
+            Direction::Past
+        } else {
+            Direction::Future
+        };
+        let (duration, last, reached) = Self::split_units(&now, target, direction, options)?;
+        let round_up = match options.rounding() {
+            Rounding::Down => false,
+            Rounding::Nearest => {
+                let next = Self::shift(&reached, 1, last, direction)?;
+                reached.duration_until(target).abs() * 2 >= reached.duration_until(&next).abs()
+            }
+            Rounding::Up => reached != *target,
+        };
 
-                let (hour, minute, second, nanosecond) =
-                    jiff_time_components(hour, day_time.time.minute, day_time.time.second, 0)?;
+        // Split again so that rounding up carries into larger units
+        let duration = if round_up {
+            let rounded = Self::shift(&reached, 1, last, direction)?;
+            Self::split_units(&now, &rounded, direction, options)?.0
+        } else {
+            duration
+        };
+        Ok(RelativeDuration {
+            duration,
+            direction,
+        })
+    }
 
-                date.at(hour, minute, second, nanosecond)
-                    .to_zoned(day_result.time_zone().clone())
+    /// Take the largest amount of each unit of `options` that does not pass
+    /// `to`, returning the amounts, the last unit considered and the time
+    /// reached
+    fn split_units(
+        from: &Zoned,
+        to: &Zoned,
+        direction: Direction,
+        options: &HumanizeOptions,
+    ) -> Result<(temps_core::Duration, TimeUnit, Zoned)> {
+        let mut duration = temps_core::Duration::default();
+        let mut reached = from.clone();
+        let mut last = options.smallest_unit();
+        let mut written = 0;
+        for unit in options.units() {
+            last = unit;
+            let fits = |amount| {
+                Self::shift(&reached, amount, unit, direction).is_ok_and(
+                    |shifted| match direction {
+                        Direction::Past => shifted >= *to,
+                        Direction::Future => shifted <= *to,
+                    },
+                )
+            };
+            // Estimate from the unit's average length, then correct
+            let mut amount = reached.duration_until(to).as_secs().abs() / nominal_seconds(unit);
+            while amount > 0 && !fits(amount) {
+                amount -= 1;
+            }
+            while fits(amount + 1) {
+                amount += 1;
+            }
+
+            if amount > 0 {
+                reached = Self::shift(&reached, amount, unit, direction)?;
+                duration = duration.with(unit, amount);
+                written += 1;
+                if written == options.max_units() {
+                    break;
+                }
+            }
+        }
+        Ok((duration, last, reached))
+    }
+
+    fn resolve_boundary(&self, boundary: Boundary) -> Result<(Zoned, Fold)> {
+        let (start, end) = self.resolve_period(boundary.period)?;
+        match (boundary.edge, self.period_end) {
+            (Edge::Start, _) => self.start_of_day(start),
+            (Edge::End, PeriodEnd::NextStart) => self.start_of_day(end),
+            (Edge::End, PeriodEnd::LastNanosecond) => {
+                let (next_start, fold) = self.start_of_day(end)?;
+                let last = next_start
+                    .checked_sub(Span::new().nanoseconds(1))
                     .map_err(|e| {
-                        TempsError::backend_error(format!("Failed to create day time: {e}"), "jiff")
-                    })
+                        TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string())
+                    })?;
+                Ok((last, fold))
             }
-            TimeExpression::Date(date) => {
-                use jiff::civil::Date;
+        }
+    }
+
+    /// Calculate the first day of a calendar period and the first day after it.
+    fn resolve_period(&self, period: Period) -> Result<(civil::Date, civil::Date)> {
+        let today = self.now().date();
+        let invalid = || TempsError::date_calculation(ERR_DATE_CALC_INVALID);
+
+        match period.unit {
+            PeriodUnit::Day => {
+                let start = today
+                    .checked_add(Span::new().try_days(period.offset).map_err(|_| invalid())?)
+                    .map_err(|_| invalid())?;
+                Ok((start, Self::next_day(start)?))
+            }
+            PeriodUnit::Week => {
+                let days = calculate_week_weekday_offset(
+                    days_from_week_start(
+                        today.weekday().to_monday_zero_offset() as i64,
+                        self.week_start,
+                    ),
+                    0,
+                    period.offset,
+                );
+                let start = today
+                    .checked_add(Span::new().try_days(days).map_err(|_| invalid())?)
+                    .map_err(|_| invalid())?;
+                let end = start
+                    .checked_add(Span::new().days(i64::from(DAYS_PER_WEEK)))
+                    .map_err(|_| invalid())?;
+                Ok((start, end))
+            }
+            unit => {
+                let (start, end) = period_month_bounds(
+                    i32::from(today.year()),
+                    today.month() as u8,
+                    unit,
+                    period.offset,
+                )
+                .ok_or_else(invalid)?;
+                let first_day = |(year, month): (i32, u8)| {
+                    let year = i16::try_from(year).map_err(|_| invalid())?;
+                    civil::Date::new(year, month as i8, 1).map_err(|_| invalid())
+                };
+                Ok((first_day(start)?, first_day(end)?))
+            }
+        }
+    }
+
+    fn start_of_day(&self, date: civil::Date) -> Result<(Zoned, Fold)> {
+        self.localize(date.at(0, 0, 0, 0))
+    }
+
+    fn start_of_next_day(&self, date: civil::Date) -> Result<Zoned> {
+        Ok(self.start_of_day(Self::next_day(date)?)?.0)
+    }
+
+    fn next_day(date: civil::Date) -> Result<civil::Date> {
+        date.tomorrow().map_err(|e| {
+            TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string())
+        })
+    }
+
+    /// The first date on or after `date` that falls on `day`.
+    fn weekday_from(date: civil::Date, day: Weekday) -> Result<civil::Date> {
+        let days = calculate_weekday_offset(
+            date.weekday().to_monday_zero_offset() as i64,
+            to_jiff_weekday(day).to_monday_zero_offset() as i64,
+            None,
+        );
+        date.checked_add(Span::new().days(days)).map_err(|e| {
+            TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string())
+        })
+    }
+
+    fn resolve_month_day(
+        today: civil::Date,
+        month_day: MonthDay,
+        policy: YearPolicy,
+    ) -> Result<civil::Date> {
+        let date = resolve_month_day(from_civil_date(today)?, month_day, policy)
+            .ok_or_else(|| TempsError::date_calculation(ERR_DATE_CALC_INVALID))?;
+        to_civil_date(date)
+    }
+
+    fn resolve_relative(&self, rel: RelativeTime) -> Result<Zoned> {
+        Self::shift(&self.now(), rel.amount, rel.unit, rel.direction)
+    }
+
+    fn resolve_relative_duration(&self, rel: RelativeDuration) -> Result<Zoned> {
+        // Apply the largest units first: "in 1 month 2 days" from January 30
+        // is March 2 (leap year), not March 1
+        let datetime = rel
+            .duration
+            .components()
+            .try_fold(self.now(), |datetime, (unit, amount)| {
+                Self::shift(&datetime, amount, unit, rel.direction)
+            })?;
+
+        let nanoseconds = Span::new()
+            .try_nanoseconds(rel.duration.nanoseconds)
+            .map_err(|_| TempsError::arithmetic_overflow(ERR_DURATION_OVERFLOW))?;
+        match rel.direction {
+            Direction::Past => datetime.checked_sub(nanoseconds),
+            Direction::Future => datetime.checked_add(nanoseconds),
+        }
+        .map_err(|e| TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string()))
+    }
+
+    /// Move a point in time by an amount of a single unit.
+    fn shift(from: &Zoned, amount: i64, unit: TimeUnit, direction: Direction) -> Result<Zoned> {
+        if amount < 0 {
+            return Err(TempsError::date_calculation(
+                ERR_RELATIVE_AMOUNT_NON_NEGATIVE,
+            ));
+        }
+
+        // Create a span based on the time unit
+        let span = match unit {
+            TimeUnit::Second => Span::new().try_seconds(amount),
+            TimeUnit::Minute => Span::new().try_minutes(amount),
+            TimeUnit::Hour => Span::new().try_hours(amount),
+            TimeUnit::Day => Span::new().try_days(amount),
+            TimeUnit::Week => Span::new().try_weeks(amount),
+            TimeUnit::Month => Span::new().try_months(amount),
+            TimeUnit::Year => Span::new().try_years(amount),
+        }
+        .map_err(|_| TempsError::arithmetic_overflow(ERR_DURATION_OVERFLOW))?;
+
+        // Apply the span in the correct direction
+        match direction {
+            Direction::Past => from.checked_sub(span).map_err(|e| {
+                TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string())
+            }),
+            Direction::Future => from.checked_add(span).map_err(|e| {
+                TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string())
+            }),
+        }
+    }
+
+    fn resolve_absolute(&self, abs: AbsoluteTime) -> Result<(Zoned, Fold)> {
+        use jiff::civil::{Date, DateTime, Time};
+        use jiff::tz::Offset;
+
+        let (year, month, day) = jiff_date_components(abs.year, abs.month, abs.day)?;
+        let date = Date::new(year, month, day)
+            .map_err(|e| TempsError::backend_error(e.to_string(), "jiff"))?;
 
-                let (year, month, day) = jiff_date_components(date.year, date.month, date.day)?;
-                let jiff_date = Date::new(year, month, day)
-                    .map_err(|_| TempsError::invalid_date(date.year, date.month, date.day))?;
+        if let (Some(hour), Some(minute)) = (abs.hour, abs.minute) {
+            // Validate hour is in valid range (0-23)
+            if hour > 23 {
+                return Err(TempsError::invalid_time(
+                    hour,
+                    minute,
+                    abs.second.unwrap_or(0),
+                ));
+            }
+            // Validate minute is in valid range (0-59)
+            if minute > 59 {
+                return Err(TempsError::invalid_time(
+                    hour,
+                    minute,
+                    abs.second.unwrap_or(0),
+                ));
+            }
+            // Validate second is in valid range (0-59)
+            if let Some(second) = abs.second
+                && second > 59
+            {
+                return Err(TempsError::invalid_time(hour, minute, second));
+            }
+
+            let second = abs.second.unwrap_or(0);
+            let nanosecond = abs.nanosecond.unwrap_or(0);
+            let (hour, minute, second, nanosecond) =
+                jiff_time_components(hour, minute, second, nanosecond)?;
+
+            let time = Time::new(hour, minute, second, nanosecond)
+                .map_err(|e| TempsError::backend_error(e.to_string(), "jiff"))?;
 
-                jiff_date
-                    .at(0, 0, 0, 0)
-                    .to_zoned(jiff::tz::TimeZone::system())
+            let datetime = DateTime::from_parts(date, time);
+
+            match &abs.timezone {
+                Some(temps_core::Timezone::Utc) => datetime
+                    .to_zoned(TimeZone::UTC)
+                    .map(|z| (z.with_time_zone(self.time_zone.clone()), Fold::Unambiguous))
                     .map_err(|e| {
-                        TempsError::backend_error(format!("Failed to create date: {e}"), "jiff")
-                    })
+                        TempsError::backend_error(format!("{ERR_TIMEZONE_CONVERSION}: {e}"), "jiff")
+                    }),
+                Some(temps_core::Timezone::Offset { hours, minutes }) => {
+                    if !is_valid_timezone_offset(temps_core::Timezone::Offset {
+                        hours: *hours,
+                        minutes: *minutes,
+                    }) {
+                        return Err(TempsError::invalid_timezone_offset(*hours, *minutes));
+                    }
+
+                    let total_seconds = calculate_timezone_offset_seconds(*hours, *minutes);
+                    let offset = Offset::from_seconds(total_seconds)
+                        .map_err(|_| TempsError::invalid_timezone_offset(*hours, *minutes))?;
+
+                    datetime
+                        .to_zoned(TimeZone::fixed(offset))
+                        .map(|z| (z.with_time_zone(self.time_zone.clone()), Fold::Unambiguous))
+                        .map_err(|e| {
+                            TempsError::backend_error(
+                                format!("{ERR_TIMEZONE_CONVERSION}: {e}"),
+                                "jiff",
+                            )
+                        })
+                }
+                // No timezone specified, treat as local time in the provider's zone
+                None => self.localize(datetime),
             }
+        } else {
+            // Date only, set time to midnight
+            self.localize(date.at(0, 0, 0, 0))
         }
     }
+
+    /// Calculate the calendar date a day reference points to.
+    fn resolve_day(&self, day_ref: DayReference) -> Result<civil::Date> {
+        let today = self.now().date();
+        let week_day = |monday_offset| days_from_week_start(monday_offset, self.week_start);
+        let current_offset = today.weekday().to_monday_zero_offset() as i64;
+
+        let days = match day_ref {
+            DayReference::Today => 0,
+            DayReference::Yesterday => -1,
+            DayReference::Tomorrow => 1,
+            DayReference::Weekday { day, modifier } => {
+                let target_offset = to_jiff_weekday(day).to_monday_zero_offset() as i64;
+                calculate_weekday_offset(current_offset, target_offset, modifier)
+            }
+            DayReference::WeekdayInWeek { day, week_offset } => {
+                let target_offset = to_jiff_weekday(day).to_monday_zero_offset() as i64;
+                calculate_week_weekday_offset(
+                    week_day(current_offset),
+                    week_day(target_offset),
+                    week_offset,
+                )
+            }
+            DayReference::Relative(rel) => return self.resolve_relative_day(today, rel),
+        };
+
+        today.checked_add(Span::new().days(days)).map_err(|e| {
+            TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string())
+        })
+    }
+
+    /// Calculate the date a relative offset lands on.
+    ///
+    /// Calendar units move the date directly, so "in 3 days" is three
+    /// calendar days away even across a DST transition.
+    fn resolve_relative_day(&self, today: civil::Date, rel: RelativeTime) -> Result<civil::Date> {
+        if rel.amount < 0 {
+            return Err(TempsError::date_calculation(
+                ERR_RELATIVE_AMOUNT_NON_NEGATIVE,
+            ));
+        }
+
+        let span = match rel.unit {
+            TimeUnit::Day => Span::new().try_days(rel.amount),
+            TimeUnit::Week => Span::new().try_weeks(rel.amount),
+            TimeUnit::Month => Span::new().try_months(rel.amount),
+            TimeUnit::Year => Span::new().try_years(rel.amount),
+            TimeUnit::Hour | TimeUnit::Minute | TimeUnit::Second => {
+                return self.resolve_relative(rel).map(|datetime| datetime.date());
+            }
+        };
+
+        span.and_then(|span| match rel.direction {
+            Direction::Past => today.checked_sub(span),
+            Direction::Future => today.checked_add(span),
+        })
+        .map_err(|e| TempsError::date_calculation_with_source(ERR_DATE_CALC_ERROR, e.to_string()))
+    }
+
+    /// Combine a date with a (possibly 12-hour) time of day.
+    fn at_time(date: civil::Date, time: Time) -> Result<civil::DateTime> {
+        if !is_valid_time(time.hour, time.minute, time.second, time.meridiem) {
+            return Err(TempsError::invalid_time(
+                time.hour,
+                time.minute,
+                time.second,
+            ));
+        }
+
+        let hour = convert_12_to_24_hour(time.hour, time.meridiem.as_ref());
+
+        let (hour, minute, second, nanosecond) =
+            jiff_time_components(hour, time.minute, time.second, 0)?;
+
+        let time = civil::Time::new(hour, minute, second, nanosecond)
+            .map_err(|e| TempsError::backend_error(e.to_string(), "jiff"))?;
+
+        Ok(date.to_datetime(time))
+    }
+
+    /// Interpret a civil datetime in the provider's time zone, applying the
+    /// disambiguation policy to skipped and repeated local times.
+    fn localize(&self, datetime: civil::DateTime) -> Result<(Zoned, Fold)> {
+        let ambiguous = self.time_zone.to_ambiguous_zoned(datetime);
+
+        let fold = match ambiguous.offset() {
+            AmbiguousOffset::Unambiguous { .. } => Fold::Unambiguous,
+            AmbiguousOffset::Gap { .. } => Fold::Gap(
+                self.disambiguation
+                    .gap_choice()
+                    .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))?,
+            ),
+            AmbiguousOffset::Fold { .. } => Fold::Overlap(
+                self.disambiguation
+                    .overlap_choice()
+                    .ok_or_else(|| TempsError::ambiguous_time(ERR_AMBIGUOUS_TIME))?,
+            ),
+        };
+
+        let choice = match fold {
+            Fold::Gap(FoldChoice::Earlier) | Fold::Overlap(FoldChoice::Earlier) => {
+                tz::Disambiguation::Earlier
+            }
+            Fold::Unambiguous | Fold::Gap(FoldChoice::Later) | Fold::Overlap(FoldChoice::Later) => {
+                tz::Disambiguation::Later
+            }
+        };
+
+        let zoned = ambiguous.disambiguate(choice).map_err(|e| {
+            TempsError::backend_error(format!("{ERR_TIMEZONE_CONVERSION}: {e}"), "jiff")
+        })?;
+
+        Ok((zoned, fold))
+    }
+}
+
+impl TimeParser for JiffProvider {
+    type DateTime = Zoned;
+
+    fn now(&self) -> Self::DateTime {
+        let now = self
+            .clock
+            .as_ref()
+            .map_or_else(Zoned::now, |clock| clock.now());
+        now.with_time_zone(self.time_zone.clone())
+    }
+
+    fn parse_expression(&self, expr: TimeExpression) -> Result<Self::DateTime> {
+        self.parse_expression_with_fold(expr)
+            .map(|(datetime, _)| datetime)
+    }
 }
 
 /// Parse a natural language time expression into a jiff `Zoned` datetime.
@@ -395,7 +1377,7 @@ impl TimeParser for JiffProvider {
 /// # Arguments
 ///
 /// * `input` - The natural language time expression to parse
-/// * `language` - The language to use for parsing
+/// * `options` - The language to use for parsing, or [`ParseOptions`]
 ///
 /// # Returns
 ///
@@ -425,7 +1407,130 @@ impl TimeParser for JiffProvider {
 /// - Date calculation results in an invalid date
 /// - Components are out of valid ranges (e.g., month 13)
 /// - The jiff library returns an error during calculations
-pub fn parse_to_zoned(input: &str, language: Language) -> Result<Zoned> {
-    let expr = temps_core::parse(input, language)?;
-    JiffProvider.parse_expression(expr)
+pub fn parse_to_zoned(input: &str, options: impl Into<ParseOptions>) -> Result<Zoned> {
+    let expr = temps_core::parse(input, options)?;
+    JiffProvider::default().parse_expression(expr)
+}
+
+/// Parse a natural language time expression in a specific time zone.
+///
+/// Works like [`parse_to_zoned`], but the expression is resolved in
+/// `time_zone` instead of the system time zone, so "tomorrow at 9am" means
+/// 9am in that zone.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::tz::TimeZone;
+/// use temps_jiff::parse_to_zoned_in;
+/// use temps_core::Language;
+///
+/// let berlin = TimeZone::get("Europe/Berlin").unwrap();
+/// let dt = parse_to_zoned_in("tomorrow at 9:00 am", Language::English, berlin.clone()).unwrap();
+/// assert_eq!(dt.hour(), 9);
+/// assert_eq!(dt.time_zone(), &berlin);
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse_to_zoned`].
+pub fn parse_to_zoned_in(
+    input: &str,
+    options: impl Into<ParseOptions>,
+    time_zone: TimeZone,
+) -> Result<Zoned> {
+    let expr = temps_core::parse(input, options)?;
+    JiffProvider::new(time_zone).parse_expression(expr)
+}
+
+/// Parse a natural language time expression relative to a fixed reference time.
+///
+/// Works like [`parse_to_zoned`], but every relative part of the expression
+/// ("in 5 minutes", "tomorrow", "next Monday", ...) is resolved against
+/// `reference` instead of the current time, in the reference's time zone.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::{civil::date, tz::TimeZone};
+/// use temps_jiff::parse_to_zoned_at;
+/// use temps_core::Language;
+///
+/// let reference = date(2024, 3, 15).at(23, 59, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+///
+/// let dt = parse_to_zoned_at("tomorrow at 9:00 am", Language::English, &reference).unwrap();
+/// assert_eq!(dt.date(), date(2024, 3, 16));
+/// assert_eq!(dt.hour(), 9);
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse_to_zoned`].
+pub fn parse_to_zoned_at(
+    input: &str,
+    options: impl Into<ParseOptions>,
+    reference: &Zoned,
+) -> Result<Zoned> {
+    let expr = temps_core::parse(input, options)?;
+    JiffProvider::new(reference.time_zone().clone())
+        .with_reference(reference.clone())
+        .parse_expression(expr)
+}
+
+/// Convert a parsed duration into a jiff [`Span`].
+///
+/// Unlike fixed-length durations, a span keeps months and years, so every
+/// parsed duration can be converted.
+///
+/// # Examples
+///
+/// ```
+/// use temps_jiff::to_span;
+/// use temps_core::{Language, parse_duration};
+///
+/// let duration = parse_duration("1 year 2 months", Language::English).unwrap();
+/// let span = to_span(duration).unwrap();
+/// assert_eq!((span.get_years(), span.get_months()), (1, 2));
+/// ```
+///
+/// # Errors
+///
+/// Returns `TempsError::BackendError` if an amount is out of jiff's range.
+pub fn to_span(duration: temps_core::Duration) -> Result<Span> {
+    let convert = || -> std::result::Result<Span, jiff::Error> {
+        Span::new()
+            .try_years(duration.years)?
+            .try_months(duration.months)?
+            .try_weeks(duration.weeks)?
+            .try_days(duration.days)?
+            .try_hours(duration.hours)?
+            .try_minutes(duration.minutes)?
+            .try_seconds(duration.seconds)?
+            .try_nanoseconds(duration.nanoseconds)
+    };
+
+    convert().map_err(|e| TempsError::backend_error(e.to_string(), "jiff"))
+}
+
+/// Parse a standalone duration like "90 minutes" or "1.5 days" into a jiff
+/// [`Span`].
+///
+/// # Examples
+///
+/// ```
+/// use temps_jiff::parse_to_span;
+/// use temps_core::Language;
+///
+/// let timeout = parse_to_span("2h30m", Language::English).unwrap();
+/// assert_eq!((timeout.get_hours(), timeout.get_minutes()), (2, 30));
+///
+/// let retention = parse_to_span("1,5 Tage", Language::German).unwrap();
+/// assert_eq!((retention.get_days(), retention.get_hours()), (1, 12));
+/// ```
+///
+/// # Errors
+///
+/// Returns the errors of [`temps_core::parse_duration`] and [`to_span`].
+pub fn parse_to_span(input: &str, language: Language) -> Result<Span> {
+    to_span(temps_core::parse_duration(input, language)?)
 }
@@ -1,13 +1,13 @@
 use jiff::{Span, Zoned, civil::DateTime};
 use temps_core::*;
 use temps_jiff::*;
-use temps_testhelpers::jiff::{MockTimeSource, TimeSource};
+use temps_testhelpers::jiff::{MockTimeSource, test_dates};
 
 // ===== Integration Tests =====
 
 #[test]
 fn test_time_provider_trait() {
-    let provider = JiffProvider;
+    let provider = JiffProvider::default();
     let now = provider.now();
     // Basic test that we can create a provider and get current time
     assert!(now > Zoned::default());
@@ -15,7 +15,7 @@ fn test_time_provider_trait() {
 
 #[test]
 fn test_jiff_provider_consistency() {
-    let provider = JiffProvider;
+    let provider = JiffProvider::default();
 
     // Test that parsing "now" returns the current time (approximately)
     let now = provider.now();
@@ -38,7 +38,7 @@ fn test_jiff_provider_consistency() {
 #[test]
 fn test_month_arithmetic_edge_cases() {
     // Test that parsing "in 1 month" works
-    let provider = JiffProvider;
+    let provider = JiffProvider::default();
     let expr = parse("in 1 month", Language::English).unwrap();
     let result = provider.parse_expression(expr);
     assert!(result.is_ok());
@@ -48,7 +48,7 @@ fn test_month_arithmetic_edge_cases() {
 fn test_leap_year_handling() {
     // Test that February 29, 2024 + 1 year = February 28, 2025
     // We can't test exact dates without mocking, but we can test that the parsing works
-    let provider = JiffProvider;
+    let provider = JiffProvider::default();
 
     let expr = parse("in 1 year", Language::English).unwrap();
     let result = provider.parse_expression(expr);
@@ -62,7 +62,7 @@ fn test_leap_year_handling() {
 #[test]
 fn test_multiple_years() {
     // Test multiple year arithmetic
-    let provider = JiffProvider;
+    let provider = JiffProvider::default();
 
     let expr = parse("in 5 years", Language::English).unwrap();
     let result = provider.parse_expression(expr);
@@ -76,7 +76,7 @@ fn test_multiple_years() {
 #[test]
 fn test_multiple_months() {
     // Test multiple month arithmetic
-    let provider = JiffProvider;
+    let provider = JiffProvider::default();
 
     let expr = parse("in 18 months", Language::English).unwrap();
     let result = provider.parse_expression(expr);
@@ -93,7 +93,7 @@ fn test_date_arithmetic_consistency() {
     // return to the exact same date (due to month length differences)
     // This is expected behavior
 
-    let provider = JiffProvider;
+    let provider = JiffProvider::default();
 
     // Test month arithmetic
     let forward_month = TimeExpression::Relative(RelativeTime {
@@ -134,70 +134,6 @@ fn test_date_arithmetic_consistency() {
 
 // ===== Mock Tests =====
 
-// Testable wrapper around JiffProvider
-struct TestableJiffProvider<T: TimeSource> {
-    time_source: T,
-}
-
-impl<T: TimeSource> TestableJiffProvider<T> {
-    fn new(time_source: T) -> Self {
-        Self { time_source }
-    }
-}
-
-impl<T: TimeSource> TimeParser for TestableJiffProvider<T> {
-    type DateTime = Zoned;
-
-    fn now(&self) -> Self::DateTime {
-        self.time_source.now()
-    }
-
-    fn parse_expression(&self, expr: TimeExpression) -> temps_core::Result<Self::DateTime> {
-        let now = self.now();
-        match expr {
-            TimeExpression::Now => Ok(now),
-            TimeExpression::Relative(rel) => {
-                // Use JiffProvider's logic but with our mocked now
-                let span = match rel.unit {
-                    TimeUnit::Second => Span::new().seconds(rel.amount),
-                    TimeUnit::Minute => Span::new().minutes(rel.amount),
-                    TimeUnit::Hour => Span::new().hours(rel.amount),
-                    TimeUnit::Day => Span::new().days(rel.amount),
-                    TimeUnit::Week => Span::new().weeks(rel.amount),
-                    TimeUnit::Month => Span::new().months(rel.amount),
-                    TimeUnit::Year => Span::new().years(rel.amount),
-                };
-
-                match rel.direction {
-                    Direction::Past => now.checked_sub(span).map_err(|e| {
-                        temps_core::TempsError::date_calculation_with_source(
-                            "Failed to subtract time",
-                            e.to_string(),
-                        )
-                    }),
-                    Direction::Future => now.checked_add(span).map_err(|e| {
-                        temps_core::TempsError::date_calculation_with_source(
-                            "Failed to add time",
-                            e.to_string(),
-                        )
-                    }),
-                }
-            }
-            TimeExpression::Absolute(abs) => {
-                JiffProvider.parse_expression(TimeExpression::Absolute(abs))
-            }
-            TimeExpression::Day(day_ref) => {
-                JiffProvider.parse_expression(TimeExpression::Day(day_ref))
-            }
-            TimeExpression::Time(time) => JiffProvider.parse_expression(TimeExpression::Time(time)),
-            TimeExpression::DayTime(day_time) => {
-                JiffProvider.parse_expression(TimeExpression::DayTime(day_time))
-            }
-            TimeExpression::Date(date) => JiffProvider.parse_expression(TimeExpression::Date(date)),
-        }
-    }
-}
-
 #[test]
 fn test_english_time_expressions_with_mock() {
     let mut mock = MockTimeSource::new();
@@ -206,7 +142,7 @@ fn test_english_time_expressions_with_mock() {
         .unwrap();
     mock.expect_now().return_const(base_time.clone());
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
 
     // Test various English natural language expressions
     let test_cases = vec![
@@ -288,7 +224,7 @@ fn test_german_time_expressions_with_mock() {
         .unwrap();
     mock.expect_now().return_const(base_time.clone());
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
 
     // Test various German natural language expressions
     let test_cases = vec![
@@ -372,7 +308,7 @@ fn test_month_arithmetic_edge_cases_with_mock() {
         .unwrap();
     mock.expect_now().return_const(jan_31_2024);
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
     let expr = parse("in 1 month", Language::English).unwrap();
     let result = provider.parse_expression(expr).unwrap();
 
@@ -391,7 +327,7 @@ fn test_month_arithmetic_non_leap_year() {
         .unwrap();
     mock.expect_now().return_const(jan_31_2023);
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
     let expr = parse("in einem Monat", Language::German).unwrap();
     let result = provider.parse_expression(expr).unwrap();
 
@@ -410,7 +346,7 @@ fn test_year_arithmetic_with_leap_day() {
         .unwrap();
     mock.expect_now().return_const(feb_29);
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
     let expr = parse("in 1 year", Language::English).unwrap();
     let result = provider.parse_expression(expr).unwrap();
 
@@ -429,7 +365,7 @@ fn test_cross_year_boundary_calculations() {
         .unwrap();
     mock.expect_now().return_const(oct_15);
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
     let expr = parse("in 6 months", Language::English).unwrap();
     let result = provider.parse_expression(expr).unwrap();
 
@@ -440,7 +376,7 @@ fn test_cross_year_boundary_calculations() {
 
 #[test]
 fn test_iso_datetime_absolute_time() {
-    let provider = JiffProvider;
+    let provider = JiffProvider::default();
 
     let test_cases = vec![
         // Basic RFC3339 dates
@@ -473,7 +409,7 @@ fn test_complex_expressions_with_articles() {
         .unwrap();
     mock.expect_now().return_const(base_time.clone());
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
 
     // Test English articles: "a", "an", "one"
     let english_cases = vec![
@@ -518,7 +454,7 @@ fn test_german_articles_with_time_calculations() {
         .unwrap();
     mock.expect_now().return_const(base_time.clone());
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
 
     // Test German articles: "ein", "eine", "einem", "einer", etc.
     let german_cases = vec![
@@ -567,7 +503,7 @@ fn test_now_expression_with_mock() {
         .unwrap();
     mock.expect_now().return_const(current_time.clone());
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
 
     // Test "now" in English
     let expr = parse("now", Language::English).unwrap();
@@ -588,7 +524,7 @@ fn test_month_year_calculations_with_spans() {
         .unwrap();
     mock.expect_now().return_const(base_time.clone());
 
-    let provider = TestableJiffProvider::new(mock);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_clock(mock);
 
     // Test month calculations
     let month_cases = vec![
@@ -735,7 +671,7 @@ fn test_time_parsing_with_jiff() {
 
 #[test]
 fn test_jiff_provider_rejects_invalid_programmatic_inputs() {
-    let provider = JiffProvider;
+    let provider = JiffProvider::default();
 
     let invalid_time = TimeExpression::Time(Time {
         hour: 0,
@@ -813,3 +749,1212 @@ fn test_date_parsing_with_jiff() {
         assert_eq!(datetime.hour(), 0); // Should be midnight
     }
 }
+
+#[test]
+fn test_reference_time_drives_every_expression() {
+    // Friday, March 15, 2024 at 23:59 - close to midnight on purpose
+    let at = |year, month, day, hour, minute| {
+        DateTime::constant(year, month, day, hour, minute, 0, 0)
+            .to_zoned(jiff::tz::TimeZone::UTC)
+            .unwrap()
+    };
+    let reference = at(2024, 3, 15, 23, 59);
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_reference(reference.clone());
+
+    let test_cases = vec![
+        ("now", reference.clone()),
+        ("in 2 minutes", at(2024, 3, 16, 0, 1)),
+        ("today", at(2024, 3, 15, 0, 0)),
+        ("yesterday", at(2024, 3, 14, 0, 0)),
+        ("tomorrow", at(2024, 3, 16, 0, 0)),
+        ("next monday", at(2024, 3, 18, 0, 0)),
+        ("last friday", at(2024, 3, 8, 0, 0)),
+        ("3:30 pm", at(2024, 3, 15, 15, 30)),
+        ("tomorrow at 9:00 am", at(2024, 3, 16, 9, 0)),
+    ];
+
+    for (input, expected) in test_cases {
+        let expr = parse(input, Language::English).unwrap();
+        let result = provider.parse_expression(expr).unwrap();
+        assert_eq!(result, expected, "Failed for input: {input}");
+    }
+}
+
+#[test]
+fn test_mock_time_source_drives_day_references() {
+    let mut mock = MockTimeSource::new();
+    mock.expect_now().return_const(test_dates::jan_31_2024());
+
+    let provider = JiffProvider::default().with_clock(mock);
+    let expr = parse("morgen um 14:00", Language::German).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+
+    assert_eq!(result.date(), jiff::civil::date(2024, 2, 1));
+    assert_eq!(result.hour(), 14);
+    assert_eq!(result.minute(), 0);
+}
+
+#[test]
+fn test_parse_to_zoned_at() {
+    let reference = test_dates::june_15_2023();
+
+    let result = parse_to_zoned_at("vor 3 Tagen", Language::German, &reference).unwrap();
+    assert_eq!(result, reference.checked_sub(Span::new().days(3)).unwrap());
+
+    let result =
+        parse_to_zoned_at("next monday at 9:00 am", Language::English, &reference).unwrap();
+    assert_eq!(result.date(), jiff::civil::date(2023, 6, 19));
+    assert_eq!(result.hour(), 9);
+}
+
+#[test]
+fn test_provider_resolves_in_configured_time_zone() {
+    let berlin = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+    // 23:30 UTC on March 15 is already March 16 in Berlin
+    let reference = DateTime::constant(2024, 3, 15, 23, 30, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap();
+    let provider = JiffProvider::new(berlin.clone()).with_reference(reference);
+
+    let expected = DateTime::constant(2024, 3, 17, 9, 0, 0, 0)
+        .to_zoned(berlin.clone())
+        .unwrap();
+    let expr = parse("tomorrow at 9:00 am", Language::English).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+    assert_eq!(result, expected);
+    assert_eq!(result.time_zone(), &berlin);
+
+    // Dates and absolute times without offset are interpreted in the provider's zone
+    let expr = parse("24.12.2024", Language::German).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+    assert_eq!(
+        result.datetime(),
+        DateTime::constant(2024, 12, 24, 0, 0, 0, 0)
+    );
+    assert_eq!(result.offset().seconds(), 3600);
+
+    let expr = parse("2024-07-01T12:00:00", Language::English).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+    assert_eq!(result.offset().seconds(), 7200);
+
+    // Absolute times with an explicit offset keep their instant
+    let expr = parse("2024-07-01T12:00:00Z", Language::English).unwrap();
+    let result = provider.parse_expression(expr).unwrap();
+    assert_eq!(
+        result.datetime(),
+        DateTime::constant(2024, 7, 1, 14, 0, 0, 0)
+    );
+}
+
+#[test]
+fn test_dst_gap_disambiguation() {
+    let berlin = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+    // 02:30 does not exist in Berlin on 2024-03-31 (clocks jump from 02:00 to 03:00)
+    let reference = DateTime::constant(2024, 3, 30, 12, 0, 0, 0)
+        .to_zoned(berlin.clone())
+        .unwrap();
+    let expr = parse("tomorrow at 2:30 am", Language::English).unwrap();
+
+    let provider = JiffProvider::new(berlin.clone()).with_reference(reference);
+    let (result, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    assert_eq!(fold, Fold::Gap(FoldChoice::Later));
+    assert_eq!(
+        result.datetime(),
+        DateTime::constant(2024, 3, 31, 3, 30, 0, 0)
+    );
+
+    let provider = provider.with_disambiguation(Disambiguation::Earlier);
+    let (result, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    assert_eq!(fold, Fold::Gap(FoldChoice::Earlier));
+    assert_eq!(
+        result.datetime(),
+        DateTime::constant(2024, 3, 31, 1, 30, 0, 0)
+    );
+
+    let provider = provider.with_disambiguation(Disambiguation::Reject);
+    assert!(matches!(
+        provider.parse_expression(expr),
+        Err(TempsError::AmbiguousTime { .. })
+    ));
+}
+
+#[test]
+fn test_dst_overlap_disambiguation() {
+    let berlin = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+    // 02:30 happens twice in Berlin on 2024-10-27 (clocks fall back from 03:00 to 02:00)
+    let expr = parse("27.10.2024", Language::German).unwrap();
+    let provider = JiffProvider::new(berlin.clone());
+    let (_, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    assert_eq!(fold, Fold::Unambiguous);
+
+    let expr = parse("2024-10-27T02:30:00", Language::English).unwrap();
+    let (result, fold) = provider.parse_expression_with_fold(expr).unwrap();
+    assert_eq!(fold, Fold::Overlap(FoldChoice::Earlier));
+    assert_eq!(result.offset().seconds(), 7200);
+
+    for (disambiguation, choice, offset) in [
+        (Disambiguation::Earlier, FoldChoice::Earlier, 7200),
+        (Disambiguation::Later, FoldChoice::Later, 3600),
+    ] {
+        let provider = JiffProvider::new(berlin.clone()).with_disambiguation(disambiguation);
+        let (result, fold) = provider.parse_expression_with_fold(expr).unwrap();
+        assert_eq!(fold, Fold::Overlap(choice));
+        assert_eq!(
+            result.datetime(),
+            DateTime::constant(2024, 10, 27, 2, 30, 0, 0)
+        );
+        assert_eq!(result.offset().seconds(), offset);
+    }
+
+    let provider = JiffProvider::new(berlin).with_disambiguation(Disambiguation::Reject);
+    assert!(matches!(
+        provider.parse_expression(expr),
+        Err(TempsError::AmbiguousTime { .. })
+    ));
+}
+
+#[test]
+fn test_parse_to_zoned_in() {
+    let tokyo = jiff::tz::TimeZone::get("Asia/Tokyo").unwrap();
+    let result = parse_to_zoned_in("morgen um 09:00", Language::German, tokyo.clone()).unwrap();
+    assert_eq!(result.time_zone(), &tokyo);
+    assert_eq!(result.hour(), 9);
+}
+
+#[test]
+fn test_compound_relative_durations() {
+    let reference = DateTime::constant(2024, 1, 30, 10, 0, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap();
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_reference(reference);
+
+    let test_cases = [
+        (
+            "in 2 hours 30 minutes",
+            Language::English,
+            (2024, 1, 30, 12, 30),
+        ),
+        (
+            "1 day and 3 hours ago",
+            Language::English,
+            (2024, 1, 29, 7, 0),
+        ),
+        // Months are applied before days: Feb 29 + 2 days
+        ("in 1 month 2 days", Language::English, (2024, 3, 2, 10, 0)),
+        (
+            "in 2 Stunden und 30 Minuten",
+            Language::German,
+            (2024, 1, 30, 12, 30),
+        ),
+        ("vor 1 Tag 3 Stunden", Language::German, (2024, 1, 29, 7, 0)),
+    ];
+
+    for (input, language, (year, month, day, hour, minute)) in test_cases {
+        let expr = parse(input, language).unwrap();
+        let result = provider.parse_expression(expr).unwrap();
+        assert_eq!(
+            result.datetime(),
+            DateTime::new(year, month, day, hour, minute, 0, 0).unwrap(),
+            "Failed for input: {input}"
+        );
+    }
+
+    // Too large for a `Span`, or too far out for jiff
+    let expr = parse("in 99999999 days and 1 hour", Language::English).unwrap();
+    assert!(matches!(
+        provider.parse_expression(expr),
+        Err(TempsError::ArithmeticOverflow { .. })
+    ));
+    let expr = parse("in 3000000 days and 1 hour", Language::English).unwrap();
+    assert!(matches!(
+        provider.parse_expression(expr),
+        Err(TempsError::DateCalculationError { .. })
+    ));
+}
+
+#[test]
+fn test_parse_to_span() {
+    let span = parse_to_span("90 minutes", Language::English).unwrap();
+    assert_eq!(span.get_minutes(), 90);
+
+    let span = parse_to_span("1.5 days", Language::English).unwrap();
+    assert_eq!((span.get_days(), span.get_hours()), (1, 12));
+
+    // Calendar units are kept as such
+    let span = parse_to_span("1 Jahr und 2 Monate", Language::German).unwrap();
+    assert_eq!((span.get_years(), span.get_months()), (1, 2));
+
+    let span = parse_to_span("2h30m", Language::English).unwrap();
+    assert_eq!(span.total(jiff::Unit::Minute).unwrap(), 150.0,);
+
+    assert!(parse_to_span("vor 2 Stunden", Language::German).is_err());
+}
+
+#[test]
+fn test_relative_day_at_time() {
+    // Wednesday, March 27, 2024, shortly before Berlin switches to summer time
+    let berlin = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+    let reference = DateTime::constant(2024, 3, 27, 23, 30, 0, 0)
+        .to_zoned(berlin.clone())
+        .unwrap();
+    let provider = JiffProvider::new(berlin).with_reference(reference);
+
+    let test_cases = [
+        ("in 3 days at 5pm", Language::English, (2024, 3, 30, 17, 0)),
+        // Calendar days, although one of them is only 23 hours long
+        ("in 4 days at 0:30", Language::English, (2024, 3, 31, 0, 30)),
+        (
+            "2 weeks ago at 10:30",
+            Language::English,
+            (2024, 3, 13, 10, 30),
+        ),
+        ("in a month at 9am", Language::English, (2024, 4, 27, 9, 0)),
+        (
+            "next week Monday at 10:00",
+            Language::English,
+            (2024, 4, 1, 10, 0),
+        ),
+        (
+            "monday this week at 10:00",
+            Language::English,
+            (2024, 3, 25, 10, 0),
+        ),
+        (
+            "vor 2 Tagen um 14:00",
+            Language::German,
+            (2024, 3, 25, 14, 0),
+        ),
+        (
+            "Freitag letzter Woche um 16:00",
+            Language::German,
+            (2024, 3, 22, 16, 0),
+        ),
+    ];
+
+    for (input, language, (year, month, day, hour, minute)) in test_cases {
+        let expr = parse(input, language).unwrap();
+        let result = provider.parse_expression(expr).unwrap();
+        assert_eq!(
+            result.datetime(),
+            DateTime::new(year, month, day, hour, minute, 0, 0).unwrap(),
+            "Failed for input: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_month_name_dates() {
+    for (input, language) in [
+        ("March 5th, 2024", Language::English),
+        ("5. März 2024", Language::German),
+    ] {
+        let result = parse_to_zoned_in(input, language, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(
+            result.datetime(),
+            DateTime::constant(2024, 3, 5, 0, 0, 0, 0)
+        );
+    }
+}
+
+#[test]
+fn test_dates_without_year_use_year_policy() {
+    let reference = DateTime::constant(2024, 6, 15, 10, 0, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap();
+    let test_cases = vec![
+        ("March 5", YearPolicy::Next, (2025, 3, 5)),
+        ("March 5", YearPolicy::Previous, (2024, 3, 5)),
+        ("March 5", YearPolicy::Nearest, (2024, 3, 5)),
+        ("Sept 1st", YearPolicy::Nearest, (2024, 9, 1)),
+        ("15/06", YearPolicy::Next, (2024, 6, 15)),
+        ("Feb 29", YearPolicy::Next, (2028, 2, 29)),
+    ];
+
+    for (input, policy, (year, month, day)) in test_cases {
+        let provider = JiffProvider::new(jiff::tz::TimeZone::UTC)
+            .with_reference(reference.clone())
+            .with_year_policy(policy);
+        let expr = parse(input, Language::English).unwrap();
+        assert_eq!(
+            provider.parse_expression(expr).unwrap().datetime(),
+            DateTime::new(year, month, day, 0, 0, 0, 0).unwrap(),
+            "Failed for input: {input} with {policy:?}"
+        );
+    }
+
+    // Defaults to the next occurrence
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_reference(reference);
+    let expr = parse("am 24.12.", Language::German).unwrap();
+    assert_eq!(
+        provider.parse_expression(expr).unwrap().datetime(),
+        DateTime::constant(2024, 12, 24, 0, 0, 0, 0)
+    );
+}
+
+#[test]
+fn test_us_date_order() {
+    let result =
+        parse_to_zoned_in("03/05/2024", Language::EnglishUs, jiff::tz::TimeZone::UTC).unwrap();
+    assert_eq!(
+        result.datetime(),
+        DateTime::constant(2024, 3, 5, 0, 0, 0, 0)
+    );
+
+    let options = ParseOptions::new(Language::English).with_date_order(DateOrder::Mdy);
+    let result = parse_to_zoned_in("12/31/2024", options, jiff::tz::TimeZone::UTC).unwrap();
+    assert_eq!(
+        result.datetime(),
+        DateTime::constant(2024, 12, 31, 0, 0, 0, 0)
+    );
+}
+
+#[test]
+fn test_parse_interval() {
+    // Friday
+    let reference = DateTime::constant(2024, 3, 15, 10, 0, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap();
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_reference(reference);
+    let at = |month, day, hour| DateTime::new(2024, month, day, hour, 0, 0, 0).unwrap();
+
+    let test_cases = vec![
+        (
+            "from 3pm to 5pm",
+            Language::English,
+            (at(3, 15, 15), at(3, 15, 17)),
+        ),
+        (
+            "from 10pm to 2am",
+            Language::English,
+            (at(3, 15, 22), at(3, 16, 2)),
+        ),
+        ("3-5pm", Language::English, (at(3, 15, 15), at(3, 15, 17))),
+        (
+            "from tomorrow at 9am to 5pm",
+            Language::English,
+            (at(3, 16, 9), at(3, 16, 17)),
+        ),
+        // Whole days include the last day
+        (
+            "from Monday to Friday",
+            Language::English,
+            (at(3, 18, 0), at(3, 23, 0)),
+        ),
+        (
+            "between Friday and Monday",
+            Language::English,
+            (at(3, 15, 0), at(3, 19, 0)),
+        ),
+        (
+            "from Monday at 9:00 to Friday at 17:00",
+            Language::English,
+            (at(3, 18, 9), at(3, 22, 17)),
+        ),
+        (
+            "von 9 bis 17 Uhr",
+            Language::German,
+            (at(3, 15, 9), at(3, 15, 17)),
+        ),
+        (
+            "vom 24.12. bis zum 26.12.",
+            Language::German,
+            (at(12, 24, 0), at(12, 27, 0)),
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        let expr = parse(input, language).unwrap();
+        let (start, end) = provider.parse_interval(expr).unwrap();
+        assert_eq!(
+            (start.datetime(), end.datetime()),
+            expected,
+            "Failed for input: {input}"
+        );
+    }
+
+    // Ends are resolved from the start across the year boundary
+    let expr = parse("from Dec 30 to Jan 2", Language::English).unwrap();
+    let (start, end) = provider.parse_interval(expr).unwrap();
+    assert_eq!(start.datetime(), at(12, 30, 0));
+    assert_eq!(end.datetime(), DateTime::constant(2025, 1, 3, 0, 0, 0, 0));
+
+    // On its own, an interval resolves to its start
+    let expr = parse("from 3pm to 5pm", Language::English).unwrap();
+    assert_eq!(
+        provider.parse_expression(expr).unwrap().datetime(),
+        at(3, 15, 15)
+    );
+
+    let expr = parse("between tomorrow and yesterday", Language::English).unwrap();
+    assert!(provider.parse_interval(expr).is_err());
+
+    let expr = parse("tomorrow", Language::English).unwrap();
+    assert!(provider.parse_interval(expr).is_err());
+}
+
+#[test]
+fn test_parse_period() {
+    // Friday
+    let reference = DateTime::constant(2024, 3, 15, 10, 0, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap();
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_reference(reference);
+    let day = |year, month, day| DateTime::new(year, month, day, 0, 0, 0, 0).unwrap();
+
+    let test_cases = vec![
+        (
+            "last week",
+            Language::English,
+            (day(2024, 3, 4), day(2024, 3, 11)),
+        ),
+        (
+            "this week",
+            Language::English,
+            (day(2024, 3, 11), day(2024, 3, 18)),
+        ),
+        (
+            "next week",
+            Language::English,
+            (day(2024, 3, 18), day(2024, 3, 25)),
+        ),
+        (
+            "this month",
+            Language::English,
+            (day(2024, 3, 1), day(2024, 4, 1)),
+        ),
+        (
+            "letzten Monat",
+            Language::German,
+            (day(2024, 2, 1), day(2024, 3, 1)),
+        ),
+        (
+            "next quarter",
+            Language::English,
+            (day(2024, 4, 1), day(2024, 7, 1)),
+        ),
+        (
+            "last quarter",
+            Language::English,
+            (day(2023, 10, 1), day(2024, 1, 1)),
+        ),
+        (
+            "nächstes Jahr",
+            Language::German,
+            (day(2025, 1, 1), day(2026, 1, 1)),
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        let expr = parse(input, language).unwrap();
+        let (start, end) = provider.parse_interval(expr).unwrap();
+        assert_eq!(
+            (start.datetime(), end.datetime()),
+            expected,
+            "Failed for input: {input}"
+        );
+        assert_eq!(
+            provider.parse_expression(expr).unwrap().datetime(),
+            expected.0
+        );
+    }
+}
+
+#[test]
+fn test_parse_boundary() {
+    // Friday
+    let reference = DateTime::constant(2024, 3, 15, 10, 0, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap();
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_reference(reference);
+    let day = |year, month, day| DateTime::new(year, month, day, 0, 0, 0, 0).unwrap();
+    let last_nanosecond = |year, month, day| {
+        DateTime::new(year, month, day, 0, 0, 0, 0)
+            .unwrap()
+            .checked_sub(Span::new().nanoseconds(1))
+            .unwrap()
+    };
+
+    let test_cases = vec![
+        // (input, language, start or last nanosecond, next period start)
+        (
+            "start of month",
+            Language::English,
+            day(2024, 3, 1),
+            day(2024, 3, 1),
+        ),
+        (
+            "end of month",
+            Language::English,
+            last_nanosecond(2024, 4, 1),
+            day(2024, 4, 1),
+        ),
+        (
+            "start of next week",
+            Language::English,
+            day(2024, 3, 18),
+            day(2024, 3, 18),
+        ),
+        (
+            "EOD",
+            Language::English,
+            last_nanosecond(2024, 3, 16),
+            day(2024, 3, 16),
+        ),
+        (
+            "end of last quarter",
+            Language::English,
+            last_nanosecond(2024, 1, 1),
+            day(2024, 1, 1),
+        ),
+        (
+            "Ende des Jahres",
+            Language::German,
+            last_nanosecond(2025, 1, 1),
+            day(2025, 1, 1),
+        ),
+    ];
+
+    let next_start = provider.clone().with_period_end(PeriodEnd::NextStart);
+    for (input, language, expected, expected_next_start) in test_cases {
+        let expr = parse(input, language).unwrap();
+        assert_eq!(
+            provider.parse_expression(expr).unwrap().datetime(),
+            expected,
+            "Failed for input: {input}"
+        );
+        assert_eq!(
+            next_start.parse_expression(expr).unwrap().datetime(),
+            expected_next_start,
+            "Failed for input: {input}"
+        );
+    }
+
+    // "end of month" ends an interval
+    let expr = parse("from today until end of month", Language::English).unwrap();
+    let (start, end) = next_start.parse_interval(expr).unwrap();
+    assert_eq!(
+        (start.datetime(), end.datetime()),
+        (day(2024, 3, 15), day(2024, 4, 1))
+    );
+}
+
+#[test]
+fn test_week_start() {
+    // Friday
+    let reference = DateTime::constant(2024, 3, 15, 10, 0, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap();
+    let day = |year, month, day| DateTime::new(year, month, day, 0, 0, 0, 0).unwrap();
+
+    let test_cases = vec![
+        (
+            WeekStart::Monday,
+            (day(2024, 3, 11), day(2024, 3, 18)),
+            day(2024, 3, 17),
+        ),
+        (
+            WeekStart::Sunday,
+            (day(2024, 3, 10), day(2024, 3, 17)),
+            day(2024, 3, 10),
+        ),
+        (
+            WeekStart::Saturday,
+            (day(2024, 3, 9), day(2024, 3, 16)),
+            day(2024, 3, 10),
+        ),
+    ];
+
+    for (week_start, this_week, sunday_this_week) in test_cases {
+        let provider = JiffProvider::new(jiff::tz::TimeZone::UTC)
+            .with_reference(reference.clone())
+            .with_week_start(week_start);
+        let expr = parse("this week", Language::English).unwrap();
+        let (start, end) = provider.parse_interval(expr).unwrap();
+        assert_eq!(
+            (start.datetime(), end.datetime()),
+            this_week,
+            "Failed for {week_start:?}"
+        );
+        let expr = parse("Sunday this week", Language::English).unwrap();
+        assert_eq!(
+            provider.parse_expression(expr).unwrap().datetime(),
+            sunday_this_week,
+            "Failed for {week_start:?}"
+        );
+        // Weekdays without a week are unaffected
+        let expr = parse("next Monday", Language::English).unwrap();
+        assert_eq!(
+            provider.parse_expression(expr).unwrap().datetime(),
+            day(2024, 3, 18)
+        );
+    }
+}
+
+#[test]
+fn test_occurrences() {
+    // Friday
+    let reference = DateTime::constant(2024, 3, 15, 10, 0, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap();
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_reference(reference);
+    let at =
+        |month, day, hour, minute| DateTime::new(2024, month, day, hour, minute, 0, 0).unwrap();
+
+    let test_cases = vec![
+        (
+            "every Monday at 9am",
+            Language::English,
+            vec![at(3, 18, 9, 0), at(3, 25, 9, 0), at(4, 1, 9, 0)],
+        ),
+        // Today's Friday at midnight has passed
+        (
+            "every other Friday",
+            Language::English,
+            vec![at(3, 29, 0, 0), at(4, 12, 0, 0), at(4, 26, 0, 0)],
+        ),
+        (
+            "every day at 9am 2 times",
+            Language::English,
+            vec![at(3, 16, 9, 0), at(3, 17, 9, 0)],
+        ),
+        (
+            "every 15 minutes",
+            Language::English,
+            vec![at(3, 15, 10, 0), at(3, 15, 10, 15), at(3, 15, 10, 30)],
+        ),
+        (
+            "monthly",
+            Language::English,
+            vec![at(4, 15, 0, 0), at(5, 15, 0, 0), at(6, 15, 0, 0)],
+        ),
+        (
+            "täglich bis 17.03.2024",
+            Language::German,
+            vec![at(3, 16, 0, 0), at(3, 17, 0, 0)],
+        ),
+        (
+            "every day until March 17th",
+            Language::English,
+            vec![at(3, 16, 0, 0), at(3, 17, 0, 0)],
+        ),
+        (
+            "jeden zweiten Freitag um 17:00",
+            Language::German,
+            vec![at(3, 15, 17, 0), at(3, 29, 17, 0), at(4, 12, 17, 0)],
+        ),
+    ];
+
+    for (input, language, expected) in test_cases {
+        let expr = parse(input, language).unwrap();
+        let occurrences: Vec<_> = provider
+            .occurrences(expr)
+            .unwrap()
+            .take(3)
+            .map(|occurrence| occurrence.unwrap().datetime())
+            .collect();
+        assert_eq!(occurrences, expected, "Failed for input: {input}");
+        assert_eq!(
+            provider.parse_expression(expr).unwrap().datetime(),
+            expected[0]
+        );
+    }
+
+    // Weeks start on Sunday: "every other Sunday" counts from this week's Sunday
+    let provider = provider.with_week_start(WeekStart::Sunday);
+    let expr = parse("every other Sunday", Language::English).unwrap();
+    assert_eq!(
+        provider
+            .occurrences(expr)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .datetime(),
+        at(3, 24, 0, 0)
+    );
+
+    assert!(
+        provider
+            .occurrences(parse("tomorrow", Language::English).unwrap())
+            .is_err()
+    );
+    let expr = parse("daily until 01/03/2024", Language::English).unwrap();
+    assert!(provider.parse_expression(expr).is_err());
+
+    // The next period lies past the last representable date
+    for input in ["every 99999999 days", "every 99999999 weeks"] {
+        let expr = parse(input, Language::English).unwrap();
+        assert!(
+            provider.parse_expression(expr).is_err(),
+            "Failed for input: {input}"
+        );
+        assert_eq!(
+            provider.occurrences(expr).unwrap().count(),
+            0,
+            "Failed for input: {input}"
+        );
+    }
+}
+
+#[test]
+fn test_rrule_occurrences() {
+    // Friday
+    let reference = DateTime::constant(2024, 3, 15, 10, 0, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap();
+    let provider = JiffProvider::new(jiff::tz::TimeZone::UTC).with_reference(reference.clone());
+    let at = |month, day, hour| DateTime::new(2024, month, day, hour, 0, 0, 0).unwrap();
+    let occurrences = |provider: &JiffProvider, rule: &str| {
+        let rule = parse_rrule(rule).unwrap();
+        provider
+            .rrule_occurrences(&rule)
+            .unwrap()
+            .take(4)
+            .map(|occurrence| occurrence.unwrap().datetime())
+            .collect::<Vec<_>>()
+    };
+
+    let test_cases = vec![
+        // Without a start, rules start at the reference time
+        (
+            "FREQ=WEEKLY;BYDAY=MO,FR",
+            vec![at(3, 15, 10), at(3, 18, 10), at(3, 22, 10), at(3, 25, 10)],
+        ),
+        // Counting from the start, not the reference time
+        (
+            "DTSTART:20240311T090000Z\nRRULE:FREQ=DAILY;COUNT=7",
+            vec![at(3, 16, 9), at(3, 17, 9)],
+        ),
+        // Until a date includes that day
+        (
+            "DTSTART:20240315T180000\nRRULE:FREQ=DAILY;UNTIL=20240317",
+            vec![at(3, 15, 18), at(3, 16, 18), at(3, 17, 18)],
+        ),
+        (
+            "DTSTART:20240315T180000\nRRULE:FREQ=DAILY;UNTIL=20240316T180000Z",
+            vec![at(3, 15, 18), at(3, 16, 18)],
+        ),
+        (
+            "DTSTART;VALUE=DATE:20240101\nRRULE:FREQ=MONTHLY;BYDAY=-1FR",
+            vec![at(3, 29, 0), at(4, 26, 0), at(5, 31, 0), at(6, 28, 0)],
+        ),
+    ];
+    for (rule, expected) in test_cases {
+        assert_eq!(
+            occurrences(&provider, rule),
+            expected,
+            "Failed for rule: {rule}"
+        );
+    }
+
+    // UTC start times are converted to the provider's time zone
+    let offset = jiff::tz::TimeZone::fixed(jiff::tz::offset(2));
+    let provider =
+        JiffProvider::new(offset.clone()).with_reference(reference.with_time_zone(offset));
+    let rule = parse_rrule("DTSTART:20240315T230000Z\nRRULE:FREQ=WEEKLY").unwrap();
+    assert_eq!(
+        provider
+            .rrule_occurrences(&rule)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .datetime(),
+        at(3, 16, 1)
+    );
+}
+
+#[test]
+fn test_rrule_occurrences_across_dst() {
+    let berlin = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+    let reference = DateTime::constant(2024, 3, 30, 0, 0, 0, 0)
+        .to_zoned(berlin.clone())
+        .unwrap();
+    let provider = JiffProvider::new(berlin).with_reference(reference);
+    let occurrences = |provider: &JiffProvider, rule: &str| {
+        let rule = parse_rrule(rule).unwrap();
+        provider
+            .rrule_occurrences(&rule)
+            .unwrap()
+            .collect::<Vec<_>>()
+    };
+
+    // 02:30 does not exist on March 31 and moves to 03:30
+    let daily = "DTSTART;TZID=Europe/Berlin:20240330T023000\nRRULE:FREQ=DAILY;COUNT=3";
+    let times: Vec<_> = occurrences(&provider, daily)
+        .into_iter()
+        .map(|occurrence| occurrence.unwrap().datetime())
+        .collect();
+    assert_eq!(
+        times,
+        [
+            DateTime::constant(2024, 3, 30, 2, 30, 0, 0),
+            DateTime::constant(2024, 3, 31, 3, 30, 0, 0),
+            DateTime::constant(2024, 4, 1, 2, 30, 0, 0),
+        ]
+    );
+
+    // The skipped hour is not repeated
+    let hourly = "DTSTART:20240331T010000\nRRULE:FREQ=HOURLY;COUNT=3";
+    let hours: Vec<_> = occurrences(&provider, hourly)
+        .into_iter()
+        .map(|occurrence| occurrence.unwrap().hour())
+        .collect();
+    assert_eq!(hours, [1, 3, 4]);
+
+    // Local times keep their time of day when summer time ends
+    let weekly = "DTSTART:20241020T090000\nRRULE:FREQ=WEEKLY;COUNT=2";
+    let utc_hours: Vec<_> = occurrences(&provider, weekly)
+        .into_iter()
+        .map(|occurrence| {
+            occurrence
+                .unwrap()
+                .with_time_zone(jiff::tz::TimeZone::UTC)
+                .hour()
+        })
+        .collect();
+    assert_eq!(utc_hours, [7, 8]);
+
+    let provider = provider.with_disambiguation(Disambiguation::Reject);
+    let result = occurrences(&provider, daily);
+    assert_eq!(result.len(), 2);
+    assert!(matches!(result[1], Err(TempsError::AmbiguousTime { .. })));
+}
+
+#[test]
+fn test_cron_schedules() {
+    let utc = jiff::tz::TimeZone::UTC;
+    let provider = JiffProvider::new(utc.clone());
+    let monthly = parse_cron("0 0 1 * *").unwrap();
+    let datetimes = |times: Vec<Zoned>| {
+        times
+            .into_iter()
+            .map(|zoned| zoned.datetime())
+            .collect::<Vec<_>>()
+    };
+
+    // Strictly after and before the given time
+    let first = DateTime::constant(2024, 3, 1, 0, 0, 0, 0)
+        .to_zoned(utc.clone())
+        .unwrap();
+    let next = provider
+        .next_after(monthly, &first)
+        .unwrap()
+        .take(2)
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(
+        datetimes(next),
+        [
+            DateTime::constant(2024, 4, 1, 0, 0, 0, 0),
+            DateTime::constant(2024, 5, 1, 0, 0, 0, 0),
+        ]
+    );
+    let previous = provider
+        .prev_before(monthly, &first)
+        .unwrap()
+        .take(2)
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(
+        datetimes(previous),
+        [
+            DateTime::constant(2024, 2, 1, 0, 0, 0, 0),
+            DateTime::constant(2024, 1, 1, 0, 0, 0, 0),
+        ]
+    );
+
+    // Sub-second times round towards the next fire time
+    let after = first.checked_add(Span::new().milliseconds(500)).unwrap();
+    let every_second = parse_cron("* * * * * *").unwrap();
+    let next = provider.next_after(every_second, &after).unwrap().next();
+    assert_eq!(
+        next.unwrap().unwrap(),
+        first.checked_add(Span::new().seconds(1)).unwrap()
+    );
+    let previous = provider.prev_before(every_second, &after).unwrap().next();
+    assert_eq!(previous.unwrap().unwrap(), first);
+
+    // The schedule follows the provider's time zone
+    let tokyo = jiff::tz::TimeZone::fixed(jiff::tz::offset(9));
+    let provider = JiffProvider::new(tokyo);
+    let daily = parse_cron("0 9 * * *").unwrap();
+    let after = DateTime::constant(2024, 3, 15, 0, 30, 0, 0)
+        .to_zoned(utc)
+        .unwrap();
+    let next = provider.next_after(daily, &after).unwrap().next().unwrap();
+    assert_eq!(
+        next.unwrap().datetime(),
+        DateTime::constant(2024, 3, 16, 9, 0, 0, 0)
+    );
+
+    // Schedules that never fire end
+    let never = parse_cron("0 0 31 4 *").unwrap();
+    assert_eq!(provider.next_after(never, &after).unwrap().count(), 0);
+}
+
+#[test]
+fn test_cron_schedules_across_dst() {
+    let berlin = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+    let provider = JiffProvider::new(berlin.clone());
+    let times = |provider: &JiffProvider, cron: &str, from, direction| -> Vec<_> {
+        let schedule = parse_cron(cron).unwrap();
+        match direction {
+            Direction::Future => provider
+                .next_after(schedule, &from)
+                .unwrap()
+                .take(4)
+                .collect(),
+            Direction::Past => provider
+                .prev_before(schedule, &from)
+                .unwrap()
+                .take(4)
+                .collect(),
+        }
+    };
+    let utc_times = |times: Vec<Result<Zoned>>| {
+        times
+            .into_iter()
+            .map(|time| {
+                time.unwrap()
+                    .with_time_zone(jiff::tz::TimeZone::UTC)
+                    .strftime("%d %H:%M")
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // 02:00 to 03:00 does not exist on March 31; skipped times move forward
+    // and are not repeated
+    let before_spring = DateTime::constant(2024, 3, 31, 1, 0, 0, 0)
+        .to_zoned(berlin.clone())
+        .unwrap();
+    let result = times(
+        &provider,
+        "*/30 * * * *",
+        before_spring.clone(),
+        Direction::Future,
+    );
+    let local: Vec<_> = result
+        .into_iter()
+        .map(|time| time.unwrap().strftime("%H:%M").to_string())
+        .collect();
+    assert_eq!(local, ["01:30", "03:00", "03:30", "04:00"]);
+
+    // 01:00 to 02:00 repeats on October 27 and fires once
+    let before_fall = DateTime::constant(2024, 10, 27, 0, 30, 0, 0)
+        .to_zoned(berlin.clone())
+        .unwrap();
+    let result = times(&provider, "0 * * * *", before_fall, Direction::Future);
+    assert_eq!(
+        utc_times(result),
+        ["26 23:00", "27 00:00", "27 02:00", "27 03:00"]
+    );
+    let after_fall = DateTime::constant(2024, 10, 27, 3, 30, 0, 0)
+        .to_zoned(berlin)
+        .unwrap();
+    let result = times(&provider, "0 * * * *", after_fall, Direction::Past);
+    assert_eq!(
+        utc_times(result),
+        ["27 02:00", "27 00:00", "26 23:00", "26 22:00"]
+    );
+
+    // Skipped times are errors that end the iteration under Reject
+    let provider = provider.with_disambiguation(Disambiguation::Reject);
+    let result = times(&provider, "30 2 * * *", before_spring, Direction::Future);
+    assert_eq!(result.len(), 1);
+    assert!(matches!(result[0], Err(TempsError::AmbiguousTime { .. })));
+}
+
+#[test]
+fn test_humanize() {
+    let utc = jiff::tz::TimeZone::UTC;
+    let reference = DateTime::constant(2024, 1, 31, 12, 0, 0, 0)
+        .to_zoned(utc.clone())
+        .unwrap();
+    let provider = JiffProvider::new(utc).with_reference(reference.clone());
+    let humanize = |offset: Span, options: HumanizeOptions| {
+        let target = reference.checked_add(offset).unwrap();
+        provider.humanize(&target, options).unwrap()
+    };
+    let english = HumanizeOptions::new(Language::English);
+
+    assert_eq!(humanize(Span::new(), english), "now");
+    assert_eq!(humanize(Span::new().seconds(1), english), "in 1 second");
+    assert_eq!(humanize(Span::new().minutes(-5), english), "5 minutes ago");
+    assert_eq!(
+        humanize(Span::new().days(-2), english.with_rounding(Rounding::Down)),
+        "2 days ago"
+    );
+
+    // Rounding the last unit
+    let offset = Span::new().minutes(90);
+    assert_eq!(humanize(offset, english), "in 2 hours");
+    assert_eq!(
+        humanize(offset, english.with_rounding(Rounding::Down)),
+        "in 1 hour"
+    );
+    let offset = Span::new().minutes(61);
+    assert_eq!(humanize(offset, english), "in 1 hour");
+    assert_eq!(
+        humanize(offset, english.with_rounding(Rounding::Up)),
+        "in 2 hours"
+    );
+    assert_eq!(
+        humanize(offset.negate(), english.with_rounding(Rounding::Up)),
+        "2 hours ago"
+    );
+
+    // Granularity, with rounding carried into larger units
+    let two_units = english.with_max_units(2);
+    assert_eq!(
+        humanize(Span::new().seconds(5430), two_units),
+        "in 1 hour 31 minutes"
+    );
+    assert_eq!(humanize(Span::new().seconds(7170), two_units), "in 2 hours");
+    assert_eq!(
+        humanize(
+            Span::new().seconds(90_061),
+            two_units.with_smallest_unit(TimeUnit::Minute)
+        ),
+        "in 1 day 1 hour"
+    );
+    assert_eq!(
+        humanize(
+            Span::new().seconds(20),
+            english.with_smallest_unit(TimeUnit::Minute)
+        ),
+        "now"
+    );
+
+    // Months and years are calendar months and years: January 31 to
+    // February 29 is one month
+    assert_eq!(humanize(Span::new().days(29), english), "in 1 month");
+    assert_eq!(
+        humanize(
+            Span::new().days(-366),
+            HumanizeOptions::new(Language::German)
+        ),
+        "vor 1 Jahr"
+    );
+    assert_eq!(
+        humanize(Span::new().weeks(2), HumanizeOptions::new(Language::German)),
+        "in 2 Wochen"
+    );
+
+    // Days are calendar days, so the 23 hours of a DST change are a day
+    let berlin = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+    let saturday = DateTime::constant(2024, 3, 30, 12, 0, 0, 0)
+        .to_zoned(berlin.clone())
+        .unwrap();
+    let sunday = DateTime::constant(2024, 3, 31, 12, 0, 0, 0)
+        .to_zoned(berlin.clone())
+        .unwrap();
+    let berlin_provider = JiffProvider::new(berlin).with_reference(saturday);
+    assert_eq!(
+        berlin_provider.humanize(&sunday, english).unwrap(),
+        "in 1 day"
+    );
+
+    // The text resolves to the time, exactly when all units are written
+    for seconds in [
+        1,
+        59,
+        3_601,
+        86_399,
+        1_000_000,
+        10_000_000,
+        40_000_000,
+        123_456_789,
+    ] {
+        for offset in [seconds, -seconds] {
+            let target = reference.checked_add(Span::new().seconds(offset)).unwrap();
+            for language in [Language::English, Language::German] {
+                let options = HumanizeOptions::new(language)
+                    .with_max_units(7)
+                    .with_rounding(Rounding::Down);
+                let text = provider.humanize(&target, options).unwrap();
+                let expr = parse(&text, language).unwrap();
+                assert_eq!(provider.parse_expression(expr).unwrap(), target, "{text}");
+
+                let text = provider.humanize(&target, language).unwrap();
+                let expr = parse(&text, language).unwrap();
+                let resolved = provider.parse_expression(expr).unwrap();
+                assert_eq!(
+                    provider.humanize(&resolved, language).unwrap(),
+                    text,
+                    "{text}"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_adapters() {
+    use jiff::{Timestamp, civil::date, tz::TimeZone};
+    use serde::Deserialize;
+    use temps_jiff::de::{Settings, with_settings};
+
+    #[derive(Deserialize)]
+    struct Config {
+        #[serde(deserialize_with = "temps_jiff::de::zoned::deserialize")]
+        expires_at: Zoned,
+        #[serde(deserialize_with = "temps_jiff::de::timestamp::deserialize")]
+        starts_at: Timestamp,
+        #[serde(deserialize_with = "temps_jiff::de::span::deserialize")]
+        retention: Span,
+    }
+
+    let berlin = TimeZone::get("Europe/Berlin").unwrap();
+    let reference = date(2024, 3, 15)
+        .at(10, 30, 0, 0)
+        .to_zoned(berlin.clone())
+        .unwrap();
+    let provider = JiffProvider::new(berlin).with_reference(reference.clone());
+    let read = |settings: Settings, json: &str| {
+        with_settings(settings, || serde_json::from_str::<Config>(json))
+    };
+
+    // Phrases resolve with the provider, across the switch to summer time
+    let json = r#"{"expires_at": "in 30 days", "starts_at": "now", "retention": "1 month"}"#;
+    let config = read(Settings::default().with_provider(provider.clone()), json).unwrap();
+    assert_eq!(
+        config.expires_at.datetime(),
+        date(2024, 4, 14).at(10, 30, 0, 0)
+    );
+    assert_eq!(config.starts_at, reference.timestamp());
+    assert_eq!(config.retention.get_months(), 1);
+
+    // ISO 8601 strings and other languages
+    let json = r#"{
+        "expires_at": "2024-12-25T15:30:00Z",
+        "starts_at": "morgen um 9:00",
+        "retention": "P1Y2M"
+    }"#;
+    let settings = Settings::new(Language::German).with_provider(provider);
+    let config = read(settings, json).unwrap();
+    assert_eq!(
+        config.expires_at.timestamp(),
+        "2024-12-25T15:30:00Z".parse::<Timestamp>().unwrap()
+    );
+    assert_eq!(
+        config.starts_at,
+        "2024-03-16T08:00:00Z".parse::<Timestamp>().unwrap()
+    );
+    assert_eq!(
+        (config.retention.get_years(), config.retention.get_months()),
+        (1, 2)
+    );
+
+    // The settings only apply within the scope
+    let german = r#"{"expires_at": "in 30 Tagen", "starts_at": "jetzt", "retention": "2 Tage"}"#;
+    assert!(read(Settings::new(Language::German), german).is_ok());
+    assert!(serde_json::from_str::<Config>(german).is_err());
+
+    // Errors carry the parse error message
+    let json = r#"{"expires_at": "in 5 blargs", "starts_at": "now", "retention": "2 days"}"#;
+    let error = serde_json::from_str::<Config>(json).err().unwrap();
+    assert!(error.to_string().contains("Failed to parse"), "{error}");
+}
@@ -2,6 +2,7 @@
 
 use chrono::{DateTime, Local, TimeZone};
 use mockall::automock;
+use temps_core::Clock;
 
 /// Common trait for mocking time sources in chrono tests
 #[automock]
@@ -9,6 +10,13 @@ pub trait TimeSource: Send + Sync {
     fn now(&self) -> DateTime<Local>;
 }
 
+/// Lets a mocked time source drive a provider via `with_clock`
+impl Clock<DateTime<Local>> for MockTimeSource {
+    fn now(&self) -> DateTime<Local> {
+        TimeSource::now(self)
+    }
+}
+
 /// Create a fixed DateTime for testing
 pub fn fixed_datetime(
     year: i32,
@@ -2,6 +2,7 @@
 
 use jiff::{Zoned, civil::date};
 use mockall::automock;
+use temps_core::Clock;
 
 /// Common trait for mocking time sources in jiff tests
 #[automock]
@@ -9,6 +10,13 @@ pub trait TimeSource: Send + Sync {
     fn now(&self) -> Zoned;
 }
 
+/// Lets a mocked time source drive a provider via `with_clock`
+impl Clock<Zoned> for MockTimeSource {
+    fn now(&self) -> Zoned {
+        TimeSource::now(self)
+    }
+}
+
 /// Common test dates
 pub mod test_dates {
     use super::*;
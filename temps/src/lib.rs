@@ -62,7 +62,7 @@
 //! let expr = parse("in 2 hours", Language::English).unwrap();
 //!
 //! // Then convert to datetime
-//! let provider = ChronoProvider;
+//! let provider = ChronoProvider::default();
 //! let dt = provider.parse_expression(expr).unwrap();
 //! # }
 //! ```
@@ -90,6 +90,7 @@
 //!
 //! - `chrono`: Enable chrono datetime backend
 //! - `jiff`: Enable jiff datetime backend
+//! - `chrono-tz`: Enable IANA time zones for the chrono backend
 //!
 //! At least one backend must be enabled.
 
@@ -97,7 +98,7 @@
 ///
 /// This module is available when the `chrono` feature is enabled.
 /// It provides chrono-specific types and functions for parsing time expressions
-/// into `chrono::DateTime<Local>` values.
+/// into `chrono::DateTime<Tz>` values (`Local` by default).
 ///
 /// This module re-exports all necessary types for using temps with chrono.
 #[cfg(feature = "chrono")]
@@ -105,8 +106,22 @@ pub mod chrono {
 
     /// The chrono-based time parser implementation
     pub use temps_chrono::ChronoProvider;
+    /// IANA time zone database for chrono
+    #[cfg(feature = "chrono-tz")]
+    pub use temps_chrono::chrono_tz;
+    /// Serde adapters that read human time strings into chrono types
+    #[cfg(feature = "serde")]
+    pub use temps_chrono::de;
     /// Convenience function to parse directly to DateTime<Local>
     pub use temps_chrono::parse_to_datetime;
+    /// Convenience function to parse relative to a fixed reference time
+    pub use temps_chrono::parse_to_datetime_at;
+    /// Convenience function to parse in a specific time zone
+    pub use temps_chrono::parse_to_datetime_in;
+    /// Convenience function to parse a duration to a chrono TimeDelta
+    pub use temps_chrono::parse_to_time_delta;
+    /// Convert a parsed duration to a chrono TimeDelta
+    pub use temps_chrono::to_time_delta;
     /// Re-export all core types
     pub use temps_core::*;
 }
@@ -125,6 +140,17 @@ pub mod jiff {
     pub use temps_core::*;
     /// The jiff-based time parser implementation
     pub use temps_jiff::JiffProvider;
+    /// Serde adapters that read human time strings into jiff types
+    #[cfg(feature = "serde")]
+    pub use temps_jiff::de;
+    /// Convenience function to parse a duration to a jiff Span
+    pub use temps_jiff::parse_to_span;
     /// Convenience function to parse directly to Zoned
     pub use temps_jiff::parse_to_zoned;
+    /// Convenience function to parse relative to a fixed reference time
+    pub use temps_jiff::parse_to_zoned_at;
+    /// Convenience function to parse in a specific time zone
+    pub use temps_jiff::parse_to_zoned_in;
+    /// Convert a parsed duration to a jiff Span
+    pub use temps_jiff::to_span;
 }